@@ -313,6 +313,12 @@ impl From<&str> for StringOrVecString {
     }
 }
 
+impl From<Vec<String>> for StringOrVecString {
+    fn from(value: Vec<String>) -> Self {
+        Self::VecString(value)
+    }
+}
+
 impl From<Vec<&str>> for StringOrVecString {
     fn from(value: Vec<&str>) -> Self {
         Self::VecString(value.into_iter().map(Into::into).collect())
@@ -0,0 +1,260 @@
+use crate::errors::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// Bulk API response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkResponse {
+    /// How long, in milliseconds, it took to process the bulk request
+    pub took: u64,
+
+    /// If `true`, one or more of the operations in the bulk request did not complete
+    /// successfully
+    pub errors: bool,
+
+    /// Result of each operation in the bulk request, in the order they were submitted
+    #[serde(default)]
+    pub items: Vec<BulkResponseItem>,
+
+    /// How long, in milliseconds, it took to process documents through an ingest pipeline
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub ingest_took: Option<u64>,
+}
+
+/// Operation performed by a bulk request item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkAction {
+    /// Indexes the document, replacing it if it already exists
+    Index,
+
+    /// Indexes the document if it does not already exist
+    Create,
+
+    /// Performs a partial document update
+    Update,
+
+    /// Removes the document
+    Delete,
+}
+
+/// Result of a single bulk operation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "BTreeMap<BulkAction, BulkResponseItemResult>")]
+pub struct BulkResponseItem {
+    /// Operation performed
+    pub action: BulkAction,
+
+    /// Result of the operation
+    pub result: BulkResponseItemResult,
+}
+
+/// Outcome of a single bulk operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkResponseItemResult {
+    /// Name of the index associated with the operation
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document ID associated with the operation
+    #[serde(
+        rename = "_id",
+        skip_serializing_if = "ShouldSkip::should_skip",
+        default
+    )]
+    pub id: Option<String>,
+
+    /// Document version associated with the operation
+    #[serde(
+        rename = "_version",
+        skip_serializing_if = "ShouldSkip::should_skip",
+        default
+    )]
+    pub version: Option<u64>,
+
+    /// Result of the operation, such as `created`, `updated`, `deleted` or `noop`
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub result: Option<String>,
+
+    /// HTTP status code returned for the operation
+    pub status: u16,
+
+    /// Sequence number assigned to the document for the operation
+    #[serde(
+        rename = "_seq_no",
+        skip_serializing_if = "ShouldSkip::should_skip",
+        default
+    )]
+    pub seq_no: Option<u64>,
+
+    /// Primary term assigned to the document for the operation
+    #[serde(
+        rename = "_primary_term",
+        skip_serializing_if = "ShouldSkip::should_skip",
+        default
+    )]
+    pub primary_term: Option<u64>,
+
+    /// Cause of the failure, when the operation failed
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub error: Option<ErrorCause>,
+}
+
+impl BulkResponse {
+    /// Items whose operation did not complete successfully
+    pub fn failed_items(&self) -> impl Iterator<Item = &BulkResponseItem> {
+        self.items.iter().filter(|item| item.is_failed())
+    }
+
+    /// Items whose operation failed and may succeed when retried
+    pub fn retryable_items(&self) -> impl Iterator<Item = &BulkResponseItem> {
+        self.items.iter().filter(|item| item.is_retryable())
+    }
+}
+
+impl BulkResponseItem {
+    /// Whether the operation did not complete successfully
+    pub fn is_failed(&self) -> bool {
+        self.result.error.is_some() || self.result.status >= 300
+    }
+
+    /// Whether the operation failed and may succeed when retried without modifications
+    pub fn is_retryable(&self) -> bool {
+        self.is_failed()
+            && (self.result.status == 429
+                || self
+                    .result
+                    .error
+                    .as_ref()
+                    .map(ErrorCause::is_retryable)
+                    .unwrap_or(false))
+    }
+}
+
+impl TryFrom<BTreeMap<BulkAction, BulkResponseItemResult>> for BulkResponseItem {
+    type Error = &'static str;
+
+    fn try_from(value: BTreeMap<BulkAction, BulkResponseItemResult>) -> Result<Self, Self::Error> {
+        if value.len() != 1 {
+            return Err("Bulk response item must contain exactly one action");
+        }
+
+        value
+            .into_iter()
+            .next()
+            .map(|(action, result)| Self { action, result })
+            .ok_or("Bulk response item must contain exactly one action")
+    }
+}
+
+impl Serialize for BulkResponseItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.action, &self.result)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: BulkResponse = serde_json::from_value(json!({
+            "took": 30,
+            "errors": true,
+            "items": [
+                {
+                    "index": {
+                        "_index": "test",
+                        "_id": "1",
+                        "_version": 1,
+                        "result": "created",
+                        "_shards": { "total": 2, "successful": 1, "failed": 0 },
+                        "status": 201,
+                        "_seq_no": 0,
+                        "_primary_term": 1
+                    }
+                },
+                {
+                    "update": {
+                        "_index": "test",
+                        "_id": "5",
+                        "status": 404,
+                        "error": {
+                            "type": "document_missing_exception",
+                            "reason": "[5]: document missing",
+                            "index": "test"
+                        }
+                    }
+                },
+                {
+                    "create": {
+                        "_index": "test",
+                        "_id": "6",
+                        "status": 429,
+                        "error": {
+                            "type": "es_rejected_execution_exception",
+                            "reason": "rejected execution"
+                        }
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert!(response.errors);
+        assert_eq!(response.items.len(), 3);
+        assert_eq!(response.items[0].action, BulkAction::Index);
+        assert_eq!(response.items[0].result.seq_no, Some(0));
+        assert!(!response.items[0].is_failed());
+
+        let failed: Vec<_> = response.failed_items().collect();
+
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0].action, BulkAction::Update);
+        assert_eq!(failed[0].result.id.as_deref(), Some("5"));
+        assert_eq!(
+            failed[0].result.error.as_ref().map(|error| &error.kind),
+            Some(&ErrorKind::DocumentMissing)
+        );
+
+        let retryable: Vec<_> = response.retryable_items().collect();
+
+        assert_eq!(retryable.len(), 1);
+        assert_eq!(retryable[0].action, BulkAction::Create);
+    }
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            BulkResponseItem {
+                action: BulkAction::Delete,
+                result: BulkResponseItemResult {
+                    index: "test".into(),
+                    id: Some("2".into()),
+                    version: None,
+                    result: Some("not_found".into()),
+                    status: 404,
+                    seq_no: None,
+                    primary_term: None,
+                    error: None,
+                },
+            },
+            json!({
+                "delete": {
+                    "_index": "test",
+                    "_id": "2",
+                    "result": "not_found",
+                    "status": 404
+                }
+            }),
+        );
+    }
+}
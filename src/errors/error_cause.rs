@@ -0,0 +1,87 @@
+use crate::errors::*;
+use crate::util::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Cause of an Elasticsearch failure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorCause {
+    /// Type of the failure
+    #[serde(rename = "type")]
+    pub kind: ErrorKind,
+
+    /// Human readable explanation of the failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub reason: Option<String>,
+
+    /// Underlying causes of the failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub root_cause: Vec<ErrorCause>,
+
+    /// Failure which caused this one
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub caused_by: Option<Box<ErrorCause>>,
+
+    /// Additional failure specific details, such as `index` or `failed_shards`
+    #[serde(flatten, default)]
+    pub metadata: BTreeMap<String, Value>,
+}
+
+impl ErrorCause {
+    /// Iterates this cause, its root causes and the chain of `caused_by` failures
+    pub fn causes(&self) -> Vec<&ErrorCause> {
+        let mut causes = vec![self];
+        causes.extend(self.root_cause.iter().flat_map(ErrorCause::causes));
+
+        if let Some(caused_by) = &self.caused_by {
+            causes.extend(caused_by.causes());
+        }
+
+        causes
+    }
+
+    /// Whether any of the causes of this failure is of the given kind
+    pub fn is(&self, kind: &ErrorKind) -> bool {
+        self.causes().iter().any(|cause| &cause.kind == kind)
+    }
+
+    /// Whether the request may succeed when retried later without modifications. Wrapping
+    /// failures, such as `search_phase_execution_exception`, are retryable when any of their
+    /// causes is.
+    pub fn is_retryable(&self) -> bool {
+        self.causes().iter().any(|cause| cause.kind.is_retryable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_nested_causes() {
+        let cause: ErrorCause = serde_json::from_value(json!({
+            "type": "search_phase_execution_exception",
+            "reason": "all shards failed",
+            "phase": "query",
+            "root_cause": [
+                {
+                    "type": "circuit_breaking_exception",
+                    "reason": "[parent] Data too large",
+                    "bytes_wanted": 1024
+                }
+            ],
+            "caused_by": {
+                "type": "illegal_argument_exception",
+                "reason": "bad argument"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(cause.kind, ErrorKind::SearchPhaseExecution);
+        assert_eq!(cause.metadata.get("phase"), Some(&json!("query")));
+        assert_eq!(cause.causes().len(), 3);
+        assert!(cause.is(&ErrorKind::IllegalArgument));
+        assert!(!cause.is(&ErrorKind::IndexNotFound));
+        assert!(cause.is_retryable());
+    }
+}
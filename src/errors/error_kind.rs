@@ -0,0 +1,181 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! error_kind {
+    ($($(#[$meta:meta])* $variant:ident => $name:literal),+ $(,)?) => {
+        /// Type of an Elasticsearch failure as reported in the `type` field of an error cause
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ErrorKind {
+            $(
+                $(#[$meta])*
+                $variant,
+            )+
+
+            /// Any failure type without a dedicated variant
+            Other(String),
+        }
+
+        impl ErrorKind {
+            /// Elasticsearch name of the failure type
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)+
+                    Self::Other(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for ErrorKind {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($name => Self::$variant,)+
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+error_kind!(
+    /// Targeted index does not exist
+    IndexNotFound => "index_not_found_exception",
+    /// Request body could not be parsed
+    Parsing => "parsing_exception",
+    /// Request body is not valid JSON or doesn't match the expected structure
+    XContentParse => "x_content_parse_exception",
+    /// All shards failed during one of the search phases
+    SearchPhaseExecution => "search_phase_execution_exception",
+    /// Document was modified concurrently or has an unexpected version
+    VersionConflict => "version_conflict_engine_exception",
+    /// Request would exceed a memory circuit breaker
+    CircuitBreaking => "circuit_breaking_exception",
+    /// Thread pool queue is full and the request was rejected
+    EsRejectedExecution => "es_rejected_execution_exception",
+    /// Executor rejected the request, e.g. because the node is shutting down
+    RejectedExecution => "rejected_execution_exception",
+    /// Request contains an invalid argument
+    IllegalArgument => "illegal_argument_exception",
+    /// Resource to create already exists
+    ResourceAlreadyExists => "resource_already_exists_exception",
+    /// Referenced resource does not exist
+    ResourceNotFound => "resource_not_found_exception",
+    /// Document to update does not exist
+    DocumentMissing => "document_missing_exception",
+    /// Request is not authenticated or not authorized
+    Security => "security_exception",
+    /// Operation is blocked by a cluster or index block
+    ClusterBlock => "cluster_block_exception",
+    /// No shard copy is available to serve the request
+    NoShardAvailable => "no_shard_available_action_exception",
+    /// Operation timed out
+    Timeout => "timeout_exception",
+    /// Cluster state update was not processed by the master node in time
+    ProcessClusterEventTimeout => "process_cluster_event_timeout_exception",
+    /// Node did not respond to a transport request in time
+    ReceiveTimeoutTransport => "receive_timeout_transport_exception",
+    /// Task was cancelled before it completed
+    TaskCancelled => "task_cancelled_exception",
+    /// Script failed to compile or execute
+    Script => "script_exception",
+);
+
+impl ErrorKind {
+    /// Whether a request that failed with this kind of error may succeed when retried later
+    /// without modifications
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::CircuitBreaking
+                | Self::EsRejectedExecution
+                | Self::RejectedExecution
+                | Self::NoShardAvailable
+                | Self::Timeout
+                | Self::ProcessClusterEventTimeout
+                | Self::ReceiveTimeoutTransport
+        )
+    }
+}
+
+impl From<String> for ErrorKind {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_from_str() {
+        assert_eq!(
+            ErrorKind::from("index_not_found_exception"),
+            ErrorKind::IndexNotFound
+        );
+        assert_eq!(
+            ErrorKind::from("x_content_parse_exception"),
+            ErrorKind::XContentParse
+        );
+        assert_eq!(
+            ErrorKind::from("strange_exception"),
+            ErrorKind::Other("strange_exception".into())
+        );
+        assert_eq!(ErrorKind::Timeout.as_str(), "timeout_exception");
+        assert_eq!(
+            ErrorKind::Other("strange_exception".into()).to_string(),
+            "strange_exception"
+        );
+    }
+
+    #[test]
+    fn retryable() {
+        assert!(ErrorKind::CircuitBreaking.is_retryable());
+        assert!(ErrorKind::EsRejectedExecution.is_retryable());
+        assert!(ErrorKind::RejectedExecution.is_retryable());
+        assert!(ErrorKind::ReceiveTimeoutTransport.is_retryable());
+        assert!(!ErrorKind::IndexNotFound.is_retryable());
+        assert!(!ErrorKind::VersionConflict.is_retryable());
+        assert!(!ErrorKind::Other("strange_exception".into()).is_retryable());
+    }
+
+    #[test]
+    fn round_trips() {
+        for name in [
+            "parsing_exception",
+            "x_content_parse_exception",
+            "es_rejected_execution_exception",
+            "rejected_execution_exception",
+            "timeout_exception",
+            "process_cluster_event_timeout_exception",
+            "receive_timeout_transport_exception",
+            "strange_exception",
+        ] {
+            let kind: ErrorKind = serde_json::from_value(serde_json::json!(name)).unwrap();
+
+            assert_eq!(serde_json::to_value(kind).unwrap(), name);
+        }
+    }
+}
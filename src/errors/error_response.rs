@@ -0,0 +1,88 @@
+use crate::errors::*;
+
+/// Error response returned by Elasticsearch for a failed request
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// let response: ErrorResponse = serde_json::from_str(r#"{
+///     "error": {
+///         "type": "index_not_found_exception",
+///         "reason": "no such index [logs]",
+///         "index": "logs"
+///     },
+///     "status": 404
+/// }"#).unwrap();
+///
+/// assert_eq!(response.kind(), &ErrorKind::IndexNotFound);
+/// assert!(!response.is_retryable());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#common-options-error-options>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    /// Cause of the failure
+    pub error: ErrorCause,
+
+    /// HTTP status code of the response
+    pub status: u16,
+}
+
+impl ErrorResponse {
+    /// Type of the top level failure
+    pub fn kind(&self) -> &ErrorKind {
+        &self.error.kind
+    }
+
+    /// Whether the request may succeed when retried later without modifications, either
+    /// because one of the failure causes is retryable or because the status code indicates a
+    /// temporary condition
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.status, 429 | 502 | 503 | 504) || self.error.is_retryable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: ErrorResponse = serde_json::from_value(json!({
+            "error": {
+                "root_cause": [
+                    {
+                        "type": "version_conflict_engine_exception",
+                        "reason": "[1]: version conflict",
+                        "index": "my-index"
+                    }
+                ],
+                "type": "version_conflict_engine_exception",
+                "reason": "[1]: version conflict",
+                "index": "my-index"
+            },
+            "status": 409
+        }))
+        .unwrap();
+
+        assert_eq!(response.kind(), &ErrorKind::VersionConflict);
+        assert_eq!(
+            response.error.reason.as_deref(),
+            Some("[1]: version conflict")
+        );
+        assert_eq!(
+            response.error.metadata.get("index"),
+            Some(&json!("my-index"))
+        );
+        assert!(!response.is_retryable());
+    }
+
+    #[test]
+    fn retryable_status() {
+        let response: ErrorResponse = serde_json::from_value(json!({
+            "error": { "type": "unknown_exception" },
+            "status": 503
+        }))
+        .unwrap();
+
+        assert!(response.is_retryable());
+    }
+}
@@ -0,0 +1,12 @@
+//! Elasticsearch reports failures with a structured error body, which can be parsed into an
+//! [`ErrorResponse`] and inspected through its [`ErrorKind`] without matching on strings.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#common-options-error-options>
+
+mod error_cause;
+mod error_kind;
+mod error_response;
+
+pub use self::error_cause::*;
+pub use self::error_kind::*;
+pub use self::error_response::*;
@@ -0,0 +1,38 @@
+use crate::util::*;
+
+/// Permanently removes the index.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-delete.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct DeleteAction {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delete_searchable_snapshot: Option<bool>,
+}
+
+impl DeleteAction {
+    /// Creates an instance of [`DeleteAction`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deletes the searchable snapshot created in a previous phase. Defaults to `true`.
+    pub fn delete_searchable_snapshot(mut self, delete_searchable_snapshot: bool) -> Self {
+        self.delete_searchable_snapshot = Some(delete_searchable_snapshot);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DeleteAction::new(), json!({}));
+
+        assert_serialize(
+            DeleteAction::new().delete_searchable_snapshot(false),
+            json!({ "delete_searchable_snapshot": false }),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::util::*;
+
+/// Force merges the index into the specified maximum number of segments.
+///
+/// To merge an index down to a single segment using the best compression codec:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// ForceMergeAction::new(1).index_codec("best_compression");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-forcemerge.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ForceMergeAction {
+    max_num_segments: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_codec: Option<String>,
+}
+
+impl ForceMergeAction {
+    /// Creates an instance of [`ForceMergeAction`]
+    ///
+    /// - `max_num_segments` - Number of segments to merge to. To fully merge the index, set to
+    ///   `1`.
+    pub fn new(max_num_segments: u32) -> Self {
+        Self {
+            max_num_segments,
+            index_codec: None,
+        }
+    }
+
+    /// Codec used to compress the document store. The only accepted value is
+    /// `best_compression`, which uses DEFLATE for a higher compression ratio but slower stored
+    /// fields performance.
+    pub fn index_codec(mut self, index_codec: impl Into<String>) -> Self {
+        self.index_codec = Some(index_codec.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(ForceMergeAction::new(1), json!({ "max_num_segments": 1 }));
+
+        assert_serialize(
+            ForceMergeAction::new(1).index_codec("best_compression"),
+            json!({
+                "max_num_segments": 1,
+                "index_codec": "best_compression"
+            }),
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::ilm::*;
+use crate::util::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Lifecycle policy body for the create or update policy API.
+///
+/// To create a policy that rolls indices over daily and deletes them after 30 days:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let policy =
+/// LifecyclePolicy::new()
+///     .hot(Phase::new().rollover(RolloverAction::new().max_age(Time::Days(1))))
+///     .delete(Phase::new().min_age(Time::Days(30)).delete(DeleteAction::new()));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-put-lifecycle.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct LifecyclePolicy {
+    policy: LifecyclePolicyInner,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+struct LifecyclePolicyInner {
+    phases: Phases,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_meta")]
+    meta: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+struct Phases {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hot: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    warm: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    cold: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    frozen: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delete: Option<Phase>,
+}
+
+impl LifecyclePolicy {
+    /// Creates an instance of [`LifecyclePolicy`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recent, searchable data that is actively being updated and queried
+    pub fn hot(mut self, phase: Phase) -> Self {
+        self.policy.phases.hot = Some(phase);
+        self
+    }
+
+    /// Data that is no longer being updated but is still queried
+    pub fn warm(mut self, phase: Phase) -> Self {
+        self.policy.phases.warm = Some(phase);
+        self
+    }
+
+    /// Data that is no longer being updated and is queried infrequently
+    pub fn cold(mut self, phase: Phase) -> Self {
+        self.policy.phases.cold = Some(phase);
+        self
+    }
+
+    /// Data that is queried rarely and never updated
+    pub fn frozen(mut self, phase: Phase) -> Self {
+        self.policy.phases.frozen = Some(phase);
+        self
+    }
+
+    /// Data that is no longer needed and can safely be removed
+    pub fn delete(mut self, phase: Phase) -> Self {
+        self.policy.phases.delete = Some(phase);
+        self
+    }
+
+    /// Optional meta-data for the policy. It is not automatically generated or used by
+    /// Elasticsearch.
+    pub fn meta<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.policy.meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            LifecyclePolicy::new(),
+            json!({ "policy": { "phases": {} } }),
+        );
+
+        assert_serialize(
+            LifecyclePolicy::new()
+                .hot(
+                    Phase::new().set_priority(100).rollover(
+                        RolloverAction::new().max_primary_shard_size(Byte::Gigabytes(50)),
+                    ),
+                )
+                .warm(
+                    Phase::new()
+                        .min_age(Time::Days(7))
+                        .shrink(ShrinkAction::new().number_of_shards(1))
+                        .forcemerge(ForceMergeAction::new(1)),
+                )
+                .cold(
+                    Phase::new()
+                        .min_age(Time::Days(30))
+                        .searchable_snapshot(SearchableSnapshotAction::new("backups")),
+                )
+                .frozen(
+                    Phase::new()
+                        .min_age(Time::Days(60))
+                        .searchable_snapshot(SearchableSnapshotAction::new("backups")),
+                )
+                .delete(
+                    Phase::new()
+                        .min_age(Time::Days(90))
+                        .delete(DeleteAction::new()),
+                )
+                .meta("description", "used for nginx log"),
+            json!({
+                "policy": {
+                    "phases": {
+                        "hot": {
+                            "actions": {
+                                "rollover": { "max_primary_shard_size": "50gb" },
+                                "set_priority": { "priority": 100 }
+                            }
+                        },
+                        "warm": {
+                            "min_age": "7d",
+                            "actions": {
+                                "shrink": { "number_of_shards": 1 },
+                                "forcemerge": { "max_num_segments": 1 }
+                            }
+                        },
+                        "cold": {
+                            "min_age": "30d",
+                            "actions": {
+                                "searchable_snapshot": { "snapshot_repository": "backups" }
+                            }
+                        },
+                        "frozen": {
+                            "min_age": "60d",
+                            "actions": {
+                                "searchable_snapshot": { "snapshot_repository": "backups" }
+                            }
+                        },
+                        "delete": {
+                            "min_age": "90d",
+                            "actions": {
+                                "delete": {}
+                            }
+                        }
+                    },
+                    "_meta": {
+                        "description": "used for nginx log"
+                    }
+                }
+            }),
+        );
+    }
+}
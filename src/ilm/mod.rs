@@ -0,0 +1,23 @@
+//! Index lifecycle management (ILM) policies automatically manage indices according to
+//! performance, resiliency, and retention requirements.
+//!
+//! A lifecycle policy defines up to five phases (hot, warm, cold, frozen and delete), and the
+//! actions to perform on an index once it enters each of them.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-lifecycle-management.html>
+
+mod delete_action;
+mod force_merge_action;
+mod lifecycle_policy;
+mod phase;
+mod rollover_action;
+mod searchable_snapshot_action;
+mod shrink_action;
+
+pub use self::delete_action::*;
+pub use self::force_merge_action::*;
+pub use self::lifecycle_policy::*;
+pub use self::phase::*;
+pub use self::rollover_action::*;
+pub use self::searchable_snapshot_action::*;
+pub use self::shrink_action::*;
@@ -0,0 +1,120 @@
+use crate::ilm::*;
+use crate::search::*;
+use crate::util::*;
+
+/// A lifecycle phase, entered once the index reaches `min_age`, with the actions to perform on
+/// the index while it is in that phase.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-index-lifecycle.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Phase {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_age: Option<Time>,
+
+    actions: Actions,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+struct Actions {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rollover: Option<RolloverAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shrink: Option<ShrinkAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    forcemerge: Option<ForceMergeAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    searchable_snapshot: Option<SearchableSnapshotAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    set_priority: Option<SetPriority>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delete: Option<DeleteAction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct SetPriority {
+    priority: u32,
+}
+
+impl Phase {
+    /// Creates an instance of [`Phase`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum age of an index before it enters this phase, measured from index creation or
+    /// rollover. Defaults to `0ms`.
+    pub fn min_age(mut self, min_age: Time) -> Self {
+        self.min_age = Some(min_age);
+        self
+    }
+
+    /// Rolls over a target to a new index when the existing index meets one or more of the
+    /// rollover conditions. Allowed in the hot phase.
+    pub fn rollover(mut self, rollover: RolloverAction) -> Self {
+        self.actions.rollover = Some(rollover);
+        self
+    }
+
+    /// Shrinks the index into a new index with fewer primary shards. Allowed in the hot and warm
+    /// phases.
+    pub fn shrink(mut self, shrink: ShrinkAction) -> Self {
+        self.actions.shrink = Some(shrink);
+        self
+    }
+
+    /// Force merges the index into the specified maximum number of segments. Allowed in the hot
+    /// and warm phases.
+    pub fn forcemerge(mut self, forcemerge: ForceMergeAction) -> Self {
+        self.actions.forcemerge = Some(forcemerge);
+        self
+    }
+
+    /// Takes a snapshot of the managed index in the configured repository and mounts it as a
+    /// searchable snapshot. Allowed in the hot, cold and frozen phases.
+    pub fn searchable_snapshot(mut self, searchable_snapshot: SearchableSnapshotAction) -> Self {
+        self.actions.searchable_snapshot = Some(searchable_snapshot);
+        self
+    }
+
+    /// Sets the priority of the index as soon as the policy enters the phase. Higher priority
+    /// indices are recovered before indices with lower priorities following a node restart.
+    pub fn set_priority(mut self, priority: u32) -> Self {
+        self.actions.set_priority = Some(SetPriority { priority });
+        self
+    }
+
+    /// Permanently removes the index. Allowed in the delete phase.
+    pub fn delete(mut self, delete: DeleteAction) -> Self {
+        self.actions.delete = Some(delete);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Phase::new(), json!({ "actions": {} }));
+
+        assert_serialize(
+            Phase::new()
+                .min_age(Time::Days(1))
+                .set_priority(50)
+                .forcemerge(ForceMergeAction::new(1)),
+            json!({
+                "min_age": "1d",
+                "actions": {
+                    "forcemerge": { "max_num_segments": 1 },
+                    "set_priority": { "priority": 50 }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Rolls over a target to a new index when the existing index meets one or more of the rollover
+/// conditions.
+///
+/// To roll over when the index is a day old or a primary shard reaches 50gb:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// RolloverAction::new()
+///     .max_age(Time::Days(1))
+///     .max_primary_shard_size(Byte::Gigabytes(50));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-rollover.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct RolloverAction {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_age: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_size: Option<Byte>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_size: Option<Byte>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_docs: Option<u64>,
+}
+
+impl RolloverAction {
+    /// Creates an instance of [`RolloverAction`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Triggers rollover after the maximum elapsed time from index creation is reached
+    pub fn max_age(mut self, max_age: Time) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Triggers rollover after the specified maximum number of documents is reached
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+
+    /// Triggers rollover when the index reaches a certain size. This is the total size of all
+    /// primary shards in the index.
+    pub fn max_size(mut self, max_size: Byte) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Triggers rollover when the largest primary shard in the index reaches a certain size
+    pub fn max_primary_shard_size(mut self, max_primary_shard_size: Byte) -> Self {
+        self.max_primary_shard_size = Some(max_primary_shard_size);
+        self
+    }
+
+    /// Triggers rollover when the largest primary shard in the index reaches a certain number
+    /// of documents
+    pub fn max_primary_shard_docs(mut self, max_primary_shard_docs: u64) -> Self {
+        self.max_primary_shard_docs = Some(max_primary_shard_docs);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(RolloverAction::new(), json!({}));
+
+        assert_serialize(
+            RolloverAction::new()
+                .max_age(Time::Days(7))
+                .max_docs(100_000_000)
+                .max_size(Byte::Gigabytes(100))
+                .max_primary_shard_size(Byte::Gigabytes(50))
+                .max_primary_shard_docs(200_000_000),
+            json!({
+                "max_age": "7d",
+                "max_docs": 100_000_000,
+                "max_size": "100gb",
+                "max_primary_shard_size": "50gb",
+                "max_primary_shard_docs": 200_000_000
+            }),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::util::*;
+
+/// Takes a snapshot of the managed index in the configured repository and mounts it as a
+/// searchable snapshot.
+///
+/// To mount the index from the `backups` repository:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// SearchableSnapshotAction::new("backups");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-searchable-snapshot.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchableSnapshotAction {
+    snapshot_repository: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    force_merge_index: Option<bool>,
+}
+
+impl SearchableSnapshotAction {
+    /// Creates an instance of [`SearchableSnapshotAction`]
+    ///
+    /// - `snapshot_repository` - Repository used to store the snapshot
+    pub fn new(snapshot_repository: impl Into<String>) -> Self {
+        Self {
+            snapshot_repository: snapshot_repository.into(),
+            force_merge_index: None,
+        }
+    }
+
+    /// Force merges the managed index to one segment before taking the snapshot. Defaults to
+    /// `true`.
+    pub fn force_merge_index(mut self, force_merge_index: bool) -> Self {
+        self.force_merge_index = Some(force_merge_index);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            SearchableSnapshotAction::new("backups"),
+            json!({ "snapshot_repository": "backups" }),
+        );
+
+        assert_serialize(
+            SearchableSnapshotAction::new("backups").force_merge_index(false),
+            json!({
+                "snapshot_repository": "backups",
+                "force_merge_index": false
+            }),
+        );
+    }
+}
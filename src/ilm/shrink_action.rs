@@ -0,0 +1,61 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Sets a source index to read-only and shrinks it into a new index with fewer primary shards.
+///
+/// Either `number_of_shards` or `max_primary_shard_size` should be set.
+///
+/// To shrink an index into a single shard:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// ShrinkAction::new().number_of_shards(1);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-shrink.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct ShrinkAction {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_size: Option<Byte>,
+}
+
+impl ShrinkAction {
+    /// Creates an instance of [`ShrinkAction`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of shards to shrink to. Must be a factor of the number of shards in the source
+    /// index.
+    pub fn number_of_shards(mut self, number_of_shards: u32) -> Self {
+        self.number_of_shards = Some(number_of_shards);
+        self
+    }
+
+    /// The max primary shard size for the target index, used to find the optimum number of
+    /// shards for the target index
+    pub fn max_primary_shard_size(mut self, max_primary_shard_size: Byte) -> Self {
+        self.max_primary_shard_size = Some(max_primary_shard_size);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            ShrinkAction::new().number_of_shards(1),
+            json!({ "number_of_shards": 1 }),
+        );
+
+        assert_serialize(
+            ShrinkAction::new().max_primary_shard_size(Byte::Gigabytes(50)),
+            json!({ "max_primary_shard_size": "50gb" }),
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::util::*;
+
+/// A custom analyzer combines zero or more character filters, a tokenizer and zero or more token
+/// filters.
+///
+/// To define a custom analyzer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let analyzer =
+/// AnalyzerDefinition::custom("standard")
+///     .char_filter(["html_strip"])
+///     .filter(["lowercase", "asciifolding"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-custom-analyzer.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "custom")]
+pub struct AnalyzerDefinition {
+    tokenizer: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    char_filter: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    position_increment_gap: Option<u32>,
+}
+
+impl AnalyzerDefinition {
+    /// Creates an instance of [`AnalyzerDefinition`]
+    ///
+    /// - `tokenizer` - A built-in or customised tokenizer
+    pub fn custom(tokenizer: impl Into<String>) -> Self {
+        Self {
+            tokenizer: tokenizer.into(),
+            char_filter: vec![],
+            filter: vec![],
+            position_increment_gap: None,
+        }
+    }
+
+    /// An optional array of built-in or customised character filters
+    pub fn char_filter<I>(mut self, char_filter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.char_filter
+            .extend(char_filter.into_iter().map(Into::into));
+        self
+    }
+
+    /// An optional array of built-in or customised token filters
+    pub fn filter<I>(mut self, filter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.filter.extend(filter.into_iter().map(Into::into));
+        self
+    }
+
+    /// When indexing an array of text values, Elasticsearch inserts a fake "gap" between the
+    /// last term of one value and the first term of the next value to ensure that a phrase query
+    /// doesn't match two terms from different array elements. Defaults to `100`.
+    pub fn position_increment_gap(mut self, position_increment_gap: u32) -> Self {
+        self.position_increment_gap = Some(position_increment_gap);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            AnalyzerDefinition::custom("standard"),
+            json!({
+                "type": "custom",
+                "tokenizer": "standard"
+            }),
+        );
+
+        assert_serialize(
+            AnalyzerDefinition::custom("standard")
+                .char_filter(["html_strip"])
+                .filter(["lowercase", "asciifolding"])
+                .position_increment_gap(10),
+            json!({
+                "type": "custom",
+                "tokenizer": "standard",
+                "char_filter": ["html_strip"],
+                "filter": ["lowercase", "asciifolding"],
+                "position_increment_gap": 10
+            }),
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::indices::*;
+
+analysis_component!(
+    /// A container enum for supported custom character filters
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-charfilters.html>
+    CharFilterDefinition {
+        HtmlStrip(HtmlStripCharFilter),
+        Mapping(MappingCharFilter),
+        PatternReplace(PatternReplaceCharFilter),
+    }
+);
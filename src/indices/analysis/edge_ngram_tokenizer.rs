@@ -0,0 +1,89 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `edge_ngram` tokenizer first breaks text down into words whenever it encounters one of a
+/// list of specified characters, then it emits N-grams of each word where the start of the
+/// N-gram is anchored to the beginning of the word.
+///
+/// To define an edge_ngram tokenizer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let tokenizer =
+/// TokenizerDefinition::edge_ngram(2, 10).token_chars([TokenChar::Letter]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-edgengram-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "edge_ngram")]
+pub struct EdgeNGramTokenizer {
+    min_gram: u32,
+
+    max_gram: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    token_chars: Vec<TokenChar>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    custom_token_chars: Option<String>,
+}
+
+impl TokenizerDefinition {
+    /// Creates an instance of [`EdgeNGramTokenizer`]
+    ///
+    /// - `min_gram` - Minimum length of characters in a gram
+    /// - `max_gram` - Maximum length of characters in a gram
+    pub fn edge_ngram(min_gram: u32, max_gram: u32) -> EdgeNGramTokenizer {
+        EdgeNGramTokenizer {
+            min_gram,
+            max_gram,
+            token_chars: vec![],
+            custom_token_chars: None,
+        }
+    }
+}
+
+impl EdgeNGramTokenizer {
+    /// Character classes that should be included in a token. Defaults to `[]` (keep all
+    /// characters).
+    pub fn token_chars<I>(mut self, token_chars: I) -> Self
+    where
+        I: IntoIterator<Item = TokenChar>,
+    {
+        self.token_chars.extend(token_chars);
+        self
+    }
+
+    /// Custom characters that should be treated as part of a token. For example, setting this to
+    /// `+-_` will make the tokenizer treat the plus, minus and underscore sign as part of a token.
+    pub fn custom_token_chars(mut self, custom_token_chars: impl Into<String>) -> Self {
+        self.custom_token_chars = Some(custom_token_chars.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenizerDefinition::edge_ngram(1, 2),
+            json!({
+                "type": "edge_ngram",
+                "min_gram": 1,
+                "max_gram": 2
+            }),
+        );
+
+        assert_serialize(
+            TokenizerDefinition::edge_ngram(2, 10)
+                .token_chars([TokenChar::Letter, TokenChar::Digit]),
+            json!({
+                "type": "edge_ngram",
+                "min_gram": 2,
+                "max_gram": 10,
+                "token_chars": ["letter", "digit"]
+            }),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `html_strip` character filter strips HTML elements from a text and replaces HTML entities
+/// with their decoded value.
+///
+/// To define an html_strip character filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let char_filter =
+/// CharFilterDefinition::html_strip().escaped_tags(["b"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-htmlstrip-charfilter.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "html_strip")]
+pub struct HtmlStripCharFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    escaped_tags: Vec<String>,
+}
+
+impl CharFilterDefinition {
+    /// Creates an instance of [`HtmlStripCharFilter`]
+    pub fn html_strip() -> HtmlStripCharFilter {
+        HtmlStripCharFilter::default()
+    }
+}
+
+impl HtmlStripCharFilter {
+    /// HTML elements without enclosing angle brackets (`< >`) which should not be stripped
+    pub fn escaped_tags<I>(mut self, escaped_tags: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.escaped_tags
+            .extend(escaped_tags.into_iter().map(Into::into));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            CharFilterDefinition::html_strip(),
+            json!({ "type": "html_strip" }),
+        );
+
+        assert_serialize(
+            CharFilterDefinition::html_strip().escaped_tags(["b", "i"]),
+            json!({
+                "type": "html_strip",
+                "escaped_tags": ["b", "i"]
+            }),
+        );
+    }
+}
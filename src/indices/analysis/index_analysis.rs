@@ -0,0 +1,160 @@
+use crate::indices::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// Analysis components registered on an index and referenced by name from its mappings.
+///
+/// To define a custom analyzer with its own tokenizer and token filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let analysis =
+/// IndexAnalysis::new()
+///     .tokenizer("autocomplete", TokenizerDefinition::edge_ngram(2, 10))
+///     .filter("english_stop", TokenFilterDefinition::stop().stopwords("_english_"))
+///     .analyzer(
+///         "autocomplete",
+///         AnalyzerDefinition::custom("autocomplete").filter(["lowercase", "english_stop"]),
+///     );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IndexAnalysis {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: BTreeMap<String, AnalyzerDefinition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokenizer: BTreeMap<String, TokenizerDefinition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: BTreeMap<String, TokenFilterDefinition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    char_filter: BTreeMap<String, CharFilterDefinition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    normalizer: BTreeMap<String, NormalizerDefinition>,
+}
+
+impl IndexAnalysis {
+    /// Creates an instance of [`IndexAnalysis`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom analyzer under the given name
+    pub fn analyzer(mut self, name: impl Into<String>, analyzer: AnalyzerDefinition) -> Self {
+        let _ = self.analyzer.insert(name.into(), analyzer);
+        self
+    }
+
+    /// Registers a custom tokenizer under the given name
+    pub fn tokenizer(
+        mut self,
+        name: impl Into<String>,
+        tokenizer: impl Into<TokenizerDefinition>,
+    ) -> Self {
+        let _ = self.tokenizer.insert(name.into(), tokenizer.into());
+        self
+    }
+
+    /// Registers a custom token filter under the given name
+    pub fn filter(
+        mut self,
+        name: impl Into<String>,
+        filter: impl Into<TokenFilterDefinition>,
+    ) -> Self {
+        let _ = self.filter.insert(name.into(), filter.into());
+        self
+    }
+
+    /// Registers a custom character filter under the given name
+    pub fn char_filter(
+        mut self,
+        name: impl Into<String>,
+        char_filter: impl Into<CharFilterDefinition>,
+    ) -> Self {
+        let _ = self.char_filter.insert(name.into(), char_filter.into());
+        self
+    }
+
+    /// Registers a custom normalizer under the given name
+    pub fn normalizer(mut self, name: impl Into<String>, normalizer: NormalizerDefinition) -> Self {
+        let _ = self.normalizer.insert(name.into(), normalizer);
+        self
+    }
+}
+
+impl ShouldSkip for IndexAnalysis {
+    fn should_skip(&self) -> bool {
+        self.analyzer.should_skip()
+            && self.tokenizer.should_skip()
+            && self.filter.should_skip()
+            && self.char_filter.should_skip()
+            && self.normalizer.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(IndexAnalysis::new(), json!({}));
+
+        assert_serialize(
+            IndexAnalysis::new()
+                .char_filter(
+                    "emoticons",
+                    CharFilterDefinition::mapping().mappings([":) => _happy_"]),
+                )
+                .tokenizer("autocomplete", TokenizerDefinition::edge_ngram(2, 10))
+                .filter("english_stemmer", TokenFilterDefinition::stemmer("english"))
+                .analyzer(
+                    "autocomplete",
+                    AnalyzerDefinition::custom("autocomplete")
+                        .char_filter(["emoticons"])
+                        .filter(["lowercase", "english_stemmer"]),
+                )
+                .normalizer(
+                    "lowercase",
+                    NormalizerDefinition::custom().filter(["lowercase"]),
+                ),
+            json!({
+                "analyzer": {
+                    "autocomplete": {
+                        "type": "custom",
+                        "tokenizer": "autocomplete",
+                        "char_filter": ["emoticons"],
+                        "filter": ["lowercase", "english_stemmer"]
+                    }
+                },
+                "tokenizer": {
+                    "autocomplete": {
+                        "type": "edge_ngram",
+                        "min_gram": 2,
+                        "max_gram": 10
+                    }
+                },
+                "filter": {
+                    "english_stemmer": {
+                        "type": "stemmer",
+                        "language": "english"
+                    }
+                },
+                "char_filter": {
+                    "emoticons": {
+                        "type": "mapping",
+                        "mappings": [":) => _happy_"]
+                    }
+                },
+                "normalizer": {
+                    "lowercase": {
+                        "type": "custom",
+                        "filter": ["lowercase"]
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `mapping` character filter accepts a map of keys and values. Whenever it encounters a
+/// string of characters that is the same as a key, it replaces them with the value associated
+/// with that key.
+///
+/// To define a mapping character filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let char_filter =
+/// CharFilterDefinition::mapping().mappings([":) => _happy_", ":( => _sad_"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-mapping-charfilter.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "mapping")]
+pub struct MappingCharFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings_path: Option<String>,
+}
+
+impl CharFilterDefinition {
+    /// Creates an instance of [`MappingCharFilter`]
+    pub fn mapping() -> MappingCharFilter {
+        MappingCharFilter::default()
+    }
+}
+
+impl MappingCharFilter {
+    /// Mappings, each element having the form `key => value`
+    pub fn mappings<I>(mut self, mappings: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.mappings.extend(mappings.into_iter().map(Into::into));
+        self
+    }
+
+    /// Path to a file containing `key => value` mappings, relative to the `config` location or
+    /// absolute
+    pub fn mappings_path(mut self, mappings_path: impl Into<String>) -> Self {
+        self.mappings_path = Some(mappings_path.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            CharFilterDefinition::mapping().mappings(["٠ => 0", "١ => 1"]),
+            json!({
+                "type": "mapping",
+                "mappings": ["٠ => 0", "١ => 1"]
+            }),
+        );
+
+        assert_serialize(
+            CharFilterDefinition::mapping().mappings_path("analysis/mapping.txt"),
+            json!({
+                "type": "mapping",
+                "mappings_path": "analysis/mapping.txt"
+            }),
+        );
+    }
+}
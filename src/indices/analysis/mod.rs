@@ -0,0 +1,73 @@
+//! Text analysis is the process of converting unstructured text, like the body of an email or a
+//! product description, into a structured format that's optimized for search.
+//!
+//! Custom analyzers, normalizers, tokenizers, token filters and character filters are defined in
+//! the index settings and referenced by name from the mappings.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html>
+
+macro_rules! analysis_component {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident($component:ty)),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum $name {
+            $(
+                $variant($component),
+            )*
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(c) => c.fmt(f),
+                    )+
+                }
+            }
+        }
+
+        $(
+            impl From<$component> for $name {
+                fn from(c: $component) -> Self {
+                    $name::$variant(c)
+                }
+            }
+        )+
+    };
+}
+
+mod analyzer_definition;
+mod char_filter_definition;
+mod edge_ngram_tokenizer;
+mod html_strip_char_filter;
+mod index_analysis;
+mod mapping_char_filter;
+mod ngram_tokenizer;
+mod normalizer_definition;
+mod pattern_replace_char_filter;
+mod pattern_tokenizer;
+mod shingle_token_filter;
+mod stemmer_token_filter;
+mod stop_token_filter;
+mod synonym_token_filter;
+mod token_filter_definition;
+mod tokenizer_definition;
+
+pub use self::analyzer_definition::*;
+pub use self::char_filter_definition::*;
+pub use self::edge_ngram_tokenizer::*;
+pub use self::html_strip_char_filter::*;
+pub use self::index_analysis::*;
+pub use self::mapping_char_filter::*;
+pub use self::ngram_tokenizer::*;
+pub use self::normalizer_definition::*;
+pub use self::pattern_replace_char_filter::*;
+pub use self::pattern_tokenizer::*;
+pub use self::shingle_token_filter::*;
+pub use self::stemmer_token_filter::*;
+pub use self::stop_token_filter::*;
+pub use self::synonym_token_filter::*;
+pub use self::token_filter_definition::*;
+pub use self::tokenizer_definition::*;
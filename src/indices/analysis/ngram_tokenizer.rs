@@ -0,0 +1,114 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Character classes that should be included in a token produced by n-gram tokenizers.
+/// Elasticsearch will split on characters that don't belong to the classes specified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenChar {
+    /// For example `a`, `b`, `ï` or `京`
+    Letter,
+
+    /// For example `3` or `7`
+    Digit,
+
+    /// For example `" "` or `"\n"`
+    Whitespace,
+
+    /// For example `!` or `"`
+    Punctuation,
+
+    /// For example `$` or `√`
+    Symbol,
+
+    /// Custom characters which need to be set using the `custom_token_chars` setting
+    Custom,
+}
+
+/// The `ngram` tokenizer first breaks text down into words whenever it encounters one of a list
+/// of specified characters, then it emits N-grams of each word of the specified length.
+///
+/// To define an ngram tokenizer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let tokenizer =
+/// TokenizerDefinition::ngram(3, 3).token_chars([TokenChar::Letter, TokenChar::Digit]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-ngram-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "ngram")]
+pub struct NGramTokenizer {
+    min_gram: u32,
+
+    max_gram: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    token_chars: Vec<TokenChar>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    custom_token_chars: Option<String>,
+}
+
+impl TokenizerDefinition {
+    /// Creates an instance of [`NGramTokenizer`]
+    ///
+    /// - `min_gram` - Minimum length of characters in a gram
+    /// - `max_gram` - Maximum length of characters in a gram
+    pub fn ngram(min_gram: u32, max_gram: u32) -> NGramTokenizer {
+        NGramTokenizer {
+            min_gram,
+            max_gram,
+            token_chars: vec![],
+            custom_token_chars: None,
+        }
+    }
+}
+
+impl NGramTokenizer {
+    /// Character classes that should be included in a token. Defaults to `[]` (keep all
+    /// characters).
+    pub fn token_chars<I>(mut self, token_chars: I) -> Self
+    where
+        I: IntoIterator<Item = TokenChar>,
+    {
+        self.token_chars.extend(token_chars);
+        self
+    }
+
+    /// Custom characters that should be treated as part of a token. For example, setting this to
+    /// `+-_` will make the tokenizer treat the plus, minus and underscore sign as part of a token.
+    pub fn custom_token_chars(mut self, custom_token_chars: impl Into<String>) -> Self {
+        self.custom_token_chars = Some(custom_token_chars.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenizerDefinition::ngram(1, 2),
+            json!({
+                "type": "ngram",
+                "min_gram": 1,
+                "max_gram": 2
+            }),
+        );
+
+        assert_serialize(
+            TokenizerDefinition::ngram(3, 3)
+                .token_chars([TokenChar::Letter, TokenChar::Custom])
+                .custom_token_chars("+-_"),
+            json!({
+                "type": "ngram",
+                "min_gram": 3,
+                "max_gram": 3,
+                "token_chars": ["letter", "custom"],
+                "custom_token_chars": "+-_"
+            }),
+        );
+    }
+}
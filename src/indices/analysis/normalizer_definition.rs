@@ -0,0 +1,71 @@
+use crate::util::*;
+
+/// Normalizers are similar to analyzers except that they may only emit a single token. As a
+/// consequence, they do not have a tokenizer and only accept a subset of the available char
+/// filters and token filters.
+///
+/// To define a custom normalizer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let normalizer =
+/// NormalizerDefinition::custom().filter(["lowercase", "asciifolding"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-normalizers.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "custom")]
+pub struct NormalizerDefinition {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    char_filter: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Vec<String>,
+}
+
+impl NormalizerDefinition {
+    /// Creates an instance of [`NormalizerDefinition`]
+    pub fn custom() -> Self {
+        Self::default()
+    }
+
+    /// An optional array of built-in or customised character filters
+    pub fn char_filter<I>(mut self, char_filter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.char_filter
+            .extend(char_filter.into_iter().map(Into::into));
+        self
+    }
+
+    /// An optional array of built-in or customised token filters
+    pub fn filter<I>(mut self, filter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.filter.extend(filter.into_iter().map(Into::into));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(NormalizerDefinition::custom(), json!({ "type": "custom" }));
+
+        assert_serialize(
+            NormalizerDefinition::custom()
+                .char_filter(["quote"])
+                .filter(["lowercase"]),
+            json!({
+                "type": "custom",
+                "char_filter": ["quote"],
+                "filter": ["lowercase"]
+            }),
+        );
+    }
+}
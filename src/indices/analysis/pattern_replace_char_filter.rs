@@ -0,0 +1,72 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `pattern_replace` character filter uses a regular expression to match characters which
+/// should be replaced with the specified replacement string.
+///
+/// To define a pattern_replace character filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let char_filter =
+/// CharFilterDefinition::pattern_replace("(\\d+)-(?=\\d)").replacement("$1_");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-pattern-replace-charfilter.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "pattern_replace")]
+pub struct PatternReplaceCharFilter {
+    pattern: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: Option<String>,
+}
+
+impl CharFilterDefinition {
+    /// Creates an instance of [`PatternReplaceCharFilter`]
+    ///
+    /// - `pattern` - A Java regular expression
+    pub fn pattern_replace(pattern: impl Into<String>) -> PatternReplaceCharFilter {
+        PatternReplaceCharFilter {
+            pattern: pattern.into(),
+            replacement: None,
+            flags: None,
+        }
+    }
+}
+
+impl PatternReplaceCharFilter {
+    /// The replacement string, which can reference capture groups using the `$1`..`$9` syntax
+    pub fn replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+
+    /// Java regular expression flags. Flags should be pipe-separated, eg
+    /// `"CASE_INSENSITIVE|COMMENTS"`.
+    pub fn flags(mut self, flags: impl Into<String>) -> Self {
+        self.flags = Some(flags.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            CharFilterDefinition::pattern_replace("(\\d+)-(?=\\d)")
+                .replacement("$1_")
+                .flags("CASE_INSENSITIVE"),
+            json!({
+                "type": "pattern_replace",
+                "pattern": "(\\d+)-(?=\\d)",
+                "replacement": "$1_",
+                "flags": "CASE_INSENSITIVE"
+            }),
+        );
+    }
+}
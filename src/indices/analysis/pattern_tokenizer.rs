@@ -0,0 +1,80 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `pattern` tokenizer uses a regular expression to either split text into terms whenever it
+/// matches a word separator, or to capture matching text as terms.
+///
+/// To define a pattern tokenizer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let tokenizer =
+/// TokenizerDefinition::pattern(",");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-pattern-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "pattern")]
+pub struct PatternTokenizer {
+    pattern: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    group: Option<i32>,
+}
+
+impl TokenizerDefinition {
+    /// Creates an instance of [`PatternTokenizer`]
+    ///
+    /// - `pattern` - A Java regular expression
+    pub fn pattern(pattern: impl Into<String>) -> PatternTokenizer {
+        PatternTokenizer {
+            pattern: pattern.into(),
+            flags: None,
+            group: None,
+        }
+    }
+}
+
+impl PatternTokenizer {
+    /// Java regular expression flags. Flags should be pipe-separated, eg
+    /// `"CASE_INSENSITIVE|COMMENTS"`.
+    pub fn flags(mut self, flags: impl Into<String>) -> Self {
+        self.flags = Some(flags.into());
+        self
+    }
+
+    /// Which capture group to extract as tokens. Defaults to `-1` (split).
+    pub fn group(mut self, group: i32) -> Self {
+        self.group = Some(group);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenizerDefinition::pattern(","),
+            json!({
+                "type": "pattern",
+                "pattern": ","
+            }),
+        );
+
+        assert_serialize(
+            TokenizerDefinition::pattern("\"((?:\\\\\"|[^\"]|\\\\\")+)\"")
+                .flags("CASE_INSENSITIVE")
+                .group(1),
+            json!({
+                "type": "pattern",
+                "pattern": "\"((?:\\\\\"|[^\"]|\\\\\")+)\"",
+                "flags": "CASE_INSENSITIVE",
+                "group": 1
+            }),
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `shingle` token filter adds shingles, or word n-grams, to a token stream by
+/// concatenating adjacent tokens.
+///
+/// To define a shingle token filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let filter =
+/// TokenFilterDefinition::shingle().min_shingle_size(2).max_shingle_size(3);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-shingle-tokenfilter.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "shingle")]
+pub struct ShingleTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    output_unigrams: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    output_unigrams_if_no_shingles: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    token_separator: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filler_token: Option<String>,
+}
+
+impl TokenFilterDefinition {
+    /// Creates an instance of [`ShingleTokenFilter`]
+    pub fn shingle() -> ShingleTokenFilter {
+        ShingleTokenFilter::default()
+    }
+}
+
+impl ShingleTokenFilter {
+    /// Minimum number of tokens to concatenate when creating shingles. Defaults to `2`.
+    pub fn min_shingle_size(mut self, min_shingle_size: u32) -> Self {
+        self.min_shingle_size = Some(min_shingle_size);
+        self
+    }
+
+    /// Maximum number of tokens to concatenate when creating shingles. Defaults to `2`.
+    pub fn max_shingle_size(mut self, max_shingle_size: u32) -> Self {
+        self.max_shingle_size = Some(max_shingle_size);
+        self
+    }
+
+    /// If `true` (default), the output includes the original input tokens
+    pub fn output_unigrams(mut self, output_unigrams: bool) -> Self {
+        self.output_unigrams = Some(output_unigrams);
+        self
+    }
+
+    /// If `true`, the output includes the original input tokens only if no shingles are
+    /// produced. Defaults to `false`.
+    pub fn output_unigrams_if_no_shingles(mut self, output_unigrams_if_no_shingles: bool) -> Self {
+        self.output_unigrams_if_no_shingles = Some(output_unigrams_if_no_shingles);
+        self
+    }
+
+    /// Separator used to concatenate adjacent tokens to form a shingle. Defaults to a space.
+    pub fn token_separator(mut self, token_separator: impl Into<String>) -> Self {
+        self.token_separator = Some(token_separator.into());
+        self
+    }
+
+    /// String used in shingles as a replacement for empty positions that do not contain a
+    /// token. Defaults to an underscore.
+    pub fn filler_token(mut self, filler_token: impl Into<String>) -> Self {
+        self.filler_token = Some(filler_token.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilterDefinition::shingle(),
+            json!({ "type": "shingle" }),
+        );
+
+        assert_serialize(
+            TokenFilterDefinition::shingle()
+                .min_shingle_size(2)
+                .max_shingle_size(3)
+                .output_unigrams(false)
+                .output_unigrams_if_no_shingles(true)
+                .token_separator("+")
+                .filler_token(""),
+            json!({
+                "type": "shingle",
+                "min_shingle_size": 2,
+                "max_shingle_size": 3,
+                "output_unigrams": false,
+                "output_unigrams_if_no_shingles": true,
+                "token_separator": "+",
+                "filler_token": ""
+            }),
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::indices::*;
+
+/// The `stemmer` token filter provides algorithmic stemming for several languages, some with
+/// additional variants.
+///
+/// To define a stemmer token filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let filter =
+/// TokenFilterDefinition::stemmer("light_german");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-stemmer-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "stemmer")]
+pub struct StemmerTokenFilter {
+    language: String,
+}
+
+impl TokenFilterDefinition {
+    /// Creates an instance of [`StemmerTokenFilter`]
+    ///
+    /// - `language` - Language-dependent stemming algorithm used to stem tokens, such as
+    ///   `english` or `light_german`
+    pub fn stemmer(language: impl Into<String>) -> StemmerTokenFilter {
+        StemmerTokenFilter {
+            language: language.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilterDefinition::stemmer("english"),
+            json!({
+                "type": "stemmer",
+                "language": "english"
+            }),
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::analyze::*;
+use crate::indices::*;
+use crate::util::*;
+
+/// The `stop` token filter removes stop words from a token stream.
+///
+/// To define a stop token filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let filter =
+/// TokenFilterDefinition::stop().stopwords(["and", "is", "the"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-stop-tokenfilter.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "stop")]
+pub struct StopTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords: Option<StringOrVecString>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords_path: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_case: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    remove_trailing: Option<bool>,
+}
+
+impl TokenFilterDefinition {
+    /// Creates an instance of [`StopTokenFilter`]
+    pub fn stop() -> StopTokenFilter {
+        StopTokenFilter::default()
+    }
+}
+
+impl StopTokenFilter {
+    /// Language value, such as `_arabic_` or `_thai_`, or an array of stop words. Defaults to
+    /// `_english_`.
+    pub fn stopwords(mut self, stopwords: impl Into<StringOrVecString>) -> Self {
+        self.stopwords = Some(stopwords.into());
+        self
+    }
+
+    /// Path to a file that contains a list of stop words to remove, relative to the `config`
+    /// location or absolute
+    pub fn stopwords_path(mut self, stopwords_path: impl Into<String>) -> Self {
+        self.stopwords_path = Some(stopwords_path.into());
+        self
+    }
+
+    /// If `true`, stop word matching is case insensitive. Defaults to `false`.
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = Some(ignore_case);
+        self
+    }
+
+    /// If `true` (default), the last token of a stream is removed if it's a stop word
+    pub fn remove_trailing(mut self, remove_trailing: bool) -> Self {
+        self.remove_trailing = Some(remove_trailing);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilterDefinition::stop(), json!({ "type": "stop" }));
+
+        assert_serialize(
+            TokenFilterDefinition::stop()
+                .stopwords("_english_")
+                .ignore_case(true),
+            json!({
+                "type": "stop",
+                "stopwords": "_english_",
+                "ignore_case": true
+            }),
+        );
+
+        assert_serialize(
+            TokenFilterDefinition::stop()
+                .stopwords(["and", "is", "the"])
+                .remove_trailing(false),
+            json!({
+                "type": "stop",
+                "stopwords": ["and", "is", "the"],
+                "remove_trailing": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `synonym` token filter allows to easily handle synonyms during the analysis process.
+///
+/// To define a synonym token filter:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let filter =
+/// TokenFilterDefinition::synonym().synonyms(["i-pod, i pod => ipod", "universe, cosmos"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-synonym-tokenfilter.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "synonym")]
+pub struct SynonymTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms_path: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms_set: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    expand: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lenient: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    updateable: Option<bool>,
+}
+
+impl TokenFilterDefinition {
+    /// Creates an instance of [`SynonymTokenFilter`]
+    pub fn synonym() -> SynonymTokenFilter {
+        SynonymTokenFilter::default()
+    }
+}
+
+impl SynonymTokenFilter {
+    /// Inline synonym rules in Solr or WordNet format
+    pub fn synonyms<I>(mut self, synonyms: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.synonyms.extend(synonyms.into_iter().map(Into::into));
+        self
+    }
+
+    /// Path to a synonyms file, relative to the `config` location or absolute
+    pub fn synonyms_path(mut self, synonyms_path: impl Into<String>) -> Self {
+        self.synonyms_path = Some(synonyms_path.into());
+        self
+    }
+
+    /// Name of a synonyms set created using the synonyms management APIs
+    pub fn synonyms_set(mut self, synonyms_set: impl Into<String>) -> Self {
+        self.synonyms_set = Some(synonyms_set.into());
+        self
+    }
+
+    /// If `true` (default), equivalent synonym rules map all terms to each other; otherwise all
+    /// terms are mapped to the first synonym in the rule.
+    pub fn expand(mut self, expand: bool) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// If `true`, ignores errors while parsing the synonym configuration. Defaults to `false`.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = Some(lenient);
+        self
+    }
+
+    /// If `true`, allows reloading search analyzers to pick up changes to synonym files. Only
+    /// to be used for search analyzers. Defaults to `false`.
+    pub fn updateable(mut self, updateable: bool) -> Self {
+        self.updateable = Some(updateable);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilterDefinition::synonym(),
+            json!({ "type": "synonym" }),
+        );
+
+        assert_serialize(
+            TokenFilterDefinition::synonym()
+                .synonyms(["universe, cosmos", "i-pod, i pod => ipod"])
+                .expand(false)
+                .lenient(true)
+                .updateable(true),
+            json!({
+                "type": "synonym",
+                "synonyms": ["universe, cosmos", "i-pod, i pod => ipod"],
+                "expand": false,
+                "lenient": true,
+                "updateable": true
+            }),
+        );
+
+        assert_serialize(
+            TokenFilterDefinition::synonym().synonyms_path("analysis/synonym.txt"),
+            json!({
+                "type": "synonym",
+                "synonyms_path": "analysis/synonym.txt"
+            }),
+        );
+    }
+}
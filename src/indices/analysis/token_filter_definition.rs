@@ -0,0 +1,13 @@
+use crate::indices::*;
+
+analysis_component!(
+    /// A container enum for supported custom token filters
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenfilters.html>
+    TokenFilterDefinition {
+        Synonym(SynonymTokenFilter),
+        Stop(StopTokenFilter),
+        Stemmer(StemmerTokenFilter),
+        Shingle(ShingleTokenFilter),
+    }
+);
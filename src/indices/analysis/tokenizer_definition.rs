@@ -0,0 +1,12 @@
+use crate::indices::*;
+
+analysis_component!(
+    /// A container enum for supported custom tokenizers
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenizers.html>
+    TokenizerDefinition {
+        NGram(NGramTokenizer),
+        EdgeNGram(EdgeNGramTokenizer),
+        Pattern(PatternTokenizer),
+    }
+);
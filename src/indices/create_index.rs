@@ -0,0 +1,91 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Creates a new index.
+///
+/// When creating an index, you can specify the settings for the index and the mappings for
+/// the fields in the index.
+///
+/// To create an index with explicit settings and mappings:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// CreateIndex::new()
+///     .settings(IndexSettings::new().number_of_shards(3))
+///     .mappings(
+///         Mappings::new()
+///             .dynamic(Dynamic::Strict)
+///             .property("title", Property::text())
+///             .property("created_at", Property::date()),
+///     );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct CreateIndex {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    settings: Option<IndexSettings>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings: Option<Mappings>,
+}
+
+impl CreateIndex {
+    /// Creates an instance of [`CreateIndex`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configuration options for the index
+    pub fn settings(mut self, settings: impl Into<IndexSettings>) -> Self {
+        self.settings = Some(settings.into());
+        self
+    }
+
+    /// Mapping for fields in the index
+    pub fn mappings(mut self, mappings: impl Into<Mappings>) -> Self {
+        self.mappings = Some(mappings.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(CreateIndex::new(), json!({}));
+
+        assert_serialize(
+            CreateIndex::new().mappings(Mappings::new().property("title", Property::keyword())),
+            json!({
+                "mappings": {
+                    "properties": {
+                        "title": { "type": "keyword" }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            CreateIndex::new()
+                .settings(
+                    IndexSettings::new()
+                        .number_of_shards(1)
+                        .number_of_replicas(0),
+                )
+                .mappings(Mappings::new().property("title", Property::keyword())),
+            json!({
+                "settings": {
+                    "number_of_shards": 1,
+                    "number_of_replicas": 0
+                },
+                "mappings": {
+                    "properties": {
+                        "title": { "type": "keyword" }
+                    }
+                }
+            }),
+        );
+    }
+}
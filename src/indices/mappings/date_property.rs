@@ -0,0 +1,133 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// JSON doesn’t have a date data type, so dates in Elasticsearch can either be strings
+/// containing formatted dates, numbers representing milliseconds-since-the-epoch or
+/// numbers representing seconds-since-the-epoch.
+///
+/// Date formats can be customised, but if no `format` is specified then it uses the default
+/// `strict_date_optional_time||epoch_millis`.
+///
+/// To create a date field mapping accepting multiple formats:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::date().format("yyyy-MM-dd HH:mm:ss||yyyy-MM-dd||epoch_millis");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "date")]
+pub struct DateProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<DateFormat>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    locale: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`DateProperty`]
+    pub fn date() -> DateProperty {
+        DateProperty::default()
+    }
+}
+
+impl DateProperty {
+    /// The date format(s) that can be parsed, multiple formats are separated by `||`.
+    /// Defaults to `strict_date_optional_time||epoch_millis`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html>
+    pub fn format(mut self, format: impl Into<DateFormat>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// The locale to use when parsing dates since months do not have the same names and/or
+    /// abbreviations in all languages. The default is the `ROOT` locale.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed dates are ignored. If `false` (default), malformed dates throw an
+    /// exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be quickly searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a date value in one of the configured `format`'s as the field which is
+    /// substituted for any explicit `null` values. Defaults to `null`, which means the field is
+    /// treated as missing.
+    pub fn null_value(mut self, null_value: impl Into<String>) -> Self {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::date(), json!({ "type": "date" }));
+
+        assert_serialize(
+            Property::date()
+                .format("yyyy-MM-dd||epoch_millis")
+                .locale("de")
+                .doc_values(true)
+                .ignore_malformed(false)
+                .index(true)
+                .null_value("1970-01-01")
+                .store(true),
+            json!({
+                "type": "date",
+                "format": "yyyy-MM-dd||epoch_millis",
+                "locale": "de",
+                "doc_values": true,
+                "ignore_malformed": false,
+                "index": true,
+                "null_value": "1970-01-01",
+                "store": true
+            }),
+        );
+    }
+}
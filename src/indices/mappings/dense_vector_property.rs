@@ -0,0 +1,100 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The vector similarity metric to use in kNN search.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html#dense-vector-similarity>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenseVectorSimilarity {
+    /// Computes similarity based on the L2 distance (also known as Euclidean distance) between
+    /// the vectors
+    L2Norm,
+
+    /// Computes the dot product of two unit vectors. All vectors, including the document and
+    /// query vectors, must be normalized to unit length.
+    DotProduct,
+
+    /// Computes the cosine similarity
+    Cosine,
+
+    /// Computes the maximum inner product of two vectors, vectors do not need to be normalized
+    MaxInnerProduct,
+}
+
+/// The `dense_vector` field type stores dense vectors of numeric values. Dense vector fields are
+/// primarily used for
+/// [k-nearest neighbor (kNN) search](https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html).
+///
+/// To create an indexed dense vector field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::dense_vector(384)
+///     .index(true)
+///     .similarity(DenseVectorSimilarity::Cosine);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "dense_vector")]
+pub struct DenseVectorProperty {
+    dims: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<DenseVectorSimilarity>,
+}
+
+impl Property {
+    /// Creates an instance of [`DenseVectorProperty`]
+    ///
+    /// - `dims` - Number of vector dimensions. Can’t exceed `2048`.
+    pub fn dense_vector(dims: u32) -> DenseVectorProperty {
+        DenseVectorProperty {
+            dims,
+            index: None,
+            similarity: None,
+        }
+    }
+}
+
+impl DenseVectorProperty {
+    /// If `true`, you can search this field using the kNN search API
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// The vector similarity metric to use in kNN search
+    pub fn similarity(mut self, similarity: DenseVectorSimilarity) -> Self {
+        self.similarity = Some(similarity);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::dense_vector(3),
+            json!({ "type": "dense_vector", "dims": 3 }),
+        );
+
+        assert_serialize(
+            Property::dense_vector(128)
+                .index(true)
+                .similarity(DenseVectorSimilarity::DotProduct),
+            json!({
+                "type": "dense_vector",
+                "dims": 128,
+                "index": true,
+                "similarity": "dot_product"
+            }),
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use serde::ser::{Serialize, Serializer};
+
+/// When Elasticsearch detects a new field in a document, it dynamically adds the field to the
+/// type mapping by default. The `dynamic` parameter controls this behavior.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dynamic {
+    /// New fields are added to the mapping (default)
+    True,
+
+    /// New fields are ignored. These fields will not be indexed or searchable, but will still
+    /// appear in the `_source` field of returned hits. These fields will not be added to the
+    /// mapping, and new fields must be added explicitly.
+    False,
+
+    /// New fields are added to the mapping as
+    /// [runtime fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime.html).
+    /// These fields are not indexed, and are loaded from `_source` at query time.
+    Runtime,
+
+    /// If new fields are detected, an exception is thrown and the document is rejected. New
+    /// fields must be explicitly added to the mapping.
+    Strict,
+}
+
+impl Serialize for Dynamic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::True => true.serialize(serializer),
+            Self::False => false.serialize(serializer),
+            Self::Runtime => "runtime".serialize(serializer),
+            Self::Strict => "strict".serialize(serializer),
+        }
+    }
+}
+
+impl From<bool> for Dynamic {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Dynamic::True,
+                Dynamic::False,
+                Dynamic::Runtime,
+                Dynamic::Strict,
+            ],
+            json!([true, false, "runtime", "strict"]),
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Dynamic templates allow you greater control of how Elasticsearch maps your data beyond the
+/// default dynamic field mapping rules.
+///
+/// Templates are processed in order — the first matching template wins. The matching conditions
+/// can be based on the detected data type (`match_mapping_type`), the field name
+/// (`match`/`unmatch`) or the full dotted path to the field (`path_match`/`path_unmatch`).
+///
+/// To map all string fields as keywords:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let template =
+/// DynamicTemplate::new("strings_as_keywords", Property::keyword().ignore_above(256))
+///     .match_mapping_type("string");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DynamicTemplate(KeyValuePair<String, DynamicTemplateInner>);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct DynamicTemplateInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    match_mapping_type: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "match")]
+    r#match: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unmatch: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    path_match: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    path_unmatch: Option<String>,
+
+    mapping: Property,
+}
+
+impl DynamicTemplate {
+    /// Creates an instance of [`DynamicTemplate`]
+    ///
+    /// - `name` - Template name
+    /// - `mapping` - The mapping that matched fields should use
+    pub fn new(name: impl Into<String>, mapping: impl Into<Property>) -> Self {
+        Self(KeyValuePair::new(
+            name.into(),
+            DynamicTemplateInner {
+                match_mapping_type: None,
+                r#match: None,
+                unmatch: None,
+                path_match: None,
+                path_unmatch: None,
+                mapping: mapping.into(),
+            },
+        ))
+    }
+
+    /// Matches on the data type that Elasticsearch detects, such as `string`, `long` or `object`
+    pub fn match_mapping_type(mut self, match_mapping_type: impl Into<String>) -> Self {
+        self.0.value.match_mapping_type = Some(match_mapping_type.into());
+        self
+    }
+
+    /// Pattern to match on the field name
+    pub fn r#match(mut self, pattern: impl Into<String>) -> Self {
+        self.0.value.r#match = Some(pattern.into());
+        self
+    }
+
+    /// Pattern to exclude fields matched by `match`
+    pub fn unmatch(mut self, pattern: impl Into<String>) -> Self {
+        self.0.value.unmatch = Some(pattern.into());
+        self
+    }
+
+    /// Pattern to match on the full dotted path to the field
+    pub fn path_match(mut self, pattern: impl Into<String>) -> Self {
+        self.0.value.path_match = Some(pattern.into());
+        self
+    }
+
+    /// Pattern to exclude fields matched by `path_match`
+    pub fn path_unmatch(mut self, pattern: impl Into<String>) -> Self {
+        self.0.value.path_unmatch = Some(pattern.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            DynamicTemplate::new("longs_as_strings", Property::keyword())
+                .match_mapping_type("string")
+                .r#match("long_*")
+                .unmatch("*_text"),
+            json!({
+                "longs_as_strings": {
+                    "match_mapping_type": "string",
+                    "match": "long_*",
+                    "unmatch": "*_text",
+                    "mapping": { "type": "keyword" }
+                }
+            }),
+        );
+
+        assert_serialize(
+            DynamicTemplate::new("full_name", Property::text())
+                .path_match("name.*")
+                .path_unmatch("*.middle"),
+            json!({
+                "full_name": {
+                    "path_match": "name.*",
+                    "path_unmatch": "*.middle",
+                    "mapping": { "type": "text" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// Fields of type `geo_point` accept latitude-longitude pairs, which can be used to find
+/// geo-points within a bounding box, within a certain distance of a central point, to aggregate
+/// documents geographically or to sort documents by distance.
+///
+/// To create a geo_point field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::geo_point().ignore_malformed(true);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "geo_point")]
+pub struct GeoPointProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_z_value: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<GeoPoint>,
+}
+
+impl Property {
+    /// Creates an instance of [`GeoPointProperty`]
+    pub fn geo_point() -> GeoPointProperty {
+        GeoPointProperty::default()
+    }
+}
+
+impl GeoPointProperty {
+    /// If `true`, malformed geo-points are ignored. If `false` (default), malformed geo-points
+    /// throw an exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// If `true` (default) three dimension points will be accepted (stored in source) but only
+    /// latitude and longitude values will be indexed; the third dimension is ignored.
+    pub fn ignore_z_value(mut self, ignore_z_value: bool) -> Self {
+        self.ignore_z_value = Some(ignore_z_value);
+        self
+    }
+
+    /// Should the field be quickly searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a geopoint value which is substituted for any explicit `null` values.
+    /// Defaults to `null`, which means the field is treated as missing.
+    pub fn null_value(mut self, null_value: GeoPoint) -> Self {
+        self.null_value = Some(null_value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::geo_point(), json!({ "type": "geo_point" }));
+
+        assert_serialize(
+            Property::geo_point()
+                .ignore_malformed(true)
+                .ignore_z_value(false)
+                .index(true)
+                .null_value(GeoPoint::coordinates(1.0, 2.0)),
+            json!({
+                "type": "geo_point",
+                "ignore_malformed": true,
+                "ignore_z_value": false,
+                "index": true,
+                "null_value": [2.0, 1.0]
+            }),
+        );
+    }
+}
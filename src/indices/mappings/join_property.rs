@@ -0,0 +1,83 @@
+use crate::indices::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// The `join` data type is a special field that creates parent/child relation within documents
+/// of the same index. The `relations` section defines a set of possible relations within the
+/// documents, each relation being a parent name and a child name.
+///
+/// To create a join field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::join()
+///     .relation("question", ["answer", "comment"])
+///     .relation("answer", ["vote"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "join")]
+pub struct JoinProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    relations: BTreeMap<String, Vec<String>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    eager_global_ordinals: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`JoinProperty`]
+    pub fn join() -> JoinProperty {
+        JoinProperty::default()
+    }
+}
+
+impl JoinProperty {
+    /// Defines a parent/child relation
+    ///
+    /// - `parent` - Parent relation name
+    /// - `children` - Child relation names
+    pub fn relation<P, I>(mut self, parent: P, children: I) -> Self
+    where
+        P: Into<String>,
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.relations
+            .entry(parent.into())
+            .or_default()
+            .extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Whether global ordinals should be built eagerly on refresh. Defaults to `true`.
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::join(), json!({ "type": "join" }));
+
+        assert_serialize(
+            Property::join()
+                .relation("question", ["answer", "comment"])
+                .relation("answer", ["vote"])
+                .eager_global_ordinals(false),
+            json!({
+                "type": "join",
+                "relations": {
+                    "question": ["answer", "comment"],
+                    "answer": ["vote"]
+                },
+                "eager_global_ordinals": false
+            }),
+        );
+    }
+}
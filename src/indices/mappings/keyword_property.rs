@@ -0,0 +1,149 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The keyword family includes the `keyword` field type, which is used for structured content
+/// such as IDs, email addresses, hostnames, status codes, zip codes, or tags.
+///
+/// Keyword fields are often used in sorting, aggregations, and term-level queries.
+///
+/// To create a keyword field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::keyword()
+///     .ignore_above(256)
+///     .normalizer("lowercase");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "keyword")]
+pub struct KeywordProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_above: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    normalizer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fields: Properties,
+}
+
+impl Property {
+    /// Creates an instance of [`KeywordProperty`]
+    pub fn keyword() -> KeywordProperty {
+        KeywordProperty::default()
+    }
+}
+
+impl KeywordProperty {
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Do not index any string longer than this value. Defaults to `2147483647` so that all
+    /// values would be accepted.
+    pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+        self.ignore_above = Some(ignore_above);
+        self
+    }
+
+    /// Should the field be quickly searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// How to pre-process the keyword prior to indexing. Defaults to `null`, meaning the keyword
+    /// is kept as-is.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/normalizer.html>
+    pub fn normalizer(mut self, normalizer: impl Into<String>) -> Self {
+        self.normalizer = Some(normalizer.into());
+        self
+    }
+
+    /// Accepts a string value which is substituted for any explicit `null` values. Defaults to
+    /// `null`, which means the field is treated as missing.
+    pub fn null_value(mut self, null_value: impl Into<String>) -> Self {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Copies the values of this field into a group field, which can then be queried as a
+    /// single field
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/copy-to.html>
+    pub fn copy_to(mut self, field: impl Into<String>) -> Self {
+        self.copy_to.push(field.into());
+        self
+    }
+
+    /// Adds a multi-field, which allows the same string value to be indexed in multiple ways
+    /// for different purposes
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-fields.html>
+    pub fn field(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        let _ = self.fields.insert(name.into(), property.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::keyword(), json!({ "type": "keyword" }));
+
+        assert_serialize(
+            Property::keyword()
+                .doc_values(false)
+                .ignore_above(256)
+                .index(true)
+                .normalizer("lowercase")
+                .null_value("NULL")
+                .store(true)
+                .copy_to("all")
+                .field("text", Property::text()),
+            json!({
+                "type": "keyword",
+                "doc_values": false,
+                "ignore_above": 256,
+                "index": true,
+                "normalizer": "lowercase",
+                "null_value": "NULL",
+                "store": true,
+                "copy_to": ["all"],
+                "fields": {
+                    "text": { "type": "text" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Mapping is the process of defining how a document, and the fields it contains, are stored and
+//! indexed.
+//!
+//! Each document is a collection of fields, which each have their own
+//! [data type](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-types.html).
+//! When mapping your data, you create a mapping definition, which contains a list of fields that
+//! are pertinent to the document. A mapping definition also includes metadata fields, like the
+//! `_source` field, which customize how a document’s associated metadata is handled.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping.html>
+
+mod date_property;
+mod dense_vector_property;
+mod dynamic;
+mod dynamic_template;
+mod geo_point_property;
+mod join_property;
+mod keyword_property;
+mod nested_property;
+mod numeric_property;
+mod object_property;
+mod text_property;
+
+pub use self::date_property::*;
+pub use self::dense_vector_property::*;
+pub use self::dynamic::*;
+pub use self::dynamic_template::*;
+pub use self::geo_point_property::*;
+pub use self::join_property::*;
+pub use self::keyword_property::*;
+pub use self::nested_property::*;
+pub use self::numeric_property::*;
+pub use self::object_property::*;
+pub use self::text_property::*;
+
+use crate::search::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+macro_rules! property {
+    ($($variant:ident($property:ty)),+ $(,)?) => {
+        /// A container enum for supported Elasticsearch field data types
+        #[derive(Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum Property {
+            $(
+                $variant($property),
+            )*
+        }
+
+        impl std::fmt::Debug for Property {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(p) => p.fmt(f),
+                    )+
+                }
+            }
+        }
+
+        $(
+            impl From<$property> for Property {
+                fn from(p: $property) -> Self {
+                    Property::$variant(p)
+                }
+            }
+        )+
+    };
+}
+
+property!(
+    Keyword(KeywordProperty),
+    Text(TextProperty),
+    Numeric(NumericProperty),
+    Date(DateProperty),
+    Object(ObjectProperty),
+    Nested(NestedProperty),
+    GeoPoint(GeoPointProperty),
+    DenseVector(DenseVectorProperty),
+    Join(JoinProperty),
+);
+
+/// Type alias for a collection of field mappings
+pub type Properties = BTreeMap<String, Property>;
+
+/// Mapping definition of an index, also used as the body of the update mapping API.
+///
+/// To create mappings:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let mappings =
+/// Mappings::new()
+///     .dynamic(Dynamic::Strict)
+///     .dynamic_template(
+///         DynamicTemplate::new("strings_as_keywords", Property::keyword())
+///             .match_mapping_type("string"),
+///     )
+///     .property(
+///         "title",
+///         Property::text()
+///             .analyzer("english")
+///             .field("raw", Property::keyword()),
+///     )
+///     .property("price", Property::scaled_float(100))
+///     .property("published_at", Property::date().format("strict_date_optional_time"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Mappings {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic_templates: Vec<DynamicTemplate>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    runtime: BTreeMap<String, RuntimeMapping>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Properties,
+}
+
+impl Mappings {
+    /// Creates an instance of [`Mappings`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Controls whether new fields are added dynamically to the index
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic.html>
+    pub fn dynamic(mut self, dynamic: impl Into<Dynamic>) -> Self {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// Pushes a custom rule to configure the mapping for dynamically added fields.
+    /// Templates are processed in order, the first matching template wins.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html>
+    pub fn dynamic_template(mut self, dynamic_template: DynamicTemplate) -> Self {
+        self.dynamic_templates.push(dynamic_template);
+        self
+    }
+
+    /// Adds a runtime field to the mapping, runtime fields are not indexed and are evaluated
+    /// at query time
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime-mapping-fields.html>
+    pub fn runtime(mut self, name: impl Into<String>, mapping: RuntimeMapping) -> Self {
+        let _ = self.runtime.insert(name.into(), mapping);
+        self
+    }
+
+    /// Adds field mapping
+    pub fn property(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        let _ = self.properties.insert(name.into(), property.into());
+        self
+    }
+}
+
+impl ShouldSkip for Mappings {
+    fn should_skip(&self) -> bool {
+        self.dynamic.should_skip()
+            && self.dynamic_templates.should_skip()
+            && self.runtime.should_skip()
+            && self.properties.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Mappings::new(), json!({}));
+
+        assert_serialize(
+            Mappings::new()
+                .dynamic(false)
+                .dynamic_template(
+                    DynamicTemplate::new("strings", Property::keyword())
+                        .match_mapping_type("string"),
+                )
+                .runtime("day_of_week", RuntimeMapping::keyword("emit('Monday')"))
+                .property("title", Property::text().field("raw", Property::keyword()))
+                .property("price", Property::double()),
+            json!({
+                "dynamic": false,
+                "dynamic_templates": [
+                    {
+                        "strings": {
+                            "match_mapping_type": "string",
+                            "mapping": { "type": "keyword" }
+                        }
+                    }
+                ],
+                "runtime": {
+                    "day_of_week": {
+                        "type": "keyword",
+                        "script": { "source": "emit('Monday')" }
+                    }
+                },
+                "properties": {
+                    "title": {
+                        "type": "text",
+                        "fields": {
+                            "raw": { "type": "keyword" }
+                        }
+                    },
+                    "price": { "type": "double" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `nested` type is a specialised version of the `object` data type that allows arrays of
+/// objects to be indexed in a way that they can be queried independently of each other.
+///
+/// To create a nested field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::nested()
+///     .property("first", Property::keyword())
+///     .property("last", Property::keyword());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/nested.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "nested")]
+pub struct NestedProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_in_parent: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_in_root: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Properties,
+}
+
+impl Property {
+    /// Creates an instance of [`NestedProperty`]
+    pub fn nested() -> NestedProperty {
+        NestedProperty::default()
+    }
+}
+
+impl NestedProperty {
+    /// Whether or not new `properties` should be added dynamically to an existing nested
+    /// object
+    pub fn dynamic(mut self, dynamic: impl Into<Dynamic>) -> Self {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// If `true`, all fields in the nested object are also added to the parent document as
+    /// standard (flat) fields. Defaults to `false`.
+    pub fn include_in_parent(mut self, include_in_parent: bool) -> Self {
+        self.include_in_parent = Some(include_in_parent);
+        self
+    }
+
+    /// If `true`, all fields in the nested object are also added to the root document as
+    /// standard (flat) fields. Defaults to `false`.
+    pub fn include_in_root(mut self, include_in_root: bool) -> Self {
+        self.include_in_root = Some(include_in_root);
+        self
+    }
+
+    /// Adds a field mapping within the nested object
+    pub fn property(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        let _ = self.properties.insert(name.into(), property.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::nested(), json!({ "type": "nested" }));
+
+        assert_serialize(
+            Property::nested()
+                .dynamic(true)
+                .include_in_parent(true)
+                .include_in_root(false)
+                .property("first", Property::keyword()),
+            json!({
+                "type": "nested",
+                "dynamic": true,
+                "include_in_parent": true,
+                "include_in_root": false,
+                "properties": {
+                    "first": { "type": "keyword" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// Numeric field types
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumericType {
+    /// A signed 64-bit integer with a minimum value of `-2^63` and a maximum value of `2^63-1`
+    Long,
+
+    /// A signed 32-bit integer with a minimum value of `-2^31` and a maximum value of `2^31-1`
+    Integer,
+
+    /// A signed 16-bit integer with a minimum value of `-32,768` and a maximum value of `32,767`
+    Short,
+
+    /// A signed 8-bit integer with a minimum value of `-128` and a maximum value of `127`
+    Byte,
+
+    /// A double-precision 64-bit IEEE 754 floating point number, restricted to finite values
+    Double,
+
+    /// A single-precision 32-bit IEEE 754 floating point number, restricted to finite values
+    Float,
+
+    /// A half-precision 16-bit IEEE 754 floating point number, restricted to finite values
+    HalfFloat,
+
+    /// A floating point number that is backed by a `long`, scaled by a fixed `double` scaling
+    /// factor
+    ScaledFloat,
+
+    /// An unsigned 64-bit integer with a minimum value of `0` and a maximum value of `2^64-1`
+    UnsignedLong,
+}
+
+/// Numeric field mapping
+///
+/// To create a `scaled_float` field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::scaled_float(100)
+///     .coerce(false)
+///     .null_value(0);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NumericProperty {
+    r#type: NumericType,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    scaling_factor: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`NumericProperty`]
+    ///
+    /// - `type` - Numeric field type
+    pub fn numeric(r#type: NumericType) -> NumericProperty {
+        NumericProperty {
+            r#type,
+            scaling_factor: None,
+            coerce: None,
+            doc_values: None,
+            ignore_malformed: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Long`] type
+    pub fn long() -> NumericProperty {
+        Self::numeric(NumericType::Long)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Integer`] type
+    pub fn integer() -> NumericProperty {
+        Self::numeric(NumericType::Integer)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Short`] type
+    pub fn short() -> NumericProperty {
+        Self::numeric(NumericType::Short)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Byte`] type
+    pub fn byte() -> NumericProperty {
+        Self::numeric(NumericType::Byte)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Double`] type
+    pub fn double() -> NumericProperty {
+        Self::numeric(NumericType::Double)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::Float`] type
+    pub fn float() -> NumericProperty {
+        Self::numeric(NumericType::Float)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::HalfFloat`] type
+    pub fn half_float() -> NumericProperty {
+        Self::numeric(NumericType::HalfFloat)
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::ScaledFloat`] type
+    ///
+    /// - `scaling_factor` - The scaling factor to use when encoding values
+    pub fn scaled_float(scaling_factor: impl Into<Number>) -> NumericProperty {
+        let mut property = Self::numeric(NumericType::ScaledFloat);
+        property.scaling_factor = Some(scaling_factor.into());
+        property
+    }
+
+    /// Creates an instance of [`NumericProperty`] with [`NumericType::UnsignedLong`] type
+    pub fn unsigned_long() -> NumericProperty {
+        Self::numeric(NumericType::UnsignedLong)
+    }
+}
+
+impl NumericProperty {
+    /// Try to convert strings to numbers and truncate fractions for integers.
+    /// Defaults to `true`.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed numbers are ignored. If `false` (default), malformed numbers throw
+    /// an exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be quickly searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a numeric value of the same type as the field which is substituted for any
+    /// explicit `null` values. Defaults to `null`, which means the field is treated as missing.
+    pub fn null_value(mut self, null_value: impl Into<Number>) -> Self {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Property::long(),
+                Property::integer(),
+                Property::short(),
+                Property::byte(),
+                Property::double(),
+                Property::float(),
+                Property::half_float(),
+                Property::unsigned_long(),
+            ],
+            json!([
+                { "type": "long" },
+                { "type": "integer" },
+                { "type": "short" },
+                { "type": "byte" },
+                { "type": "double" },
+                { "type": "float" },
+                { "type": "half_float" },
+                { "type": "unsigned_long" },
+            ]),
+        );
+
+        assert_serialize(
+            Property::scaled_float(100)
+                .coerce(false)
+                .doc_values(true)
+                .ignore_malformed(true)
+                .index(false)
+                .null_value(0)
+                .store(true),
+            json!({
+                "type": "scaled_float",
+                "scaling_factor": 100,
+                "coerce": false,
+                "doc_values": true,
+                "ignore_malformed": true,
+                "index": false,
+                "null_value": 0,
+                "store": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// JSON documents are hierarchical in nature: the document may contain inner objects which, in
+/// turn, may contain inner objects themselves. Internally, such documents are indexed as a
+/// simple, flat list of key-value pairs.
+///
+/// To create an object field mapping:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::object()
+///     .property("age", Property::integer())
+///     .property("name", Property::text());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/object.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "object")]
+pub struct ObjectProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Properties,
+}
+
+impl Property {
+    /// Creates an instance of [`ObjectProperty`]
+    pub fn object() -> ObjectProperty {
+        ObjectProperty::default()
+    }
+}
+
+impl ObjectProperty {
+    /// Whether or not new `properties` should be added dynamically to an existing object.
+    /// Defaults to the setting of the parent object.
+    pub fn dynamic(mut self, dynamic: impl Into<Dynamic>) -> Self {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// Whether the JSON value given for the object field should be parsed and indexed
+    /// (`true`, default) or completely ignored (`false`).
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Adds a field mapping within the object
+    pub fn property(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        let _ = self.properties.insert(name.into(), property.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::object(), json!({ "type": "object" }));
+
+        assert_serialize(
+            Property::object()
+                .dynamic(Dynamic::Strict)
+                .enabled(true)
+                .property("first", Property::text())
+                .property("last", Property::text()),
+            json!({
+                "type": "object",
+                "dynamic": "strict",
+                "enabled": true,
+                "properties": {
+                    "first": { "type": "text" },
+                    "last": { "type": "text" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// A field to index full-text values, such as the body of an email or the description of a
+/// product. These fields are `analyzed`, that is they are passed through an
+/// [analyzer](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html) to
+/// convert the string into a list of individual terms before being indexed.
+///
+/// It is often useful to index the same field in different ways for different purposes, which
+/// is what multi-fields are for.
+///
+/// To create a text field mapping with a keyword multi-field:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let property =
+/// Property::text()
+///     .analyzer("english")
+///     .search_analyzer("english_synonyms")
+///     .field("raw", Property::keyword());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/text.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "text")]
+pub struct TextProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_quote_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fielddata: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fields: Properties,
+}
+
+impl Property {
+    /// Creates an instance of [`TextProperty`]
+    pub fn text() -> TextProperty {
+        TextProperty::default()
+    }
+}
+
+impl TextProperty {
+    /// The analyzer which should be used for the text field, both at index-time and at
+    /// search-time (unless overridden by the `search_analyzer`). Defaults to the default index
+    /// analyzer, or the `standard` analyzer.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analyzer.html>
+    pub fn analyzer(mut self, analyzer: impl Into<String>) -> Self {
+        self.analyzer = Some(analyzer.into());
+        self
+    }
+
+    /// The analyzer that should be used at search time on the text field. Defaults to the
+    /// `analyzer` setting.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-analyzer.html>
+    pub fn search_analyzer(mut self, search_analyzer: impl Into<String>) -> Self {
+        self.search_analyzer = Some(search_analyzer.into());
+        self
+    }
+
+    /// The analyzer that should be used at search time when a phrase is encountered. Defaults to
+    /// the `search_analyzer` setting.
+    pub fn search_quote_analyzer(mut self, search_quote_analyzer: impl Into<String>) -> Self {
+        self.search_quote_analyzer = Some(search_quote_analyzer.into());
+        self
+    }
+
+    /// Can the field use in-memory fielddata for sorting, aggregations, or scripting?
+    /// Defaults to `false`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/text.html#fielddata-mapping-param>
+    pub fn fielddata(mut self, fielddata: bool) -> Self {
+        self.fielddata = Some(fielddata);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Copies the values of this field into a group field, which can then be queried as a
+    /// single field
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/copy-to.html>
+    pub fn copy_to(mut self, field: impl Into<String>) -> Self {
+        self.copy_to.push(field.into());
+        self
+    }
+
+    /// Adds a multi-field, which allows the same string value to be indexed in multiple ways
+    /// for different purposes, for example as a `keyword` field for sorting or aggregations
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-fields.html>
+    pub fn field(mut self, name: impl Into<String>, property: impl Into<Property>) -> Self {
+        let _ = self.fields.insert(name.into(), property.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::text(), json!({ "type": "text" }));
+
+        assert_serialize(
+            Property::text()
+                .analyzer("standard")
+                .search_analyzer("synonyms")
+                .search_quote_analyzer("quotes")
+                .fielddata(true)
+                .index(true)
+                .store(false)
+                .copy_to("all")
+                .field("english", Property::text().analyzer("english"))
+                .field("raw", Property::keyword()),
+            json!({
+                "type": "text",
+                "analyzer": "standard",
+                "search_analyzer": "synonyms",
+                "search_quote_analyzer": "quotes",
+                "fielddata": true,
+                "index": true,
+                "store": false,
+                "copy_to": ["all"],
+                "fields": {
+                    "english": {
+                        "type": "text",
+                        "analyzer": "english"
+                    },
+                    "raw": { "type": "keyword" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,18 @@
+//! Index APIs are used to manage individual indices, index settings, aliases, mappings, and
+//! index templates.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices.html>
+
+// Private modules
+mod create_index;
+
+// Public modules
+pub mod analysis;
+pub mod mappings;
+pub mod settings;
+
+// Public re-exports
+pub use self::analysis::*;
+pub use self::create_index::*;
+pub use self::mappings::*;
+pub use self::settings::*;
@@ -0,0 +1,19 @@
+use crate::util::*;
+
+/// Index lifecycle management settings
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-settings.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct IndexLifecycle {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) rollover_alias: Option<String>,
+}
+
+impl ShouldSkip for IndexLifecycle {
+    fn should_skip(&self) -> bool {
+        self.name.should_skip() && self.rollover_alias.should_skip()
+    }
+}
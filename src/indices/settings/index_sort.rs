@@ -0,0 +1,155 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// A single field used to sort the segments inside each shard of an index.
+///
+/// When any field in the index sorting sets `order`, `mode` or `missing`, the option is sent
+/// for every field, falling back to Elasticsearch defaults for fields that don't set it.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules-index-sorting.html>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexSortField {
+    field: String,
+    order: Option<SortOrder>,
+    mode: Option<SortMode>,
+    missing: Option<SortMissing>,
+}
+
+impl IndexSortField {
+    /// Creates an instance of [`IndexSortField`]
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            order: None,
+            mode: None,
+            missing: None,
+        }
+    }
+
+    /// The sort order to use
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Elasticsearch supports sorting by multi-valued fields. The mode option controls what
+    /// value is picked to sort the document. Only `min` and `max` are allowed.
+    pub fn mode(mut self, mode: SortMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// The missing parameter specifies how docs which are missing the field should be treated.
+    /// Only `_first` and `_last` are allowed.
+    pub fn missing(mut self, missing: SortMissing) -> Self {
+        self.missing = Some(missing);
+        self
+    }
+
+    fn order_or_default(&self) -> SortOrder {
+        self.order.unwrap_or(SortOrder::Asc)
+    }
+
+    fn mode_or_default(&self) -> SortMode {
+        self.mode.unwrap_or(match self.order_or_default() {
+            SortOrder::Asc => SortMode::Min,
+            SortOrder::Desc => SortMode::Max,
+        })
+    }
+
+    fn missing_or_default(&self) -> SortMissing {
+        self.missing.clone().unwrap_or(SortMissing::Last)
+    }
+}
+
+impl<T> From<T> for IndexSortField
+where
+    T: Into<String>,
+{
+    fn from(field: T) -> Self {
+        Self::new(field)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct IndexSort(pub(crate) Vec<IndexSortField>);
+
+impl ShouldSkip for IndexSort {
+    fn should_skip(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Serialize for IndexSort {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let has_order = self.0.iter().any(|f| f.order.is_some());
+        let has_mode = self.0.iter().any(|f| f.mode.is_some());
+        let has_missing = self.0.iter().any(|f| f.missing.is_some());
+
+        let len = 1 + has_order as usize + has_mode as usize + has_missing as usize;
+        let mut state = serializer.serialize_struct("IndexSort", len)?;
+
+        let fields: Vec<_> = self.0.iter().map(|f| &f.field).collect();
+        state.serialize_field("field", &fields)?;
+
+        if has_order {
+            let order: Vec<_> = self.0.iter().map(|f| f.order_or_default()).collect();
+            state.serialize_field("order", &order)?;
+        }
+
+        if has_mode {
+            let mode: Vec<_> = self.0.iter().map(|f| f.mode_or_default()).collect();
+            state.serialize_field("mode", &mode)?;
+        }
+
+        if has_missing {
+            let missing: Vec<_> = self.0.iter().map(|f| f.missing_or_default()).collect();
+            state.serialize_field("missing", &missing)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            IndexSort(vec![IndexSortField::new("timestamp")]),
+            json!({ "field": ["timestamp"] }),
+        );
+
+        assert_serialize(
+            IndexSort(vec![
+                IndexSortField::new("username"),
+                IndexSortField::new("date").order(SortOrder::Desc),
+            ]),
+            json!({
+                "field": ["username", "date"],
+                "order": ["asc", "desc"]
+            }),
+        );
+
+        assert_serialize(
+            IndexSort(vec![
+                IndexSortField::new("username").missing(SortMissing::First),
+                IndexSortField::new("date")
+                    .order(SortOrder::Desc)
+                    .mode(SortMode::Max),
+            ]),
+            json!({
+                "field": ["username", "date"],
+                "order": ["asc", "desc"],
+                "mode": ["min", "max"],
+                "missing": ["_first", "_last"]
+            }),
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Index level settings can be set per-index.
+//!
+//! Settings may be:
+//!
+//! - **static** - they can only be set at index creation time or on a closed index
+//! - **dynamic** - they can be changed on a live index using the update index settings API
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-modules-settings>
+
+mod index_lifecycle;
+mod index_sort;
+
+use self::index_lifecycle::*;
+pub use self::index_sort::*;
+
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// Settings of an index, used both in index creation and update index settings request bodies.
+///
+/// To create index settings:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let settings =
+/// IndexSettings::new()
+///     .number_of_shards(3)
+///     .number_of_replicas(1)
+///     .refresh_interval(Time::Seconds(30))
+///     .sort(IndexSortField::new("timestamp").order(SortOrder::Desc))
+///     .lifecycle_name("logs");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IndexSettings {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_replicas: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    refresh_interval: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: IndexSort,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lifecycle: IndexLifecycle,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analysis: Option<IndexAnalysis>,
+}
+
+impl IndexSettings {
+    /// Creates an instance of [`IndexSettings`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of primary shards that an index should have. Defaults to `1`. This setting
+    /// can only be set at index creation time.
+    pub fn number_of_shards(mut self, number_of_shards: impl TryInto<u32>) -> Self {
+        if let Ok(number_of_shards) = number_of_shards.try_into() {
+            self.number_of_shards = Some(number_of_shards);
+        }
+        self
+    }
+
+    /// The number of replicas each primary shard has. Defaults to `1`.
+    pub fn number_of_replicas(mut self, number_of_replicas: impl TryInto<u32>) -> Self {
+        if let Ok(number_of_replicas) = number_of_replicas.try_into() {
+            self.number_of_replicas = Some(number_of_replicas);
+        }
+        self
+    }
+
+    /// How often to perform a refresh operation, which makes recent changes to the index
+    /// visible to search. Defaults to `1s`.
+    pub fn refresh_interval(mut self, refresh_interval: Time) -> Self {
+        self.refresh_interval = Some(refresh_interval);
+        self
+    }
+
+    /// Pushes a field to the index sorting, which defines how the segments inside each shard
+    /// are sorted. Index sorting can only be defined once at index creation.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules-index-sorting.html>
+    pub fn sort(mut self, field: impl Into<IndexSortField>) -> Self {
+        self.sort.0.push(field.into());
+        self
+    }
+
+    /// The name of the policy to use to manage the index
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-settings.html>
+    pub fn lifecycle_name(mut self, name: impl Into<String>) -> Self {
+        self.lifecycle.name = Some(name.into());
+        self
+    }
+
+    /// The index alias to update when the index rolls over
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-settings.html>
+    pub fn lifecycle_rollover_alias(mut self, rollover_alias: impl Into<String>) -> Self {
+        self.lifecycle.rollover_alias = Some(rollover_alias.into());
+        self
+    }
+
+    /// Analyzers, tokenizers, token filters and character filters available to the index
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html>
+    pub fn analysis(mut self, analysis: IndexAnalysis) -> Self {
+        self.analysis = Some(analysis);
+        self
+    }
+}
+
+impl ShouldSkip for IndexSettings {
+    fn should_skip(&self) -> bool {
+        self.number_of_shards.should_skip()
+            && self.number_of_replicas.should_skip()
+            && self.refresh_interval.should_skip()
+            && self.sort.should_skip()
+            && self.lifecycle.should_skip()
+            && self.analysis.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(IndexSettings::new(), json!({}));
+
+        assert_serialize(
+            IndexSettings::new()
+                .number_of_shards(3)
+                .number_of_replicas(2)
+                .refresh_interval(Time::Seconds(30))
+                .sort(IndexSortField::new("timestamp").order(SortOrder::Desc))
+                .lifecycle_name("logs")
+                .lifecycle_rollover_alias("logs-alias")
+                .analysis(
+                    IndexAnalysis::new()
+                        .analyzer("my_analyzer", AnalyzerDefinition::custom("standard")),
+                ),
+            json!({
+                "number_of_shards": 3,
+                "number_of_replicas": 2,
+                "refresh_interval": "30s",
+                "sort": {
+                    "field": ["timestamp"],
+                    "order": ["desc"]
+                },
+                "lifecycle": {
+                    "name": "logs",
+                    "rollover_alias": "logs-alias"
+                },
+                "analysis": {
+                    "analyzer": {
+                        "my_analyzer": {
+                            "type": "custom",
+                            "tokenizer": "standard"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
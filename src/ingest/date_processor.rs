@@ -0,0 +1,133 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Parses dates from fields, and then uses the date or timestamp as the timestamp for the
+/// document. By default, the date processor adds the parsed date as a new field called
+/// `@timestamp`.
+///
+/// To create date processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::date("initial_date", ["dd/MM/yyyy HH:mm:ss"]).timezone("Europe/Amsterdam");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/date-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DateProcessor {
+    #[serde(rename = "date")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    formats: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    timezone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    locale: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    output_format: Option<String>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`DateProcessor`]
+    ///
+    /// - `field` - The field to get the date from
+    /// - `formats` - An array of the expected date formats. Can be a java time pattern or one of
+    ///   the following formats: `ISO8601`, `UNIX`, `UNIX_MS`, or `TAI64N`.
+    pub fn date<I>(field: impl Into<String>, formats: I) -> DateProcessor
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        DateProcessor {
+            inner: Inner {
+                field: field.into(),
+                formats: formats.into_iter().map(Into::into).collect(),
+                target_field: None,
+                timezone: None,
+                locale: None,
+                output_format: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl DateProcessor {
+    /// The field that will hold the parsed date. Defaults to `@timestamp`.
+    pub fn target_field(mut self, target_field: impl Into<String>) -> Self {
+        self.inner.target_field = Some(target_field.into());
+        self
+    }
+
+    /// The timezone to use when parsing the date. Supports template snippets.
+    /// Defaults to `UTC`.
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.inner.timezone = Some(timezone.into());
+        self
+    }
+
+    /// The locale to use when parsing the date, relevant when parsing month names or week days.
+    /// Supports template snippets. Defaults to `ENGLISH`.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.inner.locale = Some(locale.into());
+        self
+    }
+
+    /// The format to use when writing the date to `target_field`. Must be a valid java time
+    /// pattern. Defaults to `yyyy-MM-dd'T'HH:mm:ss.SSSXXX`.
+    pub fn output_format(mut self, output_format: impl Into<String>) -> Self {
+        self.inner.output_format = Some(output_format.into());
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::date("initial_date", ["ISO8601"]),
+            json!({
+                "date": {
+                    "field": "initial_date",
+                    "formats": ["ISO8601"]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Processor::date("initial_date", ["dd/MM/yyyy HH:mm:ss"])
+                .target_field("timestamp")
+                .timezone("{{{my_timezone}}}")
+                .locale("{{{my_locale}}}")
+                .output_format("yyyy-MM-dd"),
+            json!({
+                "date": {
+                    "field": "initial_date",
+                    "formats": ["dd/MM/yyyy HH:mm:ss"],
+                    "target_field": "timestamp",
+                    "timezone": "{{{my_timezone}}}",
+                    "locale": "{{{my_locale}}}",
+                    "output_format": "yyyy-MM-dd"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Enrich policy body for the create enrich policy API. The policy is used by the enrich
+/// processor to add data from existing indices to incoming documents.
+///
+/// To create a policy matching users by email:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let policy =
+/// EnrichPolicy::r#match(["users"], "email", ["first_name", "last_name", "city"])
+///     .query(Query::term("active", true));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-enrich-policy-api.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EnrichPolicy(KeyValuePair<&'static str, Inner>);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    indices: Vec<String>,
+
+    match_field: String,
+
+    enrich_fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+}
+
+impl EnrichPolicy {
+    fn new<I, F>(
+        r#type: &'static str,
+        indices: I,
+        match_field: impl Into<String>,
+        enrich_fields: F,
+    ) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        Self(KeyValuePair::new(
+            r#type,
+            Inner {
+                indices: indices.into_iter().map(Into::into).collect(),
+                match_field: match_field.into(),
+                enrich_fields: enrich_fields.into_iter().map(Into::into).collect(),
+                query: None,
+            },
+        ))
+    }
+
+    /// Creates a `match` enrich policy, matching enrich data to incoming documents based on a
+    /// term query
+    ///
+    /// - `indices` - Source indices used to create the enrich index
+    /// - `match_field` - Field in the source indices used to match incoming documents
+    /// - `enrich_fields` - Fields to add to matching incoming documents
+    pub fn r#match<I, F>(indices: I, match_field: impl Into<String>, enrich_fields: F) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        Self::new("match", indices, match_field, enrich_fields)
+    }
+
+    /// Creates a `geo_match` enrich policy, matching enrich data to incoming documents based on
+    /// a geo_shape query
+    ///
+    /// - `indices` - Source indices used to create the enrich index
+    /// - `match_field` - Geo shape field in the source indices used to match incoming documents
+    /// - `enrich_fields` - Fields to add to matching incoming documents
+    pub fn geo_match<I, F>(indices: I, match_field: impl Into<String>, enrich_fields: F) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        Self::new("geo_match", indices, match_field, enrich_fields)
+    }
+
+    /// Creates a `range` enrich policy, matching a number, date, or IP address in incoming
+    /// documents to a range of the same type in the enrich index
+    ///
+    /// - `indices` - Source indices used to create the enrich index
+    /// - `match_field` - Range field in the source indices used to match incoming documents
+    /// - `enrich_fields` - Fields to add to matching incoming documents
+    pub fn range<I, F>(indices: I, match_field: impl Into<String>, enrich_fields: F) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        Self::new("range", indices, match_field, enrich_fields)
+    }
+
+    /// Query used to filter documents in the enrich index. Defaults to a `match_all` query.
+    pub fn query(mut self, query: impl Into<Query>) -> Self {
+        let query = query.into();
+
+        if !query.should_skip() {
+            self.0.value.query = Some(query);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            EnrichPolicy::r#match(["users"], "email", ["first_name", "last_name"]),
+            json!({
+                "match": {
+                    "indices": ["users"],
+                    "match_field": "email",
+                    "enrich_fields": ["first_name", "last_name"]
+                }
+            }),
+        );
+
+        assert_serialize(
+            EnrichPolicy::geo_match(["postal_codes"], "location", ["postal_code"])
+                .query(Query::term("country", "NL")),
+            json!({
+                "geo_match": {
+                    "indices": ["postal_codes"],
+                    "match_field": "location",
+                    "enrich_fields": ["postal_code"],
+                    "query": {
+                        "term": {
+                            "country": {
+                                "value": "NL"
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            EnrichPolicy::range(["networks"], "range", ["name", "department"]),
+            json!({
+                "range": {
+                    "indices": ["networks"],
+                    "match_field": "range",
+                    "enrich_fields": ["name", "department"]
+                }
+            }),
+        );
+    }
+}
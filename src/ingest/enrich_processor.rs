@@ -0,0 +1,124 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// The enrich processor can enrich documents with data from another index.
+///
+/// To create enrich processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::enrich("users-policy", "email", "user").max_matches(1);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/enrich-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EnrichProcessor {
+    #[serde(rename = "enrich")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    policy_name: String,
+
+    field: String,
+
+    target_field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#override: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_matches: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shape_relation: Option<String>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`EnrichProcessor`]
+    ///
+    /// - `policy_name` - The name of the enrich policy to use
+    /// - `field` - The field in the input document that matches the policy's `match_field`
+    /// - `target_field` - Field added to incoming documents to contain enrich data
+    pub fn enrich(
+        policy_name: impl Into<String>,
+        field: impl Into<String>,
+        target_field: impl Into<String>,
+    ) -> EnrichProcessor {
+        EnrichProcessor {
+            inner: Inner {
+                policy_name: policy_name.into(),
+                field: field.into(),
+                target_field: target_field.into(),
+                ignore_missing: None,
+                r#override: None,
+                max_matches: None,
+                shape_relation: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl EnrichProcessor {
+    /// If `true` and `field` does not exist, the processor quietly exits without modifying the
+    /// document. Defaults to `false`.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.inner.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    /// If processor will update fields with pre-existing non-null-valued field. Defaults to
+    /// `true`.
+    pub fn r#override(mut self, r#override: bool) -> Self {
+        self.inner.r#override = Some(r#override);
+        self
+    }
+
+    /// The maximum number of matched documents to include under the configured target field.
+    /// Defaults to `1`, the maximum value is `128`.
+    pub fn max_matches(mut self, max_matches: u32) -> Self {
+        self.inner.max_matches = Some(max_matches);
+        self
+    }
+
+    /// A spatial relation operator used to match the geoshape of incoming documents to
+    /// documents in the enrich index. Only used for `geo_match` enrich policy types.
+    pub fn shape_relation(mut self, shape_relation: impl Into<String>) -> Self {
+        self.inner.shape_relation = Some(shape_relation.into());
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::enrich("users-policy", "email", "user")
+                .ignore_missing(true)
+                .r#override(false)
+                .max_matches(2),
+            json!({
+                "enrich": {
+                    "policy_name": "users-policy",
+                    "field": "email",
+                    "target_field": "user",
+                    "ignore_missing": true,
+                    "override": false,
+                    "max_matches": 2
+                }
+            }),
+        );
+    }
+}
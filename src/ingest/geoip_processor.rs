@@ -0,0 +1,135 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Adds information about the geographical location of an IPv4 or IPv6 address.
+///
+/// To create geoip processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::geoip("ip").target_field("geo");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/geoip-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeoIpProcessor {
+    #[serde(rename = "geoip")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    database_file: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    first_only: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`GeoIpProcessor`]
+    ///
+    /// - `field` - The field to get the ip address from for the geographical lookup
+    pub fn geoip(field: impl Into<String>) -> GeoIpProcessor {
+        GeoIpProcessor {
+            inner: Inner {
+                field: field.into(),
+                target_field: None,
+                database_file: None,
+                properties: vec![],
+                ignore_missing: None,
+                first_only: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl GeoIpProcessor {
+    /// The field that will hold the geographical information looked up from the MaxMind
+    /// database. Defaults to `geoip`.
+    pub fn target_field(mut self, target_field: impl Into<String>) -> Self {
+        self.inner.target_field = Some(target_field.into());
+        self
+    }
+
+    /// The database filename referring to a database the module ships with or a custom
+    /// database in the `ingest-geoip` config directory. Defaults to `GeoLite2-City.mmdb`.
+    pub fn database_file(mut self, database_file: impl Into<String>) -> Self {
+        self.inner.database_file = Some(database_file.into());
+        self
+    }
+
+    /// Controls what properties are added to the `target_field` based on the geoip lookup
+    pub fn properties<I>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.inner
+            .properties
+            .extend(properties.into_iter().map(Into::into));
+        self
+    }
+
+    /// If `true` and `field` does not exist, the processor quietly exits without modifying the
+    /// document. Defaults to `false`.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.inner.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    /// If `true` (default) only first found geoip data will be returned, even if `field`
+    /// contains an array
+    pub fn first_only(mut self, first_only: bool) -> Self {
+        self.inner.first_only = Some(first_only);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::geoip("ip"),
+            json!({ "geoip": { "field": "ip" } }),
+        );
+
+        assert_serialize(
+            Processor::geoip("ip")
+                .target_field("geo")
+                .database_file("GeoLite2-Country.mmdb")
+                .properties(["continent_name", "country_iso_code"])
+                .ignore_missing(true)
+                .first_only(false),
+            json!({
+                "geoip": {
+                    "field": "ip",
+                    "target_field": "geo",
+                    "database_file": "GeoLite2-Country.mmdb",
+                    "properties": ["continent_name", "country_iso_code"],
+                    "ignore_missing": true,
+                    "first_only": false
+                }
+            }),
+        );
+    }
+}
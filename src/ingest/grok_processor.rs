@@ -0,0 +1,122 @@
+use crate::ingest::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// Extracts structured fields out of a single text field within a document. You choose which
+/// field to extract matched fields from, as well as the grok pattern you expect will match.
+///
+/// To create grok processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::grok("message", ["%{IP:client} %{WORD:method} %{URIPATHPARAM:request}"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/grok-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GrokProcessor {
+    #[serde(rename = "grok")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    patterns: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pattern_definitions: BTreeMap<String, String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    trace_match: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`GrokProcessor`]
+    ///
+    /// - `field` - The field to use for grok expression parsing
+    /// - `patterns` - An ordered list of grok expression to match and extract named captures
+    ///   with. Returns on the first expression in the list that matches.
+    pub fn grok<I>(field: impl Into<String>, patterns: I) -> GrokProcessor
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        GrokProcessor {
+            inner: Inner {
+                field: field.into(),
+                patterns: patterns.into_iter().map(Into::into).collect(),
+                pattern_definitions: Default::default(),
+                trace_match: None,
+                ignore_missing: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl GrokProcessor {
+    /// Adds a custom pattern that can be used by the processor, pattern definitions may
+    /// reference other patterns
+    pub fn pattern_definition(
+        mut self,
+        name: impl Into<String>,
+        pattern: impl Into<String>,
+    ) -> Self {
+        let _ = self
+            .inner
+            .pattern_definitions
+            .insert(name.into(), pattern.into());
+        self
+    }
+
+    /// When `true`, `_ingest._grok_match_index` will be inserted into your matched document's
+    /// metadata with the index into the pattern found in `patterns` that matched
+    pub fn trace_match(mut self, trace_match: bool) -> Self {
+        self.inner.trace_match = Some(trace_match);
+        self
+    }
+
+    /// If `true` and `field` does not exist or is `null`, the processor quietly exits without
+    /// modifying the document. Defaults to `false`.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.inner.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::grok("message", ["%{FAVORITE_DOG:pet}", "%{FAVORITE_CAT:pet}"])
+                .pattern_definition("FAVORITE_DOG", "beagle")
+                .pattern_definition("FAVORITE_CAT", "burmese")
+                .trace_match(true)
+                .ignore_missing(true),
+            json!({
+                "grok": {
+                    "field": "message",
+                    "patterns": ["%{FAVORITE_DOG:pet}", "%{FAVORITE_CAT:pet}"],
+                    "pattern_definitions": {
+                        "FAVORITE_CAT": "burmese",
+                        "FAVORITE_DOG": "beagle"
+                    },
+                    "trace_match": true,
+                    "ignore_missing": true
+                }
+            }),
+        );
+    }
+}
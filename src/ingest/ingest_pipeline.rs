@@ -0,0 +1,128 @@
+use crate::ingest::*;
+use crate::util::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Ingest pipeline body for the create or update pipeline API.
+///
+/// To create a pipeline that parses a timestamp and drops the original field:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let pipeline =
+/// IngestPipeline::new()
+///     .description("Parses timestamps")
+///     .processor(Processor::date("ts", ["UNIX"]))
+///     .processor(Processor::remove(["ts"]))
+///     .on_failure([Processor::set("error.message", "{{ _ingest.on_failure_message }}")]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-pipeline-api.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IngestPipeline {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    description: Option<String>,
+
+    processors: Vec<Processor>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    on_failure: Vec<Processor>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_meta")]
+    meta: BTreeMap<String, Value>,
+}
+
+impl IngestPipeline {
+    /// Creates an instance of [`IngestPipeline`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Description of the ingest pipeline
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Pushes a processor to the pipeline. Processors run sequentially in the order specified.
+    pub fn processor(mut self, processor: impl Into<Processor>) -> Self {
+        self.processors.push(processor.into());
+        self
+    }
+
+    /// Processors to run immediately after a processor failure
+    pub fn on_failure<I>(mut self, processors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Processor>,
+    {
+        self.on_failure
+            .extend(processors.into_iter().map(Into::into));
+        self
+    }
+
+    /// Version number used by external systems to track ingest pipelines
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Optional metadata about the ingest pipeline
+    pub fn meta<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(IngestPipeline::new(), json!({ "processors": [] }));
+
+        assert_serialize(
+            IngestPipeline::new()
+                .description("My pipeline")
+                .processor(Processor::rename("provider", "cloud.provider"))
+                .processor(Processor::pipeline("common-pipeline"))
+                .on_failure([Processor::set("_index", "failed-{{ _index }}")])
+                .version(2)
+                .meta("serialization", "json"),
+            json!({
+                "description": "My pipeline",
+                "processors": [
+                    {
+                        "rename": {
+                            "field": "provider",
+                            "target_field": "cloud.provider"
+                        }
+                    },
+                    {
+                        "pipeline": {
+                            "name": "common-pipeline"
+                        }
+                    }
+                ],
+                "on_failure": [
+                    {
+                        "set": {
+                            "field": "_index",
+                            "value": "failed-{{ _index }}"
+                        }
+                    }
+                ],
+                "version": 2,
+                "_meta": {
+                    "serialization": "json"
+                }
+            }),
+        );
+    }
+}
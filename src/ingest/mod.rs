@@ -0,0 +1,123 @@
+//! Ingest pipelines let you perform common transformations on your data before indexing.
+//!
+//! A pipeline consists of a series of configurable tasks called processors. Each processor runs
+//! sequentially, making specific changes to incoming documents.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/ingest.html>
+
+macro_rules! processor {
+    ($($variant:ident($processor:ty)),+ $(,)?) => {
+        /// A container enum for supported ingest processor types
+        #[derive(Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum Processor {
+            $(
+                $variant($processor),
+            )*
+        }
+
+        impl std::fmt::Debug for Processor {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(p) => p.fmt(f),
+                    )+
+                }
+            }
+        }
+
+        $(
+            impl From<$processor> for Processor {
+                fn from(p: $processor) -> Self {
+                    Processor::$variant(p)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! add_processor_options {
+    () => {
+        /// Description of the processor. Useful for describing the purpose of the processor or
+        /// its configuration.
+        pub fn description(mut self, description: impl Into<String>) -> Self {
+            self.inner.options.description = Some(description.into());
+            self
+        }
+
+        /// Conditionally execute the processor, the condition is written as a Painless script
+        ///
+        /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ingest.html#conditionally-run-processor>
+        pub fn r#if(mut self, condition: impl Into<String>) -> Self {
+            self.inner.options.r#if = Some(condition.into());
+            self
+        }
+
+        /// Ignore failures for the processor
+        pub fn ignore_failure(mut self, ignore_failure: bool) -> Self {
+            self.inner.options.ignore_failure = Some(ignore_failure);
+            self
+        }
+
+        /// Handles failures for the processor
+        ///
+        /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ingest.html#handling-pipeline-failures>
+        pub fn on_failure<I>(mut self, processors: I) -> Self
+        where
+            I: IntoIterator,
+            I::Item: Into<Processor>,
+        {
+            self.inner
+                .options
+                .on_failure
+                .extend(processors.into_iter().map(Into::into));
+            self
+        }
+
+        /// Identifier for the processor. Useful for debugging and metrics.
+        pub fn tag(mut self, tag: impl Into<String>) -> Self {
+            self.inner.options.tag = Some(tag.into());
+            self
+        }
+    };
+}
+
+mod date_processor;
+mod enrich_policy;
+mod enrich_processor;
+mod geoip_processor;
+mod grok_processor;
+mod ingest_pipeline;
+mod pipeline_processor;
+mod processor_options;
+mod remove_processor;
+mod rename_processor;
+mod script_processor;
+mod set_processor;
+
+pub use self::date_processor::*;
+pub use self::enrich_policy::*;
+pub use self::enrich_processor::*;
+pub use self::geoip_processor::*;
+pub use self::grok_processor::*;
+pub use self::ingest_pipeline::*;
+pub use self::pipeline_processor::*;
+pub use self::remove_processor::*;
+pub use self::rename_processor::*;
+pub use self::script_processor::*;
+pub use self::set_processor::*;
+
+use self::processor_options::*;
+
+processor!(
+    Set(SetProcessor),
+    Rename(RenameProcessor),
+    Remove(RemoveProcessor),
+    Grok(GrokProcessor),
+    Date(DateProcessor),
+    GeoIp(GeoIpProcessor),
+    Script(ScriptProcessor),
+    Enrich(EnrichProcessor),
+    Pipeline(PipelineProcessor),
+);
@@ -0,0 +1,79 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Executes another pipeline.
+///
+/// To create pipeline processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::pipeline("pipelineA");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/pipeline-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PipelineProcessor {
+    #[serde(rename = "pipeline")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    name: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing_pipeline: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`PipelineProcessor`]
+    ///
+    /// - `name` - The name of the pipeline to execute. Supports template snippets.
+    pub fn pipeline(name: impl Into<String>) -> PipelineProcessor {
+        PipelineProcessor {
+            inner: Inner {
+                name: name.into(),
+                ignore_missing_pipeline: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl PipelineProcessor {
+    /// Whether to ignore missing pipelines instead of failing. Defaults to `false`.
+    pub fn ignore_missing_pipeline(mut self, ignore_missing_pipeline: bool) -> Self {
+        self.inner.ignore_missing_pipeline = Some(ignore_missing_pipeline);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::pipeline("pipelineA"),
+            json!({ "pipeline": { "name": "pipelineA" } }),
+        );
+
+        assert_serialize(
+            Processor::pipeline("{{ service }}-pipeline")
+                .ignore_missing_pipeline(true)
+                .r#if("ctx.service != null"),
+            json!({
+                "pipeline": {
+                    "name": "{{ service }}-pipeline",
+                    "ignore_missing_pipeline": true,
+                    "if": "ctx.service != null"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Options shared by all processors
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub(crate) struct ProcessorOptions {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) r#if: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) ignore_failure: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) on_failure: Vec<Processor>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) tag: Option<String>,
+}
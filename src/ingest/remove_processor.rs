@@ -0,0 +1,76 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Removes existing fields. If one field doesn't exist, an exception will be thrown.
+///
+/// To create remove processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::remove(["user_agent", "url"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/remove-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RemoveProcessor {
+    #[serde(rename = "remove")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`RemoveProcessor`]
+    ///
+    /// - `fields` - Fields to be removed. Supports template snippets.
+    pub fn remove<I>(fields: I) -> RemoveProcessor
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        RemoveProcessor {
+            inner: Inner {
+                field: fields.into_iter().map(Into::into).collect(),
+                ignore_missing: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl RemoveProcessor {
+    /// If `true` and `field` does not exist or is `null`, the processor quietly exits without
+    /// modifying the document. Defaults to `false`.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.inner.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::remove(["user_agent", "url"]).ignore_missing(true),
+            json!({
+                "remove": {
+                    "field": ["user_agent", "url"],
+                    "ignore_missing": true
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::ingest::*;
+use crate::util::*;
+
+/// Renames an existing field. If the field doesn't exist or the new name is already used, an
+/// exception will be thrown.
+///
+/// To create rename processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::rename("provider", "cloud.provider");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/rename-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenameProcessor {
+    #[serde(rename = "rename")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    target_field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`RenameProcessor`]
+    ///
+    /// - `field` - The field to be renamed. Supports template snippets.
+    /// - `target_field` - The new name of the field. Supports template snippets.
+    pub fn rename(field: impl Into<String>, target_field: impl Into<String>) -> RenameProcessor {
+        RenameProcessor {
+            inner: Inner {
+                field: field.into(),
+                target_field: target_field.into(),
+                ignore_missing: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl RenameProcessor {
+    /// If `true` and `field` does not exist, the processor quietly exits without modifying the
+    /// document. Defaults to `false`.
+    pub fn ignore_missing(mut self, ignore_missing: bool) -> Self {
+        self.inner.ignore_missing = Some(ignore_missing);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::rename("provider", "cloud.provider"),
+            json!({
+                "rename": {
+                    "field": "provider",
+                    "target_field": "cloud.provider"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Processor::rename("provider", "cloud.provider")
+                .ignore_missing(true)
+                .tag("rename-provider"),
+            json!({
+                "rename": {
+                    "field": "provider",
+                    "target_field": "cloud.provider",
+                    "ignore_missing": true,
+                    "tag": "rename-provider"
+                }
+            }),
+        );
+    }
+}
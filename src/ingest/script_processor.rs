@@ -0,0 +1,74 @@
+use crate::ingest::*;
+use crate::search::*;
+
+/// Runs an inline or stored script on incoming documents. The script runs in the `ingest`
+/// context.
+///
+/// To create script processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::script(Script::source("ctx['_index'] = ctx['lang'] + '-' + params['dataset'];")
+///     .param("dataset", "catalog"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/script-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptProcessor {
+    #[serde(rename = "script")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    #[serde(flatten)]
+    script: Script,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`ScriptProcessor`]
+    ///
+    /// - `script` - Inline or stored script to run
+    pub fn script(script: Script) -> ScriptProcessor {
+        ScriptProcessor {
+            inner: Inner {
+                script,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl ScriptProcessor {
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::script(
+                Script::source("ctx['_index'] = ctx['lang'] + '-' + params['dataset'];")
+                    .lang(ScriptLang::Painless)
+                    .param("dataset", "catalog"),
+            )
+            .description("Set index based on `lang` field and `dataset` param"),
+            json!({
+                "script": {
+                    "source": "ctx['_index'] = ctx['lang'] + '-' + params['dataset'];",
+                    "lang": "painless",
+                    "params": {
+                        "dataset": "catalog"
+                    },
+                    "description": "Set index based on `lang` field and `dataset` param"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::ingest::*;
+use crate::util::*;
+use serde_json::Value;
+
+/// Sets one field and associates it with the specified value. If the field already exists, its
+/// value will be replaced with the provided one.
+///
+/// To create set processor:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let processor =
+/// Processor::set("host.os.name", "{{{os}}}").r#override(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/set-processor.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SetProcessor {
+    #[serde(rename = "set")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    value: Value,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#override: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_empty_value: Option<bool>,
+
+    #[serde(flatten)]
+    options: ProcessorOptions,
+}
+
+impl Processor {
+    /// Creates an instance of [`SetProcessor`]
+    ///
+    /// - `field` - The field to insert, upsert, or update. Supports template snippets.
+    /// - `value` - The value to be set for the field. Supports template snippets.
+    pub fn set(field: impl Into<String>, value: impl Into<Value>) -> SetProcessor {
+        SetProcessor {
+            inner: Inner {
+                field: field.into(),
+                value: value.into(),
+                r#override: None,
+                ignore_empty_value: None,
+                options: Default::default(),
+            },
+        }
+    }
+}
+
+impl SetProcessor {
+    /// If `false`, the processor will only update fields with a non-null value. Defaults to
+    /// `true`.
+    pub fn r#override(mut self, r#override: bool) -> Self {
+        self.inner.r#override = Some(r#override);
+        self
+    }
+
+    /// If `true` and `value` is a template snippet that evaluates to `null` or the empty string,
+    /// the processor quietly exits without modifying the document. Defaults to `false`.
+    pub fn ignore_empty_value(mut self, ignore_empty_value: bool) -> Self {
+        self.inner.ignore_empty_value = Some(ignore_empty_value);
+        self
+    }
+
+    add_processor_options!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Processor::set("count", 1),
+            json!({
+                "set": {
+                    "field": "count",
+                    "value": 1
+                }
+            }),
+        );
+
+        assert_serialize(
+            Processor::set("host.os.name", "{{{os}}}")
+                .r#override(false)
+                .ignore_empty_value(true)
+                .description("Copies the OS name")
+                .r#if("ctx.os != null")
+                .ignore_failure(true)
+                .on_failure([Processor::set("error", "failed")])
+                .tag("set-os"),
+            json!({
+                "set": {
+                    "field": "host.os.name",
+                    "value": "{{{os}}}",
+                    "override": false,
+                    "ignore_empty_value": true,
+                    "description": "Copies the OS name",
+                    "if": "ctx.os != null",
+                    "ignore_failure": true,
+                    "on_failure": [
+                        {
+                            "set": {
+                                "field": "error",
+                                "value": "failed"
+                            }
+                        }
+                    ],
+                    "tag": "set-os"
+                }
+            }),
+        );
+    }
+}
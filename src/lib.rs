@@ -121,8 +121,34 @@ pub(crate) mod util;
 
 // Public modules
 pub mod analyze;
+pub mod bulk;
+pub mod errors;
+pub mod ilm;
+pub mod indices;
+pub mod ingest;
+pub mod ml;
+pub mod query_rules;
+pub mod reindex;
 pub mod search;
+pub mod security;
+pub mod snapshot;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod transforms;
+pub mod watcher;
 
 // Public re-exports
 pub use self::analyze::*;
+pub use self::bulk::*;
+pub use self::errors::*;
+pub use self::ilm::*;
+pub use self::indices::*;
+pub use self::ingest::*;
+pub use self::ml::*;
+pub use self::query_rules::*;
+pub use self::reindex::*;
 pub use self::search::*;
+pub use self::security::*;
+pub use self::snapshot::*;
+pub use self::transforms::*;
+pub use self::watcher::*;
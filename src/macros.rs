@@ -17,7 +17,7 @@ macro_rules! add_boost_and_name {
             B: std::convert::TryInto<Boost>,
         {
             if let Ok(boost) = boost.try_into() {
-                self.inner.boost = Some(boost);
+                self.inner.boost = $crate::util::non_default(boost, 1);
             }
             self
         }
@@ -0,0 +1,132 @@
+use crate::ml::*;
+use crate::search::*;
+use crate::util::*;
+
+/// Specifies how to analyze the data. After you create a job, you cannot change the analysis
+/// configuration.
+///
+/// To analyze the mean response time in 15 minute buckets:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let analysis_config =
+/// AnalysisConfig::new(Time::Minutes(15), [Detector::new("mean").field_name("responsetime")])
+///     .influencers(["airline"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ml-put-job.html#ml-put-job-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnalysisConfig {
+    bucket_span: Time,
+
+    detectors: Vec<Detector>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    influencers: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    summary_count_field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    categorization_field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    latency: Option<Time>,
+}
+
+impl AnalysisConfig {
+    /// Creates an instance of [`AnalysisConfig`]
+    ///
+    /// - `bucket_span` - The size of the interval that the analysis is aggregated into,
+    ///   typically between `5m` and `1h`
+    /// - `detectors` - Detector configuration objects, which describe the anomaly detectors
+    ///   that are used in the job
+    pub fn new<I>(bucket_span: Time, detectors: I) -> Self
+    where
+        I: IntoIterator<Item = Detector>,
+    {
+        Self {
+            bucket_span,
+            detectors: detectors.into_iter().collect(),
+            influencers: vec![],
+            summary_count_field_name: None,
+            categorization_field_name: None,
+            latency: None,
+        }
+    }
+
+    /// Field names that contain potential influencers, entities that have contributed to or
+    /// are to blame for the anomalies
+    pub fn influencers<I>(mut self, influencers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.influencers
+            .extend(influencers.into_iter().map(Into::into));
+        self
+    }
+
+    /// If this property is specified, the data that is fed to the job is expected to be
+    /// pre-summarized. Should be `doc_count` when the datafeed uses aggregations.
+    pub fn summary_count_field_name(mut self, summary_count_field_name: impl Into<String>) -> Self {
+        self.summary_count_field_name = Some(summary_count_field_name.into());
+        self
+    }
+
+    /// The field used to categorize raw text values for the `mlcategory` detector keyword
+    pub fn categorization_field_name(
+        mut self,
+        categorization_field_name: impl Into<String>,
+    ) -> Self {
+        self.categorization_field_name = Some(categorization_field_name.into());
+        self
+    }
+
+    /// The size of the window in which to expect data that is out of time order
+    pub fn latency(mut self, latency: Time) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            AnalysisConfig::new(Time::Minutes(15), [Detector::new("count")]),
+            json!({
+                "bucket_span": "15m",
+                "detectors": [
+                    { "function": "count" }
+                ]
+            }),
+        );
+
+        assert_serialize(
+            AnalysisConfig::new(
+                Time::Hours(1),
+                [
+                    Detector::new("count").by_field_name("mlcategory"),
+                    Detector::new("mean").field_name("responsetime"),
+                ],
+            )
+            .influencers(["airline"])
+            .summary_count_field_name("doc_count")
+            .categorization_field_name("message")
+            .latency(Time::Seconds(30)),
+            json!({
+                "bucket_span": "1h",
+                "detectors": [
+                    { "function": "count", "by_field_name": "mlcategory" },
+                    { "function": "mean", "field_name": "responsetime" }
+                ],
+                "influencers": ["airline"],
+                "summary_count_field_name": "doc_count",
+                "categorization_field_name": "message",
+                "latency": "30s"
+            }),
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::ml::*;
+use crate::util::*;
+
+/// Anomaly detection job body for the create anomaly detection jobs API.
+///
+/// To create a job detecting unusual event rates per airline:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let job =
+/// AnomalyDetectionJob::new(
+///     AnalysisConfig::new(Time::Minutes(15), [Detector::new("count").partition_field_name("airline")]),
+///     DataDescription::new().time_field("timestamp"),
+/// )
+/// .description("Event rate per airline");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ml-put-job.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnomalyDetectionJob {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    groups: Vec<String>,
+
+    analysis_config: AnalysisConfig,
+
+    data_description: DataDescription,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    results_index_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    model_snapshot_retention_days: Option<u32>,
+}
+
+impl AnomalyDetectionJob {
+    /// Creates an instance of [`AnomalyDetectionJob`]
+    ///
+    /// - `analysis_config` - Specifies how to analyze the data
+    /// - `data_description` - Describes the format of the input data
+    pub fn new(analysis_config: AnalysisConfig, data_description: DataDescription) -> Self {
+        Self {
+            description: None,
+            groups: vec![],
+            analysis_config,
+            data_description,
+            results_index_name: None,
+            model_snapshot_retention_days: None,
+        }
+    }
+
+    /// A description of the job
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Job groups the job belongs to
+    pub fn groups<I>(mut self, groups: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.groups.extend(groups.into_iter().map(Into::into));
+        self
+    }
+
+    /// A text string that affects the name of the machine learning results index. Defaults to
+    /// `shared`, which generates an index named `.ml-anomalies-shared`.
+    pub fn results_index_name(mut self, results_index_name: impl Into<String>) -> Self {
+        self.results_index_name = Some(results_index_name.into());
+        self
+    }
+
+    /// The time in days that model snapshots are retained. Defaults to `10`.
+    pub fn model_snapshot_retention_days(mut self, model_snapshot_retention_days: u32) -> Self {
+        self.model_snapshot_retention_days = Some(model_snapshot_retention_days);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            AnomalyDetectionJob::new(
+                AnalysisConfig::new(Time::Minutes(15), [Detector::new("count")]),
+                DataDescription::new(),
+            ),
+            json!({
+                "analysis_config": {
+                    "bucket_span": "15m",
+                    "detectors": [{ "function": "count" }]
+                },
+                "data_description": {}
+            }),
+        );
+
+        assert_serialize(
+            AnomalyDetectionJob::new(
+                AnalysisConfig::new(
+                    Time::Minutes(15),
+                    [Detector::new("sum").field_name("bytes")],
+                ),
+                DataDescription::new().time_field("timestamp"),
+            )
+            .description("Unusual traffic")
+            .groups(["web"])
+            .results_index_name("web-traffic")
+            .model_snapshot_retention_days(5),
+            json!({
+                "description": "Unusual traffic",
+                "groups": ["web"],
+                "analysis_config": {
+                    "bucket_span": "15m",
+                    "detectors": [{ "function": "sum", "field_name": "bytes" }]
+                },
+                "data_description": {
+                    "time_field": "timestamp"
+                },
+                "results_index_name": "web-traffic",
+                "model_snapshot_retention_days": 5
+            }),
+        );
+    }
+}
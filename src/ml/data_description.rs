@@ -0,0 +1,53 @@
+use crate::util::*;
+
+/// Describes the format of the input data.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ml-put-job.html#ml-put-job-request-body>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct DataDescription {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_format: Option<String>,
+}
+
+impl DataDescription {
+    /// Creates an instance of [`DataDescription`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the field that contains the timestamp. Defaults to `time`.
+    pub fn time_field(mut self, time_field: impl Into<String>) -> Self {
+        self.time_field = Some(time_field.into());
+        self
+    }
+
+    /// The time format, which can be `epoch`, `epoch_ms`, or a custom pattern. Defaults to
+    /// `epoch`.
+    pub fn time_format(mut self, time_format: impl Into<String>) -> Self {
+        self.time_format = Some(time_format.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DataDescription::new(), json!({}));
+
+        assert_serialize(
+            DataDescription::new()
+                .time_field("timestamp")
+                .time_format("epoch_ms"),
+            json!({
+                "time_field": "timestamp",
+                "time_format": "epoch_ms"
+            }),
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::search::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// Datafeed body for the create datafeeds API. Datafeeds retrieve data from Elasticsearch for
+/// analysis by an anomaly detection job.
+///
+/// To feed error logs into a job:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let datafeed =
+/// Datafeed::new("error-rate", ["logs-*"])
+///     .query(Query::term("level", "error"))
+///     .frequency(Time::Seconds(150));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ml-put-datafeed.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Datafeed {
+    job_id: String,
+
+    indices: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "aggregations"
+    )]
+    aggs: Aggregations,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    runtime_mappings: BTreeMap<String, RuntimeMapping>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    frequency: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_delay: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    scroll_size: Option<u32>,
+}
+
+impl Datafeed {
+    /// Creates an instance of [`Datafeed`]
+    ///
+    /// - `job_id` - Identifier for the anomaly detection job
+    /// - `indices` - Index names, wildcards are supported
+    pub fn new<I>(job_id: impl Into<String>, indices: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            job_id: job_id.into(),
+            indices: indices.into_iter().map(Into::into).collect(),
+            query: None,
+            aggs: Default::default(),
+            runtime_mappings: Default::default(),
+            frequency: None,
+            query_delay: None,
+            scroll_size: None,
+        }
+    }
+
+    /// The Elasticsearch query domain-specific language (DSL) used to restrict the data fed to
+    /// the job. Defaults to a `match_all` query.
+    pub fn query(mut self, query: impl Into<Query>) -> Self {
+        let query = query.into();
+
+        if !query.should_skip() {
+            self.query = Some(query);
+        }
+
+        self
+    }
+
+    /// Adds a runtime field to the datafeed
+    pub fn runtime_mapping(mut self, name: impl ToString, mapping: RuntimeMapping) -> Self {
+        let _ = self.runtime_mappings.insert(name.to_string(), mapping);
+        self
+    }
+
+    /// The interval at which scheduled queries are made while the datafeed runs in real time.
+    /// Defaults to a short interval relative to the job's bucket span.
+    pub fn frequency(mut self, frequency: Time) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// The number of seconds behind real time that data is queried. Defaults to a random
+    /// value between `60s` and `120s`.
+    pub fn query_delay(mut self, query_delay: Time) -> Self {
+        self.query_delay = Some(query_delay);
+        self
+    }
+
+    /// The size parameter that is used in Elasticsearch searches when the datafeed does not
+    /// use aggregations. Defaults to `1000`.
+    pub fn scroll_size(mut self, scroll_size: u32) -> Self {
+        self.scroll_size = Some(scroll_size);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Datafeed::new("error-rate", ["logs-*"]),
+            json!({
+                "job_id": "error-rate",
+                "indices": ["logs-*"]
+            }),
+        );
+
+        assert_serialize(
+            Datafeed::new("error-rate", ["logs-*"])
+                .query(Query::term("level", "error"))
+                .aggregate("max_timestamp", Aggregation::max("timestamp"))
+                .frequency(Time::Seconds(150))
+                .query_delay(Time::Seconds(90))
+                .scroll_size(500),
+            json!({
+                "job_id": "error-rate",
+                "indices": ["logs-*"],
+                "query": {
+                    "term": {
+                        "level": {
+                            "value": "error"
+                        }
+                    }
+                },
+                "aggregations": {
+                    "max_timestamp": {
+                        "max": {
+                            "field": "timestamp"
+                        }
+                    }
+                },
+                "frequency": "150s",
+                "query_delay": "90s",
+                "scroll_size": 500
+            }),
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::util::*;
+
+/// A detector applies an analytical function to specific fields in your data.
+///
+/// To detect unusually high mean response times per airline:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let detector =
+/// Detector::new("high_mean")
+///     .field_name("responsetime")
+///     .partition_field_name("airline");
+/// ```
+/// <https://www.elastic.co/guide/en/machine-learning/current/ml-ad-functions.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Detector {
+    function: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    by_field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    over_field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    partition_field_name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    detector_description: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    use_null: Option<bool>,
+}
+
+impl Detector {
+    /// Creates an instance of [`Detector`]
+    ///
+    /// - `function` - The analysis function that is used, such as `count`, `rare`, `mean`,
+    ///   `min`, `max` or `sum`
+    pub fn new(function: impl Into<String>) -> Self {
+        Self {
+            function: function.into(),
+            field_name: None,
+            by_field_name: None,
+            over_field_name: None,
+            partition_field_name: None,
+            detector_description: None,
+            use_null: None,
+        }
+    }
+
+    /// The field that the detector uses in the function. Required by all functions except
+    /// `count`, `rare` and their variants.
+    pub fn field_name(mut self, field_name: impl Into<String>) -> Self {
+        self.field_name = Some(field_name.into());
+        self
+    }
+
+    /// The field used to split the data. In particular, this property is used for analyzing the
+    /// splits with respect to their own history.
+    pub fn by_field_name(mut self, by_field_name: impl Into<String>) -> Self {
+        self.by_field_name = Some(by_field_name.into());
+        self
+    }
+
+    /// The field used to split the data. In particular, this property is used for analyzing the
+    /// splits with respect to the history of all splits.
+    pub fn over_field_name(mut self, over_field_name: impl Into<String>) -> Self {
+        self.over_field_name = Some(over_field_name.into());
+        self
+    }
+
+    /// The field used to segment the analysis. When you use this property, you have completely
+    /// independent baselines for each value of this field.
+    pub fn partition_field_name(mut self, partition_field_name: impl Into<String>) -> Self {
+        self.partition_field_name = Some(partition_field_name.into());
+        self
+    }
+
+    /// A description of the detector
+    pub fn detector_description(mut self, detector_description: impl Into<String>) -> Self {
+        self.detector_description = Some(detector_description.into());
+        self
+    }
+
+    /// Defines whether a new series is used as the null series when there is no value for the
+    /// by or partition fields. Defaults to `false`.
+    pub fn use_null(mut self, use_null: bool) -> Self {
+        self.use_null = Some(use_null);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Detector::new("count"), json!({ "function": "count" }));
+
+        assert_serialize(
+            Detector::new("high_mean")
+                .field_name("responsetime")
+                .by_field_name("endpoint")
+                .over_field_name("clientip")
+                .partition_field_name("airline")
+                .detector_description("High response times")
+                .use_null(true),
+            json!({
+                "function": "high_mean",
+                "field_name": "responsetime",
+                "by_field_name": "endpoint",
+                "over_field_name": "clientip",
+                "partition_field_name": "airline",
+                "detector_description": "High response times",
+                "use_null": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Machine learning anomaly detection jobs model the normal behavior of your time series data,
+//! and datafeeds retrieve the data the jobs analyze from Elasticsearch.
+//!
+//! <https://www.elastic.co/guide/en/machine-learning/current/ml-ad-overview.html>
+
+mod analysis_config;
+mod anomaly_detection_job;
+mod data_description;
+mod datafeed;
+mod detector;
+
+pub use self::analysis_config::*;
+pub use self::anomaly_detection_job::*;
+pub use self::data_description::*;
+pub use self::datafeed::*;
+pub use self::detector::*;
@@ -0,0 +1,13 @@
+//! Query rules allow customization of search results for queries that match specified
+//! criteria metadata, by pinning or excluding documents. Rules are grouped into rulesets and
+//! applied at search time with the `rule` query.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-using-query-rules.html>
+
+mod query_rule;
+mod query_rule_criteria;
+mod query_ruleset;
+
+pub use self::query_rule::*;
+pub use self::query_rule_criteria::*;
+pub use self::query_ruleset::*;
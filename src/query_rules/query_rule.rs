@@ -0,0 +1,145 @@
+use crate::query_rules::*;
+use crate::search::*;
+use crate::util::*;
+
+/// The action a [`QueryRule`] takes on the documents it references
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryRuleType {
+    /// Pins the documents to the top of the results
+    Pinned,
+
+    /// Excludes the documents from the results
+    Exclude,
+}
+
+/// A query rule, pinning or excluding documents when all of its criteria are met.
+///
+/// To pin two documents for queries that are exactly `pugs`:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let rule =
+/// QueryRule::pinned(
+///     "pug-rule",
+///     [QueryRuleCriteria::new(QueryRuleCriteriaType::Exact, "user_query", ["pugs"])],
+///     PinnedQueryValues::ids(["id1", "id2"]),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-query-ruleset.html#put-query-ruleset-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryRule {
+    rule_id: String,
+
+    r#type: QueryRuleType,
+
+    criteria: Vec<QueryRuleCriteria>,
+
+    actions: PinnedQueryValues,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    priority: Option<u32>,
+}
+
+impl QueryRule {
+    /// Creates an instance of [`QueryRule`]
+    ///
+    /// - `rule_id` - A unique identifier for the rule
+    /// - `type` - The action to take on the documents
+    /// - `criteria` - The criteria that must be met for the rule to be applied
+    /// - `actions` - The ids or documents to pin or exclude
+    pub fn new<I>(
+        rule_id: impl Into<String>,
+        r#type: QueryRuleType,
+        criteria: I,
+        actions: PinnedQueryValues,
+    ) -> Self
+    where
+        I: IntoIterator<Item = QueryRuleCriteria>,
+    {
+        Self {
+            rule_id: rule_id.into(),
+            r#type,
+            criteria: criteria.into_iter().collect(),
+            actions,
+            priority: None,
+        }
+    }
+
+    /// Creates an instance of [`QueryRule`] that pins documents to the top of the results
+    pub fn pinned<I>(rule_id: impl Into<String>, criteria: I, actions: PinnedQueryValues) -> Self
+    where
+        I: IntoIterator<Item = QueryRuleCriteria>,
+    {
+        Self::new(rule_id, QueryRuleType::Pinned, criteria, actions)
+    }
+
+    /// Creates an instance of [`QueryRule`] that excludes documents from the results
+    pub fn exclude<I>(rule_id: impl Into<String>, criteria: I, actions: PinnedQueryValues) -> Self
+    where
+        I: IntoIterator<Item = QueryRuleCriteria>,
+    {
+        Self::new(rule_id, QueryRuleType::Exclude, criteria, actions)
+    }
+
+    /// The priority of the rule, rules with lower values are applied first
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            QueryRule::pinned(
+                "pug-rule",
+                [QueryRuleCriteria::new(
+                    QueryRuleCriteriaType::Exact,
+                    "user_query",
+                    ["pugs"],
+                )],
+                PinnedQueryValues::ids(["id1", "id2"]),
+            )
+            .priority(1),
+            json!({
+                "rule_id": "pug-rule",
+                "type": "pinned",
+                "criteria": [
+                    {
+                        "type": "exact",
+                        "metadata": "user_query",
+                        "values": ["pugs"]
+                    }
+                ],
+                "actions": {
+                    "ids": ["id1", "id2"]
+                },
+                "priority": 1
+            }),
+        );
+
+        assert_serialize(
+            QueryRule::exclude(
+                "hide-docs",
+                [QueryRuleCriteria::always()],
+                PinnedQueryValues::docs([PinnedDocument::new("products", "42")]),
+            ),
+            json!({
+                "rule_id": "hide-docs",
+                "type": "exclude",
+                "criteria": [
+                    { "type": "always" }
+                ],
+                "actions": {
+                    "docs": [
+                        { "_index": "products", "_id": "42" }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::util::*;
+use serde_json::Value;
+
+/// The type of a [`QueryRuleCriteria`], determining how match criteria metadata is compared
+/// to the criteria values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryRuleCriteriaType {
+    /// Matches all queries, regardless of input
+    Always,
+
+    /// Matches if the metadata exactly matches one of the values
+    Exact,
+
+    /// Matches if the metadata matches one of the values within an allowed Levenshtein edit
+    /// distance
+    Fuzzy,
+
+    /// Matches if the metadata starts with one of the values
+    Prefix,
+
+    /// Matches if the metadata ends with one of the values
+    Suffix,
+
+    /// Matches if the metadata contains one of the values
+    Contains,
+
+    /// Matches if the metadata is less than one of the values
+    Lt,
+
+    /// Matches if the metadata is less than or equal to one of the values
+    Lte,
+
+    /// Matches if the metadata is greater than one of the values
+    Gt,
+
+    /// Matches if the metadata is greater than or equal to one of the values
+    Gte,
+}
+
+/// Criteria that must be met for a query rule to be applied.
+///
+/// To match queries that are exactly `puggles` or `pugs`:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let criteria =
+/// QueryRuleCriteria::new(QueryRuleCriteriaType::Exact, "user_query", ["puggles", "pugs"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-query-ruleset.html#put-query-ruleset-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryRuleCriteria {
+    r#type: QueryRuleCriteriaType,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    values: Vec<Value>,
+}
+
+impl QueryRuleCriteria {
+    /// Creates an instance of [`QueryRuleCriteria`]
+    ///
+    /// - `type` - How the metadata is compared to the values
+    /// - `metadata` - The metadata field to match against, provided in the `match_criteria` of
+    ///   the `rule` query
+    /// - `values` - The values to match against the metadata field
+    pub fn new<I>(r#type: QueryRuleCriteriaType, metadata: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        Self {
+            r#type,
+            metadata: Some(metadata.into()),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Creates an instance of [`QueryRuleCriteria`] that matches all queries
+    pub fn always() -> Self {
+        Self {
+            r#type: QueryRuleCriteriaType::Always,
+            metadata: None,
+            values: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(QueryRuleCriteria::always(), json!({ "type": "always" }));
+
+        assert_serialize(
+            QueryRuleCriteria::new(QueryRuleCriteriaType::Exact, "user_query", ["pugs"]),
+            json!({
+                "type": "exact",
+                "metadata": "user_query",
+                "values": ["pugs"]
+            }),
+        );
+
+        assert_serialize(
+            QueryRuleCriteria::new(QueryRuleCriteriaType::Gte, "user_age", [18]),
+            json!({
+                "type": "gte",
+                "metadata": "user_age",
+                "values": [18]
+            }),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::query_rules::*;
+
+/// Query ruleset body for the create or update query ruleset API.
+///
+/// To create a ruleset with a single rule:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let ruleset =
+/// QueryRuleset::new().rule(QueryRule::pinned(
+///     "pug-rule",
+///     [QueryRuleCriteria::new(QueryRuleCriteriaType::Exact, "user_query", ["pugs"])],
+///     PinnedQueryValues::ids(["id1"]),
+/// ));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-query-ruleset.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct QueryRuleset {
+    rules: Vec<QueryRule>,
+}
+
+impl QueryRuleset {
+    /// Creates an instance of [`QueryRuleset`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a rule to the ruleset
+    pub fn rule(mut self, rule: QueryRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(QueryRuleset::new(), json!({ "rules": [] }));
+
+        assert_serialize(
+            QueryRuleset::new()
+                .rule(QueryRule::pinned(
+                    "rule-1",
+                    [QueryRuleCriteria::always()],
+                    PinnedQueryValues::ids(["id1"]),
+                ))
+                .rule(QueryRule::exclude(
+                    "rule-2",
+                    [QueryRuleCriteria::new(
+                        QueryRuleCriteriaType::Contains,
+                        "user_query",
+                        ["cats"],
+                    )],
+                    PinnedQueryValues::ids(["id2"]),
+                )),
+            json!({
+                "rules": [
+                    {
+                        "rule_id": "rule-1",
+                        "type": "pinned",
+                        "criteria": [{ "type": "always" }],
+                        "actions": { "ids": ["id1"] }
+                    },
+                    {
+                        "rule_id": "rule-2",
+                        "type": "exclude",
+                        "criteria": [
+                            {
+                                "type": "contains",
+                                "metadata": "user_query",
+                                "values": ["cats"]
+                            }
+                        ],
+                        "actions": { "ids": ["id2"] }
+                    }
+                ]
+            }),
+        );
+    }
+}
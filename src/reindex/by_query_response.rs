@@ -0,0 +1,156 @@
+use crate::errors::*;
+use crate::util::*;
+
+/// Response of the reindex, update by query and delete by query APIs
+///
+/// - <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html#docs-reindex-api-response-body>
+/// - <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html#docs-update-by-query-api-response-body>
+/// - <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html#docs-delete-by-query-api-response-body>
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ByQueryResponse {
+    /// The number of milliseconds from start to end of the whole operation
+    #[serde(default)]
+    pub took: u64,
+
+    /// Indicates whether any of the requests executed during the operation timed out
+    #[serde(default)]
+    pub timed_out: bool,
+
+    /// The number of documents that were successfully processed
+    #[serde(default)]
+    pub total: u64,
+
+    /// The number of documents that were successfully updated
+    #[serde(default)]
+    pub updated: u64,
+
+    /// The number of documents that were successfully created
+    #[serde(default)]
+    pub created: u64,
+
+    /// The number of documents that were successfully deleted
+    #[serde(default)]
+    pub deleted: u64,
+
+    /// The number of scroll responses pulled back by the operation
+    #[serde(default)]
+    pub batches: u64,
+
+    /// The number of version conflicts the operation hit
+    #[serde(default)]
+    pub version_conflicts: u64,
+
+    /// The number of documents that were ignored
+    #[serde(default)]
+    pub noops: u64,
+
+    /// The number of retries attempted by the operation
+    #[serde(default)]
+    pub retries: ByQueryRetries,
+
+    /// Number of milliseconds the request slept to conform to `requests_per_second`
+    #[serde(default)]
+    pub throttled_millis: u64,
+
+    /// The number of requests per second effectively executed during the operation
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub requests_per_second: Option<f32>,
+
+    /// Unrecoverable errors, which abort the operation
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub failures: Vec<ByQueryFailure>,
+}
+
+/// The number of retries attempted by a batch operation
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ByQueryRetries {
+    /// The number of bulk actions retried
+    #[serde(default)]
+    pub bulk: u64,
+
+    /// The number of search actions retried
+    #[serde(default)]
+    pub search: u64,
+}
+
+/// Unrecoverable failure of a batch operation, caused either by an indexing or a search failure
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ByQueryFailure {
+    /// Index of the failed document or shard
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub index: Option<String>,
+
+    /// ID of the document that failed to be indexed
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub id: Option<String>,
+
+    /// HTTP status code of the indexing failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub status: Option<u16>,
+
+    /// Cause of the indexing failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub cause: Option<ErrorCause>,
+
+    /// Shard of the search failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub shard: Option<i64>,
+
+    /// Node of the search failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub node: Option<String>,
+
+    /// Cause of the search failure
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub reason: Option<ErrorCause>,
+}
+
+impl ByQueryResponse {
+    /// Whether the whole operation completed without failures or timeouts
+    pub fn is_success(&self) -> bool {
+        !self.timed_out && self.failures.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: ByQueryResponse = serde_json::from_value(json!({
+            "took": 147,
+            "timed_out": false,
+            "total": 120,
+            "deleted": 115,
+            "batches": 1,
+            "version_conflicts": 5,
+            "noops": 0,
+            "retries": { "bulk": 0, "search": 0 },
+            "throttled_millis": 0,
+            "requests_per_second": -1.0,
+            "throttled_until_millis": 0,
+            "failures": [
+                {
+                    "index": "my-index",
+                    "id": "7",
+                    "status": 409,
+                    "cause": {
+                        "type": "version_conflict_engine_exception",
+                        "reason": "[7]: version conflict"
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(response.deleted, 115);
+        assert_eq!(response.version_conflicts, 5);
+        assert_eq!(response.created, 0);
+        assert!(!response.is_success());
+        assert_eq!(
+            response.failures[0].cause.as_ref().map(|cause| &cause.kind),
+            Some(&ErrorKind::VersionConflict)
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Responses of the APIs that process documents in batches: reindex, update by query and delete
+//! by query. When run asynchronously, their progress is reported through the task management
+//! API.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html>
+
+mod by_query_response;
+mod task_response;
+
+pub use self::by_query_response::*;
+pub use self::task_response::*;
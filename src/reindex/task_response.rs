@@ -0,0 +1,147 @@
+use crate::errors::*;
+use crate::reindex::*;
+use crate::util::*;
+
+/// Response of a reindex, update by query or delete by query request submitted with
+/// `wait_for_completion=false`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SubmittedTask {
+    /// ID of the task, which can be used with the task management API
+    pub task: String,
+}
+
+/// Get task API response for a reindex, update by query or delete by query task
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskResponse {
+    /// Whether the task has completed
+    pub completed: bool,
+
+    /// Task details and progress
+    pub task: TaskInfo,
+
+    /// Final response of the operation, once it has completed successfully
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub response: Option<ByQueryResponse>,
+
+    /// Failure of the operation, once it has completed unsuccessfully
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub error: Option<ErrorCause>,
+}
+
+/// Details of a running or completed task
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskInfo {
+    /// Node running the task
+    pub node: String,
+
+    /// Task ID on the node
+    pub id: u64,
+
+    /// Action performed by the task, e.g. `indices:data/write/reindex`
+    pub action: String,
+
+    /// Description of the task
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub description: Option<String>,
+
+    /// Start time of the task, in milliseconds since epoch
+    pub start_time_in_millis: u64,
+
+    /// Time the task has been running, in nanoseconds
+    pub running_time_in_nanos: u64,
+
+    /// Whether the task can be cancelled
+    pub cancellable: bool,
+
+    /// Progress of the task
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub status: Option<TaskStatus>,
+}
+
+/// Progress of a reindex, update by query or delete by query task
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct TaskStatus {
+    /// The number of documents to process
+    #[serde(default)]
+    pub total: u64,
+
+    /// The number of documents updated so far
+    #[serde(default)]
+    pub updated: u64,
+
+    /// The number of documents created so far
+    #[serde(default)]
+    pub created: u64,
+
+    /// The number of documents deleted so far
+    #[serde(default)]
+    pub deleted: u64,
+
+    /// The number of scroll responses pulled back so far
+    #[serde(default)]
+    pub batches: u64,
+
+    /// The number of version conflicts so far
+    #[serde(default)]
+    pub version_conflicts: u64,
+
+    /// The number of documents ignored so far
+    #[serde(default)]
+    pub noops: u64,
+}
+
+impl TaskStatus {
+    /// Number of documents processed so far
+    pub fn processed(&self) -> u64 {
+        self.updated + self.created + self.deleted + self.version_conflicts + self.noops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let submitted: SubmittedTask =
+            serde_json::from_value(json!({ "task": "r1A2WoRbTwKZ516z6NEs5A:36619" })).unwrap();
+
+        assert_eq!(submitted.task, "r1A2WoRbTwKZ516z6NEs5A:36619");
+
+        let response: TaskResponse = serde_json::from_value(json!({
+            "completed": false,
+            "task": {
+                "node": "r1A2WoRbTwKZ516z6NEs5A",
+                "id": 36619,
+                "type": "transport",
+                "action": "indices:data/write/reindex",
+                "status": {
+                    "total": 6154,
+                    "updated": 3500,
+                    "created": 0,
+                    "deleted": 0,
+                    "batches": 4,
+                    "version_conflicts": 0,
+                    "noops": 0,
+                    "retries": { "bulk": 0, "search": 0 },
+                    "throttled_millis": 0
+                },
+                "description": "reindex from [source] to [dest]",
+                "start_time_in_millis": 1535149899665u64,
+                "running_time_in_nanos": 5926816523u64,
+                "cancellable": true
+            }
+        }))
+        .unwrap();
+
+        assert!(!response.completed);
+        assert_eq!(response.task.id, 36619);
+        assert_eq!(
+            response.task.status.map(|status| status.processed()),
+            Some(3500)
+        );
+        assert!(response.response.is_none());
+    }
+}
@@ -90,6 +90,13 @@ impl DiversifiedSamplerAggregation {
     }
 
     add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.aggs
+            .values()
+            .flat_map(Aggregation::embedded_queries)
+            .collect()
+    }
 }
 
 #[cfg(test)]
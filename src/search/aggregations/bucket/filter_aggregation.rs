@@ -29,6 +29,12 @@ impl Aggregation {
 
 impl FilterAggregation {
     add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        std::iter::once(&self.filter)
+            .chain(self.aggs.values().flat_map(Aggregation::embedded_queries))
+            .collect()
+    }
 }
 
 #[cfg(test)]
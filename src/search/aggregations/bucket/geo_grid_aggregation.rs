@@ -0,0 +1,224 @@
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+macro_rules! geo_grid_aggregation {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $inner:ident, $key:ident, $constructor:ident, $precisions:expr, $default:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Serialize, PartialEq)]
+        pub struct $name {
+            $key: $inner,
+
+            #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+            aggs: Aggregations,
+        }
+
+        #[derive(Debug, Clone, Serialize, PartialEq)]
+        struct $inner {
+            field: String,
+
+            #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+            precision: Option<u8>,
+
+            #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+            bounds: Option<GeoBoundingBox>,
+
+            #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+            size: Option<u64>,
+
+            #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+            shard_size: Option<u64>,
+        }
+
+        impl Aggregation {
+            #[doc = concat!("Creates an instance of [`", stringify!($name), "`]")]
+            ///
+            /// - `field` - `geo_point` or `geo_shape` field to aggregate
+            pub fn $constructor(field: impl Into<String>) -> $name {
+                $name {
+                    $key: $inner {
+                        field: field.into(),
+                        precision: None,
+                        bounds: None,
+                        size: None,
+                        shard_size: None,
+                    },
+                    aggs: Aggregations::new(),
+                }
+            }
+        }
+
+        impl $name {
+            #[doc = concat!(
+                "Precision of the cells, in the range `",
+                stringify!($precisions),
+                "`. Values outside of this range are ignored.\n\nDefaults to `",
+                $default,
+                "`."
+            )]
+            pub fn precision(mut self, precision: u8) -> Self {
+                if ($precisions).contains(&precision) {
+                    self.$key.precision = Some(precision);
+                }
+                self
+            }
+
+            /// Only aggregates the cells intersecting the bounding box, e.g. the viewport of a
+            /// map. Cells on the edge of the box are returned in full.
+            pub fn bounds(mut self, bounds: GeoBoundingBox) -> Self {
+                self.$key.bounds = Some(bounds);
+                self
+            }
+
+            /// Maximum number of buckets to return, keeping the cells with the most documents.
+            ///
+            /// Defaults to `10000`.
+            pub fn size(mut self, size: impl TryInto<u64>) -> Self {
+                if let Ok(size) = size.try_into() {
+                    self.$key.size = Some(size);
+                }
+                self
+            }
+
+            /// Number of buckets returned from each shard, allowing more accurate counting of
+            /// the top cells.
+            ///
+            /// Defaults to `max(10, (size x number-of-shards))`.
+            pub fn shard_size(mut self, shard_size: impl TryInto<u64>) -> Self {
+                if let Ok(shard_size) = shard_size.try_into() {
+                    self.$key.shard_size = Some(shard_size);
+                }
+                self
+            }
+
+            add_aggregate!();
+
+            pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+                self.aggs
+                    .values()
+                    .flat_map(Aggregation::embedded_queries)
+                    .collect()
+            }
+        }
+    };
+}
+
+geo_grid_aggregation!(
+    /// A multi-bucket aggregation that groups `geo_point` and `geo_shape` values into buckets
+    /// that represent a grid of [geohash](https://en.wikipedia.org/wiki/Geohash) cells.
+    ///
+    /// When aggregating a `geo_shape` field, a shape is counted in every cell it intersects, so
+    /// the sum of the bucket document counts can exceed the number of matching documents, and
+    /// high precisions on large shapes are expensive. Limiting the aggregation to the visible
+    /// area with [`bounds`](Self::bounds) keeps the number of cells in check:
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let aggregation =
+    /// Aggregation::geohash_grid("location")
+    ///     .precision(8)
+    ///     .bounds(GeoBoundingBox::MainDiagonal {
+    ///         top_left: GeoPoint::coordinates(52.4, 4.7),
+    ///         bottom_right: GeoPoint::coordinates(52.3, 5.0),
+    ///     });
+    /// ```
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geohashgrid-aggregation.html>
+    GeohashGridAggregation,
+    GeohashGridAggregationInner,
+    geohash_grid,
+    geohash_grid,
+    1..=12,
+    "5"
+);
+
+geo_grid_aggregation!(
+    /// A multi-bucket aggregation that groups `geo_point` and `geo_shape` values into buckets
+    /// that represent a grid of [map tiles](https://en.wikipedia.org/wiki/Tiled_web_map), where
+    /// the precision is the zoom level.
+    ///
+    /// When aggregating a `geo_shape` field, a shape is counted in every tile it intersects, so
+    /// the sum of the bucket document counts can exceed the number of matching documents.
+    /// Limiting the aggregation to the visible area with [`bounds`](Self::bounds) keeps the
+    /// number of tiles in check:
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let aggregation =
+    /// Aggregation::geotile_grid("location")
+    ///     .precision(22)
+    ///     .bounds(GeoBoundingBox::MainDiagonal {
+    ///         top_left: GeoPoint::coordinates(52.4, 4.7),
+    ///         bottom_right: GeoPoint::coordinates(52.3, 5.0),
+    ///     });
+    /// ```
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geotilegrid-aggregation.html>
+    GeotileGridAggregation,
+    GeotileGridAggregationInner,
+    geotile_grid,
+    geotile_grid,
+    0..=29,
+    "7"
+);
+
+geo_grid_aggregation!(
+    /// A multi-bucket aggregation that groups `geo_point` and `geo_shape` values into buckets
+    /// that represent a grid of [H3](https://h3geo.org/docs/) hexagonal cells.
+    ///
+    /// When aggregating a `geo_shape` field, a shape is counted in every cell it intersects, so
+    /// the sum of the bucket document counts can exceed the number of matching documents.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geohexgrid-aggregation.html>
+    GeohexGridAggregation,
+    GeohexGridAggregationInner,
+    geohex_grid,
+    geohex_grid,
+    0..=15,
+    "6"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::geohash_grid("location"),
+            json!({ "geohash_grid": { "field": "location" } }),
+        );
+
+        assert_serialize(
+            Aggregation::geotile_grid("location")
+                .precision(8)
+                .bounds(GeoBoundingBox::WellKnownText {
+                    wkt: "BBOX (4.7, 5.0, 52.4, 52.3)".into(),
+                })
+                .size(100)
+                .shard_size(200)
+                .aggregate("max_price", Aggregation::max("price")),
+            json!({
+                "geotile_grid": {
+                    "field": "location",
+                    "precision": 8,
+                    "bounds": { "wkt": "BBOX (4.7, 5.0, 52.4, 52.3)" },
+                    "size": 100,
+                    "shard_size": 200
+                },
+                "aggs": {
+                    "max_price": { "max": { "field": "price" } }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Aggregation::geohex_grid("location").precision(16),
+            json!({ "geohex_grid": { "field": "location" } }),
+        );
+
+        assert_serialize(
+            Aggregation::geohash_grid("location").precision(0),
+            json!({ "geohash_grid": { "field": "location" } }),
+        );
+    }
+}
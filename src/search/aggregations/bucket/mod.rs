@@ -21,10 +21,16 @@
 
 mod diversified_sampler_aggregation;
 mod filter_aggregation;
+mod geo_grid_aggregation;
 mod sampler_aggregation;
+mod significant_terms_aggregation;
+mod significant_text_aggregation;
 mod terms_aggregation;
 
 pub use self::diversified_sampler_aggregation::*;
 pub use self::filter_aggregation::*;
+pub use self::geo_grid_aggregation::*;
 pub use self::sampler_aggregation::*;
+pub use self::significant_terms_aggregation::*;
+pub use self::significant_text_aggregation::*;
 pub use self::terms_aggregation::*;
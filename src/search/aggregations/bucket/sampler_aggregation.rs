@@ -40,6 +40,13 @@ impl SamplerAggregation {
     }
 
     add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.aggs
+            .values()
+            .flat_map(Aggregation::embedded_queries)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,202 @@
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// An aggregation that returns interesting or unusual occurrences of terms in a set, i.e. terms
+/// that are more frequent in the documents matching the query (the foreground set) than in the
+/// index (the background set).
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new()
+///     .query(Query::terms("force", ["British Transport Police"]))
+///     .aggregate(
+///         "significant_crime_types",
+///         Aggregation::significant_terms("crime_type")
+///             .significance_heuristic(SignificanceHeuristic::gnd())
+///             .min_doc_count(10u16),
+///     );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significantterms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SignificantTermsAggregation {
+    significant_terms: SignificantTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SignificantTermsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u16>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u16>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    background_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    significance_heuristic: Option<SignificanceHeuristic>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SignificantTermsAggregation`]
+    ///
+    /// - `field` - field to find significant terms in
+    pub fn significant_terms(field: impl Into<String>) -> SignificantTermsAggregation {
+        SignificantTermsAggregation {
+            significant_terms: SignificantTermsAggregationInner {
+                field: field.into(),
+                size: None,
+                shard_size: None,
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                background_filter: None,
+                include: None,
+                exclude: None,
+                significance_heuristic: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl SignificantTermsAggregation {
+    /// How many term buckets should be returned out of the overall terms list, defaults to `10`
+    pub fn size(mut self, size: impl TryInto<u64>) -> Self {
+        if let Ok(size) = size.try_into() {
+            self.significant_terms.size = Some(size);
+        }
+        self
+    }
+
+    /// How many candidate terms each shard returns, higher values improve the accuracy of the
+    /// significance scores at the cost of performance
+    pub fn shard_size(mut self, shard_size: impl TryInto<u64>) -> Self {
+        if let Ok(shard_size) = shard_size.try_into() {
+            self.significant_terms.shard_size = Some(shard_size);
+        }
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits, defaults to `3`
+    pub fn min_doc_count(mut self, min_doc_count: impl Into<u16>) -> Self {
+        self.significant_terms.min_doc_count = Some(min_doc_count.into());
+        self
+    }
+
+    /// Only considers the terms that match more than a configured number of hits on each shard
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: impl Into<u16>) -> Self {
+        self.significant_terms.shard_min_doc_count = Some(shard_min_doc_count.into());
+        self
+    }
+
+    /// Narrows the background set, which defaults to all documents in the index, to the
+    /// documents matching the query
+    pub fn background_filter(mut self, background_filter: impl Into<Query>) -> Self {
+        let background_filter = background_filter.into();
+
+        if !background_filter.should_skip() {
+            self.significant_terms.background_filter = Some(background_filter);
+        }
+        self
+    }
+
+    /// Filters the values for which buckets will be created, see
+    /// [`TermsAggregation::include`]
+    pub fn include(mut self, include: impl Into<TermsInclude>) -> Self {
+        let include = include.into();
+
+        if !include.should_skip() {
+            self.significant_terms.include = Some(include);
+        }
+        self
+    }
+
+    /// Filters out values for which buckets would be created, either with a regular expression
+    /// or a list of exact values. Exclusions take precedence over [`include`](Self::include).
+    pub fn exclude(mut self, exclude: impl Into<TermsExclude>) -> Self {
+        let exclude = exclude.into();
+
+        if !exclude.should_skip() {
+            self.significant_terms.exclude = Some(exclude);
+        }
+        self
+    }
+
+    /// Heuristic scoring the significance of the terms, defaults to
+    /// [`jlh`](SignificanceHeuristic::jlh)
+    pub fn significance_heuristic(mut self, significance_heuristic: SignificanceHeuristic) -> Self {
+        self.significant_terms.significance_heuristic = Some(significance_heuristic);
+        self
+    }
+
+    add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.significant_terms
+            .background_filter
+            .iter()
+            .chain(self.aggs.values().flat_map(Aggregation::embedded_queries))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::significant_terms("crime_type"),
+            json!({ "significant_terms": { "field": "crime_type" } }),
+        );
+
+        assert_serialize(
+            Aggregation::significant_terms("crime_type")
+                .size(20)
+                .shard_size(100)
+                .min_doc_count(10u16)
+                .shard_min_doc_count(2u16)
+                .background_filter(Query::term("city", "London"))
+                .include("burglary.*")
+                .significance_heuristic(
+                    SignificanceHeuristic::chi_square().background_is_superset(false),
+                )
+                .aggregate("forces", Aggregation::terms("force")),
+            json!({
+                "significant_terms": {
+                    "field": "crime_type",
+                    "size": 20,
+                    "shard_size": 100,
+                    "min_doc_count": 10,
+                    "shard_min_doc_count": 2,
+                    "background_filter": { "term": { "city": { "value": "London" } } },
+                    "include": "burglary.*",
+                    "chi_square": { "background_is_superset": false }
+                },
+                "aggs": {
+                    "forces": { "terms": { "field": "force" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// An aggregation that returns interesting or unusual occurrences of free-text terms in a set.
+/// Unlike the [`SignificantTermsAggregation`], it analyzes text fields on the fly, which makes
+/// it expensive on large sets of documents, so it is typically nested under a
+/// [`SamplerAggregation`].
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new()
+///     .query(Query::r#match("content", "Bird flu"))
+///     .aggregate(
+///         "sample",
+///         Aggregation::sampler().shard_size(100).aggregate(
+///             "keywords",
+///             Aggregation::significant_text("content")
+///                 .filter_duplicate_text(true)
+///                 .significance_heuristic(SignificanceHeuristic::mutual_information()),
+///         ),
+///     );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significanttext-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SignificantTextAggregation {
+    significant_text: SignificantTextAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SignificantTextAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u16>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u16>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    background_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter_duplicate_text: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    source_fields: Vec<String>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    significance_heuristic: Option<SignificanceHeuristic>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SignificantTextAggregation`]
+    ///
+    /// - `field` - text field to find significant terms in
+    pub fn significant_text(field: impl Into<String>) -> SignificantTextAggregation {
+        SignificantTextAggregation {
+            significant_text: SignificantTextAggregationInner {
+                field: field.into(),
+                size: None,
+                shard_size: None,
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                background_filter: None,
+                filter_duplicate_text: None,
+                source_fields: vec![],
+                significance_heuristic: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl SignificantTextAggregation {
+    /// How many term buckets should be returned out of the overall terms list, defaults to `10`
+    pub fn size(mut self, size: impl TryInto<u64>) -> Self {
+        if let Ok(size) = size.try_into() {
+            self.significant_text.size = Some(size);
+        }
+        self
+    }
+
+    /// How many candidate terms each shard returns, higher values improve the accuracy of the
+    /// significance scores at the cost of performance
+    pub fn shard_size(mut self, shard_size: impl TryInto<u64>) -> Self {
+        if let Ok(shard_size) = shard_size.try_into() {
+            self.significant_text.shard_size = Some(shard_size);
+        }
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits, defaults to `3`
+    pub fn min_doc_count(mut self, min_doc_count: impl Into<u16>) -> Self {
+        self.significant_text.min_doc_count = Some(min_doc_count.into());
+        self
+    }
+
+    /// Only considers the terms that match more than a configured number of hits on each shard
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: impl Into<u16>) -> Self {
+        self.significant_text.shard_min_doc_count = Some(shard_min_doc_count.into());
+        self
+    }
+
+    /// Narrows the background set, which defaults to all documents in the index, to the
+    /// documents matching the query
+    pub fn background_filter(mut self, background_filter: impl Into<Query>) -> Self {
+        let background_filter = background_filter.into();
+
+        if !background_filter.should_skip() {
+            self.significant_text.background_filter = Some(background_filter);
+        }
+        self
+    }
+
+    /// Filters out duplicate sections of text, such as boilerplate or copies of the same
+    /// article, which would otherwise skew the statistics
+    pub fn filter_duplicate_text(mut self, filter_duplicate_text: bool) -> Self {
+        self.significant_text.filter_duplicate_text = Some(filter_duplicate_text);
+        self
+    }
+
+    /// Source fields to read the text from, when the aggregated `field` is not present in the
+    /// `_source`, e.g. when it is populated by `copy_to`
+    pub fn source_fields<I>(mut self, source_fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.significant_text
+            .source_fields
+            .extend(source_fields.into_iter().map(|field| field.to_string()));
+        self
+    }
+
+    /// Heuristic scoring the significance of the terms, defaults to
+    /// [`jlh`](SignificanceHeuristic::jlh)
+    pub fn significance_heuristic(mut self, significance_heuristic: SignificanceHeuristic) -> Self {
+        self.significant_text.significance_heuristic = Some(significance_heuristic);
+        self
+    }
+
+    add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.significant_text
+            .background_filter
+            .iter()
+            .chain(self.aggs.values().flat_map(Aggregation::embedded_queries))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::significant_text("content"),
+            json!({ "significant_text": { "field": "content" } }),
+        );
+
+        assert_serialize(
+            Aggregation::significant_text("content")
+                .size(5)
+                .shard_size(50)
+                .min_doc_count(2u16)
+                .shard_min_doc_count(1u16)
+                .background_filter(Query::term("category", "news"))
+                .filter_duplicate_text(true)
+                .source_fields(["title", "body"])
+                .significance_heuristic(SignificanceHeuristic::percentage()),
+            json!({
+                "significant_text": {
+                    "field": "content",
+                    "size": 5,
+                    "shard_size": 50,
+                    "min_doc_count": 2,
+                    "shard_min_doc_count": 1,
+                    "background_filter": { "term": { "category": { "value": "news" } } },
+                    "filter_duplicate_text": true,
+                    "source_fields": ["title", "body"],
+                    "percentage": {}
+                }
+            }),
+        );
+    }
+}
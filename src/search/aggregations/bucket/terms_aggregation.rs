@@ -31,6 +31,12 @@ struct TermsAggregationInner {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
 }
 
 /// Terms Aggregation sorting struct
@@ -69,6 +75,8 @@ impl Aggregation {
                 order: vec![],
                 min_doc_count: None,
                 missing: None,
+                include: None,
+                exclude: None,
             },
             aggs: Aggregations::new(),
         }
@@ -131,7 +139,52 @@ impl TermsAggregation {
         self
     }
 
+    /// Filters the values for which buckets will be created, either with a regular expression,
+    /// a list of exact values or a partition of the unique values.
+    ///
+    /// Partitions allow aggregating very high-cardinality fields in several passes, by sending
+    /// one request per partition:
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let searches = (0..20).map(|partition| {
+    ///     Search::new().size(0).aggregate(
+    ///         "expired_sessions",
+    ///         Aggregation::terms("account_id")
+    ///             .include(TermsInclude::partition(partition, 20))
+    ///             .size(10_000),
+    ///     )
+    /// });
+    /// # assert_eq!(searches.count(), 20);
+    /// ```
+    /// Partitions outside of `num_partitions` are ignored.
+    pub fn include(mut self, include: impl Into<TermsInclude>) -> Self {
+        let include = include.into();
+
+        if !include.should_skip() {
+            self.terms.include = Some(include);
+        }
+        self
+    }
+
+    /// Filters out values for which buckets would be created, either with a regular expression
+    /// or a list of exact values. Exclusions take precedence over [`include`](Self::include).
+    pub fn exclude(mut self, exclude: impl Into<TermsExclude>) -> Self {
+        let exclude = exclude.into();
+
+        if !exclude.should_skip() {
+            self.terms.exclude = Some(exclude);
+        }
+        self
+    }
+
     add_aggregate!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.aggs
+            .values()
+            .flat_map(Aggregation::embedded_queries)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -195,5 +248,48 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Aggregation::terms("account_id")
+                .include(TermsInclude::partition(0, 20))
+                .size(10_000),
+            json!({
+                "terms": {
+                    "field": "account_id",
+                    "size": 10_000,
+                    "include": { "partition": 0, "num_partitions": 20 }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Aggregation::terms("make")
+                .include(".*sport.*")
+                .include(TermsInclude::partition(20, 20)),
+            json!({ "terms": { "field": "make", "include": ".*sport.*" } }),
+        );
+
+        assert_serialize(
+            Aggregation::terms("make")
+                .include(".*sport.*")
+                .exclude("water_.*")
+                .exclude(""),
+            json!({
+                "terms": { "field": "make", "include": ".*sport.*", "exclude": "water_.*" }
+            }),
+        );
+
+        assert_serialize(
+            Aggregation::terms("make")
+                .include(TermsInclude::values(["mazda", "honda"]))
+                .exclude(TermsExclude::values(["rover", "jensen"])),
+            json!({
+                "terms": {
+                    "field": "make",
+                    "include": ["honda", "mazda"],
+                    "exclude": ["jensen", "rover"]
+                }
+            }),
+        );
     }
 }
@@ -1,5 +1,5 @@
+use crate::search::*;
 use crate::util::*;
-use crate::{Aggregation, Number};
 
 /// A `boxplot` metrics aggregation that computes boxplot of numeric values extracted from the
 /// aggregated documents. These values can be generated from specific numeric or [histogram fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/histogram.html)
@@ -23,8 +23,8 @@ pub struct BoxplotAggregation {
 #[derive(Debug, Clone, Serialize, PartialEq)]
 struct BoxplotAggregationInner {
     field: String,
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    compression: Option<Number>,
+    #[serde(flatten)]
+    tdigest: TDigest,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Number>,
 }
@@ -37,7 +37,7 @@ impl Aggregation {
         BoxplotAggregation {
             boxplot: BoxplotAggregationInner {
                 field: field.into(),
-                compression: None,
+                tdigest: TDigest::new(),
                 missing: None,
             },
         }
@@ -60,7 +60,13 @@ impl BoxplotAggregation {
     /// data which arrives sorted and in-order) the default settings will produce a TDigest roughly
     /// 64KB in size. In practice data tends to be more random and the TDigest will use less memory.
     pub fn compression(mut self, compression: impl Into<Number>) -> Self {
-        self.boxplot.compression = Some(compression.into());
+        self.boxplot.tdigest = self.boxplot.tdigest.compression(compression);
+        self
+    }
+
+    /// Options of the TDigest algorithm used to approximate the quartiles, see [`TDigest`]
+    pub fn tdigest(mut self, tdigest: TDigest) -> Self {
+        self.boxplot.tdigest = tdigest;
         self
     }
 
@@ -95,5 +101,16 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Aggregation::boxplot("test_field")
+                .tdigest(TDigest::new().execution_hint(TDigestExecutionHint::HighAccuracy)),
+            json!({
+                "boxplot": {
+                    "field": "test_field",
+                    "execution_hint": "high_accuracy"
+                }
+            }),
+        );
     }
 }
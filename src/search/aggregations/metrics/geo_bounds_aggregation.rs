@@ -0,0 +1,65 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A metric aggregation that computes the geographic bounding box containing all values for a
+/// `geo_point` or `geo_shape` field.
+///
+/// For `geo_shape` fields the bounding box covers the whole extent of every shape, not only the
+/// points it is made of.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-geobounds-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoBoundsAggregation {
+    geo_bounds: GeoBoundsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoBoundsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    wrap_longitude: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoBoundsAggregation`]
+    ///
+    /// - `field` - `geo_point` or `geo_shape` field to aggregate
+    pub fn geo_bounds(field: impl Into<String>) -> GeoBoundsAggregation {
+        GeoBoundsAggregation {
+            geo_bounds: GeoBoundsAggregationInner {
+                field: field.into(),
+                wrap_longitude: None,
+            },
+        }
+    }
+}
+
+impl GeoBoundsAggregation {
+    /// Specifies whether the bounding box should be allowed to overlap the international date
+    /// line.
+    ///
+    /// Defaults to `true`.
+    pub fn wrap_longitude(mut self, wrap_longitude: bool) -> Self {
+        self.geo_bounds.wrap_longitude = Some(wrap_longitude);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::geo_bounds("location"),
+            json!({ "geo_bounds": { "field": "location" } }),
+        );
+
+        assert_serialize(
+            Aggregation::geo_bounds("location").wrap_longitude(false),
+            json!({ "geo_bounds": { "field": "location", "wrap_longitude": false } }),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that approximates the
+/// [median absolute deviation](https://en.wikipedia.org/wiki/Median_absolute_deviation) of its
+/// search results.
+///
+/// Median absolute deviation is a measure of variability. It is a robust statistic, meaning that
+/// it is useful for describing data that may have outliers, or may not be normally distributed.
+///
+/// The median is approximated with the TDigest algorithm, see [`TDigest`].
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-median-absolute-deviation-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MedianAbsoluteDeviationAggregation {
+    median_absolute_deviation: MedianAbsoluteDeviationAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MedianAbsoluteDeviationAggregationInner {
+    field: String,
+
+    #[serde(flatten)]
+    tdigest: TDigest,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MedianAbsoluteDeviationAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn median_absolute_deviation(
+        field: impl Into<String>,
+    ) -> MedianAbsoluteDeviationAggregation {
+        MedianAbsoluteDeviationAggregation {
+            median_absolute_deviation: MedianAbsoluteDeviationAggregationInner {
+                field: field.into(),
+                tdigest: TDigest::new(),
+                missing: None,
+            },
+        }
+    }
+}
+
+impl MedianAbsoluteDeviationAggregation {
+    /// Options of the TDigest algorithm used to approximate the median
+    pub fn tdigest(mut self, tdigest: TDigest) -> Self {
+        self.median_absolute_deviation.tdigest = tdigest;
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing(mut self, missing: impl Into<Number>) -> Self {
+        self.median_absolute_deviation.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::median_absolute_deviation("test_field"),
+            json!({ "median_absolute_deviation": { "field": "test_field" } }),
+        );
+
+        assert_serialize(
+            Aggregation::median_absolute_deviation("test_field")
+                .tdigest(
+                    TDigest::new()
+                        .compression(100)
+                        .execution_hint(TDigestExecutionHint::HighAccuracy),
+                )
+                .missing(5),
+            json!({
+                "median_absolute_deviation": {
+                    "field": "test_field",
+                    "compression": 100,
+                    "execution_hint": "high_accuracy",
+                    "missing": 5
+                }
+            }),
+        );
+    }
+}
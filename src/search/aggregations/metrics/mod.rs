@@ -13,8 +13,12 @@
 mod avg_aggregation;
 mod boxplot_aggregation;
 mod cardinality_aggregation;
+mod geo_bounds_aggregation;
 mod max_aggregation;
+mod median_absolute_deviation_aggregation;
 mod min_aggregation;
+mod percentile_ranks_aggregation;
+mod percentiles_aggregation;
 mod rate_aggregation;
 mod sum_aggregation;
 mod top_hits_aggregation;
@@ -22,8 +26,12 @@ mod top_hits_aggregation;
 pub use self::avg_aggregation::*;
 pub use self::boxplot_aggregation::*;
 pub use self::cardinality_aggregation::*;
+pub use self::geo_bounds_aggregation::*;
 pub use self::max_aggregation::*;
+pub use self::median_absolute_deviation_aggregation::*;
 pub use self::min_aggregation::*;
+pub use self::percentile_ranks_aggregation::*;
+pub use self::percentiles_aggregation::*;
 pub use self::rate_aggregation::*;
 pub use self::sum_aggregation::*;
 pub use self::top_hits_aggregation::*;
@@ -0,0 +1,112 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentile ranks over numeric
+/// values extracted from the aggregated documents.
+///
+/// Percentile rank show the percentage of observed values which are below certain value. For
+/// example, if a value is greater than or equal to 95% of the observed values it is said to be
+/// at the 95th percentile rank.
+///
+/// To compute the rank of load times of 500 and 600 milliseconds:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let aggregation =
+/// Aggregation::percentile_ranks("load_time", [500, 600]).method(Hdr::new(3));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-rank-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentileRanksAggregation {
+    percentile_ranks: PercentileRanksAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentileRanksAggregationInner {
+    field: String,
+
+    values: Vec<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    method: Option<PercentilesMethod>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentileRanksAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    /// - `values` - values to calculate the percentile ranks of
+    pub fn percentile_ranks<T>(field: impl Into<String>, values: T) -> PercentileRanksAggregation
+    where
+        T: IntoIterator,
+        T::Item: Into<Number>,
+    {
+        PercentileRanksAggregation {
+            percentile_ranks: PercentileRanksAggregationInner {
+                field: field.into(),
+                values: values.into_iter().map(Into::into).collect(),
+                keyed: None,
+                method: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl PercentileRanksAggregation {
+    /// Returns the percentile ranks as an array of key-value pairs instead of a hash when set to
+    /// `false`.
+    ///
+    /// Defaults to `true`.
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentile_ranks.keyed = Some(keyed);
+        self
+    }
+
+    /// Algorithm used to approximate the percentile ranks, either [`TDigest`] or [`Hdr`]
+    pub fn method(mut self, method: impl Into<PercentilesMethod>) -> Self {
+        self.percentile_ranks.method = Some(method.into());
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing(mut self, missing: impl Into<Number>) -> Self {
+        self.percentile_ranks.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::percentile_ranks("test_field", [500, 600]),
+            json!({ "percentile_ranks": { "field": "test_field", "values": [500, 600] } }),
+        );
+
+        assert_serialize(
+            Aggregation::percentile_ranks("test_field", [1.5])
+                .keyed(false)
+                .method(Hdr::new(3))
+                .missing(0),
+            json!({
+                "percentile_ranks": {
+                    "field": "test_field",
+                    "values": [1.5],
+                    "keyed": false,
+                    "hdr": { "number_of_significant_value_digits": 3 },
+                    "missing": 0
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentiles over numeric
+/// values extracted from the aggregated documents.
+///
+/// Percentiles show the point at which a certain percentage of observed values occur. For
+/// example, the 95th percentile is the value which is greater than 95% of the observed values.
+///
+/// To compute the 95th and 99th load time percentiles with an HDR histogram:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let aggregation =
+/// Aggregation::percentiles("load_time")
+///     .percents([95.0, 99.0])
+///     .method(Hdr::new(3));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentilesAggregation {
+    percentiles: PercentilesAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentilesAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    percents: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    method: Option<PercentilesMethod>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentilesAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn percentiles(field: impl Into<String>) -> PercentilesAggregation {
+        PercentilesAggregation {
+            percentiles: PercentilesAggregationInner {
+                field: field.into(),
+                percents: vec![],
+                keyed: None,
+                method: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl PercentilesAggregation {
+    /// Percentiles to calculate, between `0` and `100`.
+    ///
+    /// Defaults to `[ 1, 5, 25, 50, 75, 95, 99 ]`.
+    pub fn percents<T>(mut self, percents: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<f64>,
+    {
+        self.percentiles
+            .percents
+            .extend(percents.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the percentiles as an array of key-value pairs instead of a hash when set to
+    /// `false`.
+    ///
+    /// Defaults to `true`.
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentiles.keyed = Some(keyed);
+        self
+    }
+
+    /// Algorithm used to approximate the percentiles, either [`TDigest`] or [`Hdr`]
+    pub fn method(mut self, method: impl Into<PercentilesMethod>) -> Self {
+        self.percentiles.method = Some(method.into());
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing(mut self, missing: impl Into<Number>) -> Self {
+        self.percentiles.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Aggregation::percentiles("test_field"),
+            json!({ "percentiles": { "field": "test_field" } }),
+        );
+
+        assert_serialize(
+            Aggregation::percentiles("test_field")
+                .percents([95.0, 99.0])
+                .keyed(false)
+                .method(TDigest::new().compression(200))
+                .missing(10),
+            json!({
+                "percentiles": {
+                    "field": "test_field",
+                    "percents": [95.0, 99.0],
+                    "keyed": false,
+                    "tdigest": { "compression": 200 },
+                    "missing": 10
+                }
+            }),
+        );
+
+        assert_serialize(
+            Aggregation::percentiles("test_field").method(Hdr::new(3)),
+            json!({
+                "percentiles": {
+                    "field": "test_field",
+                    "hdr": { "number_of_significant_value_digits": 3 }
+                }
+            }),
+        );
+    }
+}
@@ -64,10 +64,38 @@ aggregation!(
     Min(MinAggregation),
     Sum(SumAggregation),
     Rate(RateAggregation),
+    Boxplot(BoxplotAggregation),
+    Percentiles(PercentilesAggregation),
+    PercentileRanks(PercentileRanksAggregation),
+    MedianAbsoluteDeviation(MedianAbsoluteDeviationAggregation),
     Sampler(SamplerAggregation),
     Filter(FilterAggregation),
-    DiversifiedSampler(DiversifiedSamplerAggregation)
+    DiversifiedSampler(DiversifiedSamplerAggregation),
+    SignificantTerms(SignificantTermsAggregation),
+    SignificantText(SignificantTextAggregation),
+    GeohashGrid(GeohashGridAggregation),
+    GeotileGrid(GeotileGridAggregation),
+    GeohexGrid(GeohexGridAggregation),
+    GeoBounds(GeoBoundsAggregation),
 );
 
+impl Aggregation {
+    /// Queries of the aggregation and its sub-aggregations, such as `filter` aggregation queries
+    pub(crate) fn embedded_queries(&self) -> Vec<&crate::Query> {
+        match self {
+            Self::Terms(aggregation) => aggregation.embedded_queries(),
+            Self::Sampler(aggregation) => aggregation.embedded_queries(),
+            Self::Filter(aggregation) => aggregation.embedded_queries(),
+            Self::DiversifiedSampler(aggregation) => aggregation.embedded_queries(),
+            Self::SignificantTerms(aggregation) => aggregation.embedded_queries(),
+            Self::SignificantText(aggregation) => aggregation.embedded_queries(),
+            Self::GeohashGrid(aggregation) => aggregation.embedded_queries(),
+            Self::GeotileGrid(aggregation) => aggregation.embedded_queries(),
+            Self::GeohexGrid(aggregation) => aggregation.embedded_queries(),
+            _ => vec![],
+        }
+    }
+}
+
 /// Type alias for a collection of aggregations
 pub type Aggregations = std::collections::BTreeMap<AggregationName, Aggregation>;
@@ -2,8 +2,16 @@
 
 // Common parameters
 mod aggregation_name;
+mod percentiles_method;
 mod rate_mode;
+mod significance_heuristic;
+mod terms_exclude;
+mod terms_include;
 
 // Public re-exports
 pub use self::aggregation_name::*;
+pub use self::percentiles_method::*;
 pub use self::rate_mode::*;
+pub use self::significance_heuristic::*;
+pub use self::terms_exclude::*;
+pub use self::terms_include::*;
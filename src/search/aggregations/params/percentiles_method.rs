@@ -0,0 +1,137 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Algorithm used to approximate percentiles by the
+/// [`PercentilesAggregation`](crate::PercentilesAggregation) and
+/// [`PercentileRanksAggregation`](crate::PercentileRanksAggregation)
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html#search-aggregations-metrics-percentile-aggregation-approximation>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum PercentilesMethod {
+    /// Approximates percentiles with a TDigest, see [`TDigest`]
+    #[serde(rename = "tdigest")]
+    TDigest(TDigest),
+
+    /// Computes percentiles with an HDR histogram, see [`Hdr`]
+    #[serde(rename = "hdr")]
+    Hdr(Hdr),
+}
+
+impl From<TDigest> for PercentilesMethod {
+    fn from(value: TDigest) -> Self {
+        Self::TDigest(value)
+    }
+}
+
+impl From<Hdr> for PercentilesMethod {
+    fn from(value: Hdr) -> Self {
+        Self::Hdr(value)
+    }
+}
+
+/// Options of the TDigest algorithm, the default algorithm of the percentiles, percentile ranks,
+/// boxplot and median absolute deviation aggregations.
+///
+/// The TDigest algorithm uses a number of "nodes" to approximate percentiles —— the more nodes
+/// available, the higher the accuracy (and large memory footprint) proportional to the volume
+/// of data.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html#search-aggregations-metrics-percentile-aggregation-compression>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct TDigest {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    compression: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_hint: Option<TDigestExecutionHint>,
+}
+
+impl TDigest {
+    /// Creates an instance of [`TDigest`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the maximum number of nodes to 20 * `compression`. Increasing the compression
+    /// increases the accuracy of the percentiles at the cost of more memory and slower
+    /// aggregations.
+    ///
+    /// Defaults to `100`.
+    pub fn compression(mut self, compression: impl Into<Number>) -> Self {
+        self.compression = Some(compression.into());
+        self
+    }
+
+    /// Selects the TDigest implementation, trading accuracy for speed
+    pub fn execution_hint(mut self, execution_hint: TDigestExecutionHint) -> Self {
+        self.execution_hint = Some(execution_hint);
+        self
+    }
+}
+
+impl ShouldSkip for TDigest {
+    fn should_skip(&self) -> bool {
+        self.compression.should_skip() && self.execution_hint.should_skip()
+    }
+}
+
+/// TDigest implementation to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TDigestExecutionHint {
+    /// Optimized implementation trading a small accuracy loss for speed
+    Default,
+
+    /// Implementation with higher accuracy at the cost of performance
+    HighAccuracy,
+}
+
+/// Options of the [HDR histogram](https://github.com/HdrHistogram/HdrHistogram), which can be
+/// faster than TDigest at the cost of a larger memory footprint. Only supports positive values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html#_hdr_histogram>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Hdr {
+    number_of_significant_value_digits: u8,
+}
+
+impl Hdr {
+    /// Creates an instance of [`Hdr`]
+    ///
+    /// - `number_of_significant_value_digits` - Resolution of values for the histogram in number
+    ///   of significant digits, between `0` and `5`
+    pub fn new(number_of_significant_value_digits: u8) -> Self {
+        Self {
+            number_of_significant_value_digits: number_of_significant_value_digits.min(5),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TDigest::new(), json!({}));
+
+        assert_serialize(
+            PercentilesMethod::from(
+                TDigest::new()
+                    .compression(200)
+                    .execution_hint(TDigestExecutionHint::HighAccuracy),
+            ),
+            json!({
+                "tdigest": {
+                    "compression": 200,
+                    "execution_hint": "high_accuracy"
+                }
+            }),
+        );
+
+        assert_serialize(
+            PercentilesMethod::from(Hdr::new(3)),
+            json!({ "hdr": { "number_of_significant_value_digits": 3 } }),
+        );
+    }
+}
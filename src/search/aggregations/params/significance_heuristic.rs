@@ -0,0 +1,194 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Heuristic scoring how significant a term is in the foreground set of documents compared to
+/// the background set, used by the [`SignificantTermsAggregation`](crate::SignificantTermsAggregation)
+/// and the [`SignificantTextAggregation`](crate::SignificantTextAggregation)
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let heuristic =
+/// SignificanceHeuristic::chi_square()
+///     .include_negatives(true)
+///     .background_is_superset(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significantterms-aggregation.html#_parameters_5>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignificanceHeuristic {
+    /// JLH score, the default heuristic
+    Jlh {},
+
+    /// Google normalized distance as described in
+    /// ["The Google Similarity Distance"](https://arxiv.org/pdf/cs/0412098v3.pdf)
+    Gnd {
+        /// Whether the background set contains the foreground set, defaults to `true`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Chi square as described in section 13.5.2 of
+    /// ["Information Retrieval"](https://nlp.stanford.edu/IR-book/)
+    ChiSquare {
+        /// Whether to include terms that appear less often in the foreground set than in the
+        /// background set, defaults to `false`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        include_negatives: Option<bool>,
+
+        /// Whether the background set contains the foreground set, defaults to `true`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Mutual information as described in section 13.5.1 of
+    /// ["Information Retrieval"](https://nlp.stanford.edu/IR-book/)
+    MutualInformation {
+        /// Whether to include terms that appear less often in the foreground set than in the
+        /// background set, defaults to `false`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        include_negatives: Option<bool>,
+
+        /// Whether the background set contains the foreground set, defaults to `true`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Number of foreground documents with the term divided by the number of background
+    /// documents with the term
+    Percentage {},
+
+    /// Custom score computed by a script with access to the `_subset_freq`, `_superset_freq`,
+    /// `_subset_size` and `_superset_size` parameters
+    ScriptHeuristic {
+        /// Script computing the score
+        script: Script,
+    },
+}
+
+impl SignificanceHeuristic {
+    /// Creates a JLH heuristic
+    pub fn jlh() -> Self {
+        Self::Jlh {}
+    }
+
+    /// Creates a Google normalized distance heuristic
+    pub fn gnd() -> Self {
+        Self::Gnd {
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates a chi square heuristic
+    pub fn chi_square() -> Self {
+        Self::ChiSquare {
+            include_negatives: None,
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates a mutual information heuristic
+    pub fn mutual_information() -> Self {
+        Self::MutualInformation {
+            include_negatives: None,
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates a percentage heuristic
+    pub fn percentage() -> Self {
+        Self::Percentage {}
+    }
+
+    /// Creates a scripted heuristic
+    pub fn script(script: Script) -> Self {
+        Self::ScriptHeuristic { script }
+    }
+
+    /// Whether the background set contains the foreground set, which is the case unless a
+    /// `background_filter` selects a different set of documents. Applies to the
+    /// [`gnd`](Self::gnd), [`chi_square`](Self::chi_square) and
+    /// [`mutual_information`](Self::mutual_information) heuristics and is ignored by the others.
+    pub fn background_is_superset(mut self, value: bool) -> Self {
+        match &mut self {
+            Self::Gnd {
+                background_is_superset,
+            }
+            | Self::ChiSquare {
+                background_is_superset,
+                ..
+            }
+            | Self::MutualInformation {
+                background_is_superset,
+                ..
+            } => *background_is_superset = Some(value),
+            _ => {}
+        }
+        self
+    }
+
+    /// Whether to include terms that appear less often in the foreground set than in the
+    /// background set. Applies to the [`chi_square`](Self::chi_square) and
+    /// [`mutual_information`](Self::mutual_information) heuristics and is ignored by the others.
+    pub fn include_negatives(mut self, value: bool) -> Self {
+        match &mut self {
+            Self::ChiSquare {
+                include_negatives, ..
+            }
+            | Self::MutualInformation {
+                include_negatives, ..
+            } => *include_negatives = Some(value),
+            _ => {}
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(SignificanceHeuristic::jlh(), json!({ "jlh": {} }));
+
+        assert_serialize(
+            SignificanceHeuristic::gnd().background_is_superset(false),
+            json!({ "gnd": { "background_is_superset": false } }),
+        );
+
+        assert_serialize(
+            SignificanceHeuristic::chi_square()
+                .include_negatives(true)
+                .background_is_superset(false),
+            json!({
+                "chi_square": {
+                    "include_negatives": true,
+                    "background_is_superset": false
+                }
+            }),
+        );
+
+        assert_serialize(
+            SignificanceHeuristic::mutual_information().include_negatives(false),
+            json!({ "mutual_information": { "include_negatives": false } }),
+        );
+
+        assert_serialize(
+            SignificanceHeuristic::percentage().include_negatives(true),
+            json!({ "percentage": {} }),
+        );
+
+        assert_serialize(
+            SignificanceHeuristic::script(Script::source(
+                "params._subset_freq / (params._superset_freq - params._subset_freq + 1)",
+            )),
+            json!({
+                "script_heuristic": {
+                    "script": {
+                        "source": "params._subset_freq / (params._superset_freq - params._subset_freq + 1)"
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Filters out the values for which buckets would be created by a
+/// [`TermsAggregation`](crate::TermsAggregation), applied after
+/// [`TermsInclude`] when both are set
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#_filtering_values_4>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TermsExclude {
+    /// Values matching the regular expression are not aggregated
+    Regex(String),
+
+    /// The exact values are not aggregated
+    Values(Terms),
+}
+
+impl TermsExclude {
+    /// Creates an instance of [`TermsExclude::Regex`]
+    pub fn regex(regex: impl ToString) -> Self {
+        Self::Regex(regex.to_string())
+    }
+
+    /// Creates an instance of [`TermsExclude::Values`]
+    pub fn values(values: impl Into<Terms>) -> Self {
+        Self::Values(values.into())
+    }
+}
+
+impl From<&str> for TermsExclude {
+    fn from(value: &str) -> Self {
+        Self::regex(value)
+    }
+}
+
+impl From<String> for TermsExclude {
+    fn from(value: String) -> Self {
+        Self::Regex(value)
+    }
+}
+
+impl ShouldSkip for TermsExclude {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Regex(regex) => regex.should_skip(),
+            Self::Values(values) => values.should_skip(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TermsExclude::regex("water_.*"), json!("water_.*"));
+        assert_serialize(
+            TermsExclude::values(["rover", "jensen"]),
+            json!(["jensen", "rover"]),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(TermsExclude::regex("").should_skip());
+        assert!(TermsExclude::values(Vec::<String>::new()).should_skip());
+        assert!(!TermsExclude::regex("water_.*").should_skip());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Filters the values for which buckets will be created by a
+/// [`TermsAggregation`](crate::TermsAggregation)
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#_filtering_values_4>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TermsInclude {
+    /// Only values matching the regular expression are aggregated
+    Regex(String),
+
+    /// Only the exact values are aggregated
+    Values(Terms),
+
+    /// Only the values that fall into the given partition are aggregated, which allows
+    /// processing very high-cardinality fields in several requests
+    Partition {
+        /// Zero based partition to aggregate, must be less than `num_partitions`
+        partition: u32,
+
+        /// Number of partitions the unique values are grouped into
+        num_partitions: u32,
+    },
+}
+
+impl TermsInclude {
+    /// Creates an instance of [`TermsInclude::Regex`]
+    pub fn regex(regex: impl ToString) -> Self {
+        Self::Regex(regex.to_string())
+    }
+
+    /// Creates an instance of [`TermsInclude::Values`]
+    pub fn values(values: impl Into<Terms>) -> Self {
+        Self::Values(values.into())
+    }
+
+    /// Creates an instance of [`TermsInclude::Partition`]
+    ///
+    /// - `partition` - Zero based partition to aggregate
+    /// - `num_partitions` - Number of partitions the unique values are grouped into
+    pub fn partition(partition: u32, num_partitions: u32) -> Self {
+        Self::Partition {
+            partition,
+            num_partitions,
+        }
+    }
+}
+
+impl From<&str> for TermsInclude {
+    fn from(value: &str) -> Self {
+        Self::regex(value)
+    }
+}
+
+impl From<String> for TermsInclude {
+    fn from(value: String) -> Self {
+        Self::Regex(value)
+    }
+}
+
+impl ShouldSkip for TermsInclude {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Regex(regex) => regex.should_skip(),
+            Self::Values(values) => values.should_skip(),
+            Self::Partition {
+                partition,
+                num_partitions,
+            } => partition >= num_partitions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TermsInclude::regex(".*sport.*"), json!(".*sport.*"));
+        assert_serialize(
+            TermsInclude::values(["mazda", "honda"]),
+            json!(["honda", "mazda"]),
+        );
+        assert_serialize(
+            TermsInclude::partition(0, 20),
+            json!({ "partition": 0, "num_partitions": 20 }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(TermsInclude::partition(20, 20).should_skip());
+        assert!(TermsInclude::regex("").should_skip());
+        assert!(!TermsInclude::partition(19, 20).should_skip());
+    }
+}
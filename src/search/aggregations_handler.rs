@@ -0,0 +1,299 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Read access to the `aggregations` section of a search response, obtained through
+/// [`SearchResponse::aggs`](crate::SearchResponse::aggs).
+///
+/// Lookups of missing aggregations return [`None`] or nothing, regardless of whether the
+/// response has an `aggregations` section at all.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # use serde_json::json;
+/// # let response: SearchResponse = serde_json::from_value(json!({
+/// #     "took": 1,
+/// #     "timed_out": false,
+/// #     "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+/// #     "hits": { "hits": [] },
+/// #     "aggregations": {
+/// #         "avg_price": { "value": 12.5 },
+/// #         "genres": { "buckets": [{ "key": "rock", "doc_count": 3 }] }
+/// #     }
+/// # })).unwrap();
+/// let aggs = response.aggs();
+///
+/// assert_eq!(aggs.value("avg_price"), Some(12.5));
+/// assert_eq!(aggs.buckets("genres").len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AggregationsHandler<'a> {
+    aggregations: Option<&'a Value>,
+}
+
+impl<'a> AggregationsHandler<'a> {
+    /// Creates an instance of [`AggregationsHandler`] over an `aggregations` object, or over the
+    /// sub-aggregations of a bucket
+    pub fn new(aggregations: Option<&'a Value>) -> Self {
+        Self { aggregations }
+    }
+
+    /// Returns `true` when there are no aggregation results
+    pub fn is_empty(&self) -> bool {
+        self.names().next().is_none()
+    }
+
+    /// Names of the returned aggregations. Bucket properties such as `key` and `doc_count` are
+    /// left out when reading the sub-aggregations of a bucket.
+    pub fn names(&self) -> impl Iterator<Item = &'a str> {
+        self.aggregations
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter(|(_, aggregation)| aggregation.is_object())
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Raw result of the aggregation `name`
+    pub fn get(&self, name: &str) -> Option<&'a Value> {
+        self.aggregations
+            .and_then(|aggregations| aggregations.get(name))
+    }
+
+    /// Parses the result of the aggregation `name` into a typed response, such as
+    /// [`DateHistogramResponse`](crate::DateHistogramResponse)
+    pub fn parse<T>(&self, name: &str) -> Option<Result<T, serde_json::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.get(name).map(T::deserialize)
+    }
+
+    /// Parses every aggregation into `T`, recording the aggregations that cannot be parsed
+    /// instead of failing as a whole. Useful for clusters running plugin-provided aggregations
+    /// whose results do not match any known response type.
+    ///
+    /// Sub-aggregations of a bucket can be parsed the same way through
+    /// [`bucket`](Self::bucket).
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// #[derive(Deserialize)]
+    /// struct Metric {
+    ///     value: f64,
+    /// }
+    ///
+    /// # let aggregations = json!({
+    /// #     "avg_price": { "value": 12.5 },
+    /// #     "plugin_agg": { "custom": [1, 2, 3] }
+    /// # });
+    /// # let aggs = AggregationsHandler::new(Some(&aggregations));
+    /// let parsed = aggs.parse_lenient::<Metric>();
+    ///
+    /// assert_eq!(parsed.parsed["avg_price"].value, 12.5);
+    /// assert_eq!(parsed.failures[0].name, "plugin_agg");
+    /// ```
+    pub fn parse_lenient<T>(&self) -> LenientAggregations<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut aggregations = LenientAggregations {
+            parsed: BTreeMap::new(),
+            failures: Vec::new(),
+        };
+
+        for name in self.names() {
+            let value = match self.get(name) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match T::deserialize(value) {
+                Ok(parsed) => {
+                    let _ = aggregations.parsed.insert(name.to_string(), parsed);
+                }
+                Err(error) => aggregations.failures.push(AggregationFailure {
+                    name: name.to_string(),
+                    value: value.clone(),
+                    error,
+                }),
+            }
+        }
+
+        aggregations
+    }
+
+    /// The `value` of a single value metrics aggregation such as `avg`, `sum` or `cardinality`.
+    /// Returns [`None`] when there is no such aggregation or the value is `null`, e.g. the
+    /// average of no documents.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.get(name)
+            .and_then(|aggregation| aggregation.get("value"))
+            .and_then(Value::as_f64)
+    }
+
+    /// Buckets of a multi bucket aggregation. Buckets of keyed responses are returned in the
+    /// order of their keys.
+    pub fn buckets(&self, name: &str) -> Vec<&'a Value> {
+        match self
+            .get(name)
+            .and_then(|aggregation| aggregation.get("buckets"))
+        {
+            Some(Value::Array(buckets)) => buckets.iter().collect(),
+            Some(Value::Object(buckets)) => buckets.values().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Sub-aggregations of a single bucket aggregation such as `filter`, `nested` or
+    /// `global`
+    pub fn bucket(&self, name: &str) -> AggregationsHandler<'a> {
+        Self::new(self.get(name))
+    }
+
+    /// Number of documents in a single bucket aggregation such as `filter`, `nested` or
+    /// `global`
+    pub fn doc_count(&self, name: &str) -> Option<u64> {
+        self.get(name)
+            .and_then(|aggregation| aggregation.get("doc_count"))
+            .and_then(Value::as_u64)
+    }
+}
+
+/// Aggregation results parsed with [`AggregationsHandler::parse_lenient`]
+#[derive(Debug)]
+pub struct LenientAggregations<T> {
+    /// Successfully parsed aggregations, by name
+    pub parsed: BTreeMap<String, T>,
+
+    /// Aggregations that could not be parsed
+    pub failures: Vec<AggregationFailure>,
+}
+
+/// An aggregation result that could not be parsed
+#[derive(Debug)]
+pub struct AggregationFailure {
+    /// Name of the aggregation
+    pub name: String,
+
+    /// Raw result of the aggregation
+    pub value: Value,
+
+    /// Why the result could not be parsed
+    pub error: serde_json::Error,
+}
+
+impl<T> LenientAggregations<T> {
+    /// Returns `true` when every aggregation was parsed
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_aggregations() {
+        let aggregations = json!({
+            "avg_price": { "value": 12.5 },
+            "avg_missing": { "value": null },
+            "genres": {
+                "buckets": [
+                    { "key": "rock", "doc_count": 3 },
+                    { "key": "jazz", "doc_count": 1 }
+                ]
+            },
+            "ranges": {
+                "buckets": {
+                    "cheap": { "doc_count": 2 },
+                    "expensive": { "doc_count": 1 }
+                }
+            },
+            "in_stock": {
+                "doc_count": 4,
+                "avg_price": { "value": 10.0 }
+            }
+        });
+        let aggs = AggregationsHandler::new(Some(&aggregations));
+
+        assert!(!aggs.is_empty());
+        assert_eq!(
+            aggs.names().collect::<Vec<_>>(),
+            vec!["avg_missing", "avg_price", "genres", "in_stock", "ranges"]
+        );
+        assert_eq!(aggs.value("avg_price"), Some(12.5));
+        assert_eq!(aggs.value("avg_missing"), None);
+        assert_eq!(aggs.value("unknown"), None);
+        assert_eq!(aggs.buckets("genres").len(), 2);
+        assert_eq!(aggs.buckets("ranges")[0]["doc_count"], 2);
+        assert!(aggs.buckets("avg_price").is_empty());
+        assert_eq!(aggs.doc_count("in_stock"), Some(4));
+        assert_eq!(aggs.bucket("in_stock").value("avg_price"), Some(10.0));
+        assert_eq!(
+            aggs.bucket("in_stock").names().collect::<Vec<_>>(),
+            vec!["avg_price"]
+        );
+        assert!(aggs.bucket("unknown").is_empty());
+    }
+
+    #[test]
+    fn handles_missing_aggregations() {
+        let aggs = AggregationsHandler::new(None);
+
+        assert!(aggs.is_empty());
+        assert_eq!(aggs.names().count(), 0);
+        assert_eq!(aggs.get("any"), None);
+        assert!(aggs.parse::<Value>("any").is_none());
+        assert!(aggs.buckets("any").is_empty());
+    }
+
+    #[test]
+    fn parses_leniently() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Metric {
+            value: f64,
+        }
+
+        let aggregations = json!({
+            "avg_price": { "value": 12.5 },
+            "max_price": { "value": 20.0 },
+            "plugin_agg": { "value": "unexpected" },
+            "in_stock": {
+                "doc_count": 4,
+                "avg_price": { "value": 10.0 },
+                "plugin_agg": { "custom": [1, 2, 3] }
+            }
+        });
+        let aggs = AggregationsHandler::new(Some(&aggregations));
+
+        let parsed = aggs.parse_lenient::<Metric>();
+
+        assert!(!parsed.is_complete());
+        assert_eq!(
+            parsed.parsed.keys().collect::<Vec<_>>(),
+            vec!["avg_price", "max_price"]
+        );
+        assert_eq!(
+            parsed
+                .failures
+                .iter()
+                .map(|failure| failure.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["in_stock", "plugin_agg"]
+        );
+        assert_eq!(parsed.failures[1].value, json!({ "value": "unexpected" }));
+
+        let parsed = aggs.bucket("in_stock").parse_lenient::<Metric>();
+
+        assert_eq!(parsed.parsed["avg_price"], Metric { value: 10.0 });
+        assert_eq!(parsed.failures.len(), 1);
+        assert!(AggregationsHandler::new(None)
+            .parse_lenient::<Metric>()
+            .is_complete());
+    }
+}
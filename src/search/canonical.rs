@@ -0,0 +1,198 @@
+use crate::search::*;
+use crate::util::*;
+use serde_json::Value;
+
+impl Search {
+    /// Serializes the request with the keys of every object sorted, so that requests built
+    /// from the same clauses in a different order of builder calls, or with map parameters
+    /// filled in a different order, serialize identically. Clause order within arrays, such as
+    /// `bool` clauses or sorts, is significant to Elasticsearch and kept as is.
+    ///
+    /// Regular serialization is deterministic as well, with fields in a fixed order per type,
+    /// but follows the order of builder calls for maps such as `aggs` or script `params`
+    /// when the `preserve_order` feature of `serde_json` is enabled.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let a = Search::new()
+    ///     .size(10)
+    ///     .aggregate("tags", Aggregation::terms("tags"))
+    ///     .aggregate("authors", Aggregation::terms("author"));
+    /// let b = Search::new()
+    ///     .aggregate("authors", Aggregation::terms("author"))
+    ///     .aggregate("tags", Aggregation::terms("tags"))
+    ///     .size(10);
+    ///
+    /// assert_eq!(a.to_canonical_string()?, b.to_canonical_string()?);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn to_canonical_json(&self) -> Result<Value, serde_json::Error> {
+        canonical_json(self)
+    }
+
+    /// Serializes the request into a compact canonical JSON string, suitable as a cache or
+    /// deduplication key, see [`to_canonical_json`](Self::to_canonical_json)
+    pub fn to_canonical_string(&self) -> Result<String, serde_json::Error> {
+        self.to_canonical_json().map(|value| value.to_string())
+    }
+
+    /// Stable 64-bit hash of the [canonical](Self::to_canonical_string) request body, e.g. to
+    /// key application-level result caches on the search itself.
+    ///
+    /// The hash is computed with FNV-1a, so the value does not change between processes,
+    /// platforms or Rust versions. It is not a cryptographic hash.
+    ///
+    /// The hash covers the serialized request body, which depends on the enabled features of
+    /// this crate: with the `compact` feature, explicitly set default values are left out and
+    /// the same search hashes differently. Persisted fingerprints are therefore only comparable
+    /// between builds with the same feature set and a version of this crate serializing the
+    /// request the same way.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let a = Search::new().query(Query::term("user", "kimchy")).size(10);
+    /// let b = Search::new().size(10).query(Query::term("user", "kimchy"));
+    ///
+    /// assert_eq!(a.fingerprint()?, b.fingerprint()?);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn fingerprint(&self) -> Result<u64, serde_json::Error> {
+        self.to_canonical_json().map(|value| fingerprint(&value))
+    }
+
+    /// Same as [`fingerprint`](Self::fingerprint), but ignores the pagination parameters
+    /// `from`, `size` and `search_after`, so that every page of the same search shares the
+    /// fingerprint.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let search = Search::new().query(Query::term("user", "kimchy"));
+    ///
+    /// assert_eq!(
+    ///     search.clone().from(0).size(10).fingerprint_without_pagination()?,
+    ///     search.from(10).size(10).fingerprint_without_pagination()?,
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn fingerprint_without_pagination(&self) -> Result<u64, serde_json::Error> {
+        let mut value = self.to_canonical_json()?;
+
+        if let Some(body) = value.as_object_mut() {
+            for parameter in PAGINATION_PARAMETERS {
+                let _ = body.remove(*parameter);
+            }
+        }
+
+        Ok(fingerprint(&value))
+    }
+}
+
+impl Query {
+    /// Serializes the query with the keys of every object sorted, see
+    /// [`Search::to_canonical_json`]
+    pub fn to_canonical_json(&self) -> Result<Value, serde_json::Error> {
+        canonical_json(self)
+    }
+
+    /// Serializes the query into a compact canonical JSON string, see
+    /// [`Search::to_canonical_string`]
+    pub fn to_canonical_string(&self) -> Result<String, serde_json::Error> {
+        self.to_canonical_json().map(|value| value.to_string())
+    }
+}
+
+/// Top level request parameters selecting a page of the results
+const PAGINATION_PARAMETERS: &[&str] = &["from", "size", "search_after"];
+
+/// 64-bit FNV-1a hash of the compact JSON representation
+fn fingerprint(value: &Value) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    value.to_string().bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+fn canonical_json<T>(value: &T) -> Result<Value, serde_json::Error>
+where
+    T: serde::Serialize,
+{
+    serde_json::to_value(value).map(sort_keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_serialization() {
+        let search = Search::new()
+            .size(10)
+            .query(
+                Query::rule(["curated"], Query::term("user", "kimchy"))
+                    .match_criteria("user_query", "kimchy")
+                    .match_criteria("country", "us"),
+            )
+            .sort(Sort::new("@timestamp"));
+
+        assert_eq!(
+            search.to_canonical_string().unwrap(),
+            concat!(
+                r#"{"query":{"rule":{"match_criteria":{"country":"us","user_query":"kimchy"},"#,
+                r#""organic":{"term":{"user":{"value":"kimchy"}}},"ruleset_ids":["curated"]}},"#,
+                r#""size":10,"sort":[{"@timestamp":{}}]}"#
+            )
+        );
+
+        assert_eq!(
+            Query::from(
+                Query::bool()
+                    .filter(Query::term("a", 1))
+                    .must(Query::term("b", 2))
+            )
+            .to_canonical_string()
+            .unwrap(),
+            r#"{"bool":{"filter":[{"term":{"a":{"value":1}}}],"must":[{"term":{"b":{"value":2}}}]}}"#
+        );
+    }
+
+    #[test]
+    fn fingerprint_is_stable() {
+        assert_eq!(
+            fingerprint(&Value::String(String::new())),
+            0x07cc_7607_b494_9e25
+        );
+        assert_eq!(Search::new().fingerprint().unwrap(), 0x08f4_4b07_b590_1a25);
+    }
+
+    #[test]
+    fn fingerprint_depends_on_features() {
+        let search = Search::new().query(Query::term("user", "kimchy"));
+        let boosted = Search::new().query(Query::term("user", "kimchy").boost(1));
+
+        assert_eq!(
+            search.fingerprint().unwrap() == boosted.fingerprint().unwrap(),
+            cfg!(feature = "compact")
+        );
+    }
+
+    #[test]
+    fn fingerprint_without_pagination() {
+        let search = Search::new().query(Query::term("user", "kimchy"));
+        let page = search.clone().from(20).size(10).search_after(["a", "b"]);
+
+        assert_ne!(search.fingerprint().unwrap(), page.fingerprint().unwrap());
+        assert_eq!(
+            search.fingerprint_without_pagination().unwrap(),
+            page.fingerprint_without_pagination().unwrap()
+        );
+        assert_ne!(
+            search.fingerprint_without_pagination().unwrap(),
+            Search::new()
+                .query(Query::term("user", "elastic"))
+                .fingerprint_without_pagination()
+                .unwrap()
+        );
+    }
+}
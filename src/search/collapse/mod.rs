@@ -0,0 +1,173 @@
+//! Collapse search results based on field values
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/collapse-search-results.html>
+
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// You can use the `collapse` parameter to collapse search results based on field values. The
+/// collapsing is done by selecting only the top sorted document per collapse key.
+///
+/// The field used for collapsing must be a single valued
+/// [`keyword`](https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html) or
+/// [`numeric`](https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html) field
+/// with [`doc_values`](https://www.elastic.co/guide/en/elasticsearch/reference/current/doc-values.html)
+/// activated.
+///
+/// To collapse search results:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let collapse =
+/// Collapse::new("user.id")
+///     .inner_hits(InnerHits::new().name("most_recent").size(5))
+///     .max_concurrent_group_searches(4);
+/// ```
+///
+/// Collapsed groups can be collapsed again through their inner hits, e.g. to group products by
+/// brand and then variants within each brand:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let collapse =
+/// Collapse::new("brand").inner_hits(
+///     InnerHits::new()
+///         .name("by_variant")
+///         .collapse("variant")
+///         .size(3),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/collapse-search-results.html#second-level-of-collapsing>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Collapse {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Vec<InnerHits>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_concurrent_group_searches: Option<u64>,
+}
+
+impl Collapse {
+    /// Creates an instance of [`Collapse`]
+    ///
+    /// - `field` - Field to collapse the result set on
+    pub fn new(field: impl ToString) -> Self {
+        Self {
+            field: field.to_string(),
+            inner_hits: vec![],
+            max_concurrent_group_searches: None,
+        }
+    }
+
+    /// Expands each collapsed top hit with the inner hits. Can be called multiple times to
+    /// request several representations of each collapsed group.
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits.push(inner_hits);
+        self
+    }
+
+    /// The number of concurrent requests allowed to retrieve the inner hits per group.
+    ///
+    /// Defaults to the number of data nodes and the default search thread pool size.
+    pub fn max_concurrent_group_searches(
+        mut self,
+        max_concurrent_group_searches: impl TryInto<u64>,
+    ) -> Self {
+        if let Ok(max_concurrent_group_searches) = max_concurrent_group_searches.try_into() {
+            self.max_concurrent_group_searches = Some(max_concurrent_group_searches);
+        }
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner_hits
+            .iter()
+            .flat_map(InnerHits::embedded_queries)
+            .collect()
+    }
+}
+
+impl ShouldSkip for Collapse {
+    fn should_skip(&self) -> bool {
+        self.field.should_skip()
+    }
+}
+
+impl<T> From<T> for Collapse
+where
+    T: ToString,
+{
+    fn from(field: T) -> Self {
+        Self::new(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Collapse::new("user.id"), json!({ "field": "user.id" }));
+
+        assert_serialize(
+            Collapse::new("user.id")
+                .inner_hits(
+                    InnerHits::new()
+                        .name("largest_responses")
+                        .size(3)
+                        .sort(Sort::new("http.response.bytes").order(SortOrder::Desc)),
+                )
+                .inner_hits(
+                    InnerHits::new()
+                        .name("most_recent")
+                        .size(3)
+                        .sort(Sort::new("@timestamp").order(SortOrder::Desc)),
+                )
+                .max_concurrent_group_searches(4),
+            json!({
+                "field": "user.id",
+                "inner_hits": [
+                    {
+                        "name": "largest_responses",
+                        "size": 3,
+                        "sort": [
+                            { "http.response.bytes": { "order": "desc" } }
+                        ]
+                    },
+                    {
+                        "name": "most_recent",
+                        "size": 3,
+                        "sort": [
+                            { "@timestamp": { "order": "desc" } }
+                        ]
+                    }
+                ],
+                "max_concurrent_group_searches": 4
+            }),
+        );
+
+        assert_serialize(
+            Search::new().collapse(
+                Collapse::new("geo.country_name").inner_hits(
+                    InnerHits::new()
+                        .name("by_location")
+                        .collapse("user.id")
+                        .size(3),
+                ),
+            ),
+            json!({
+                "collapse": {
+                    "field": "geo.country_name",
+                    "inner_hits": [
+                        {
+                            "name": "by_location",
+                            "collapse": { "field": "user.id" },
+                            "size": 3
+                        }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::search::*;
+
+/// Count API response
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CountResponse {
+    /// Number of documents matching the query
+    pub count: u64,
+
+    /// Number of shards touched with their states
+    #[serde(rename = "_shards")]
+    pub shards: Shards,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: CountResponse = serde_json::from_value(json!({
+            "count": 42,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 }
+        }))
+        .unwrap();
+
+        assert_eq!(response.count, 42);
+        assert_eq!(response.shards.successful, 1);
+    }
+}
@@ -0,0 +1,164 @@
+//! Deep pagination through all hits of a search using a point in time and `search_after`.
+use crate::search::*;
+use serde_json::Value;
+
+/// Owns the state needed to page through all hits of a [`Search`] against a [`PointInTime`]
+/// with `search_after`.
+///
+/// Each request is produced by [`Cursor::next_request`] and the corresponding response is fed
+/// back with [`Cursor::advance`], which keeps track of the latest point in time ID and the sort
+/// values of the last hit. The cursor is exhausted once a page comes back empty.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # fn execute(search: &Search) -> SearchResponse {
+/// #     serde_json::from_value(serde_json::json!({
+/// #         "took": 1, "timed_out": false, "pit_id": "pit-id",
+/// #         "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+/// #         "hits": { "hits": [] }
+/// #     })).unwrap()
+/// # }
+/// let search = Search::new()
+///     .size(1000)
+///     .sort(Sort::new("@timestamp").order(SortOrder::Asc));
+///
+/// let mut cursor = Cursor::new(search, "pit-id").keep_alive(Time::Minutes(1));
+///
+/// while let Some(request) = cursor.next_request() {
+///     let response = execute(&request);
+///     // process response.hits.hits
+///     cursor.advance(&response);
+/// }
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#search-after>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    search: Search,
+    pit_id: String,
+    keep_alive: Option<Time>,
+    search_after: Vec<Value>,
+    exhausted: bool,
+}
+
+impl Cursor {
+    /// Creates an instance of [`Cursor`]
+    ///
+    /// - `search` - Base search request, which should define a `sort`
+    /// - `pit_id` - ID returned by the open point in time API
+    pub fn new(search: Search, pit_id: impl ToString) -> Self {
+        Self {
+            search,
+            pit_id: pit_id.to_string(),
+            keep_alive: None,
+            search_after: vec![],
+            exhausted: false,
+        }
+    }
+
+    /// Extends the time to live of the point in time with every request
+    pub fn keep_alive(mut self, keep_alive: Time) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+
+    /// Builds the request body for the next page, or [`None`] once all hits have been retrieved
+    pub fn next_request(&self) -> Option<Search> {
+        if self.exhausted {
+            return None;
+        }
+
+        let mut pit = PointInTime::new(&self.pit_id);
+
+        if let Some(keep_alive) = self.keep_alive {
+            pit = pit.keep_alive(keep_alive);
+        }
+
+        Some(
+            self.search
+                .clone()
+                .pit(pit)
+                .search_after(self.search_after.clone()),
+        )
+    }
+
+    /// Ingests the response of the last request, updating the point in time ID and the sort
+    /// values to search after
+    pub fn advance<H, IH>(&mut self, response: &SearchResponse<H, IH>) {
+        if let Some(pit_id) = &response.pit_id {
+            self.pit_id = pit_id.clone();
+        }
+
+        match response.hits.hits.last() {
+            Some(hit) if !hit.sort.is_empty() => self.search_after = hit.sort.clone(),
+            _ => self.exhausted = true,
+        }
+    }
+
+    /// Latest point in time ID, which should be closed once the cursor is no longer used
+    pub fn pit_id(&self) -> &str {
+        &self.pit_id
+    }
+
+    /// Whether all hits have been retrieved
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    fn response(pit_id: &str, sort: Option<Value>) -> SearchResponse {
+        let hits = match sort {
+            Some(sort) => json!([{ "_id": "1", "sort": sort }]),
+            None => json!([]),
+        };
+
+        serde_json::from_value(json!({
+            "took": 1,
+            "timed_out": false,
+            "pit_id": pit_id,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+            "hits": { "hits": hits }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn iterates_pages() {
+        let search = Search::new()
+            .size(2)
+            .sort(Sort::new("@timestamp").order(SortOrder::Asc));
+
+        let mut cursor = Cursor::new(search, "pit-1").keep_alive(Time::Minutes(1));
+
+        assert_serialize(
+            cursor.next_request().unwrap(),
+            json!({
+                "size": 2,
+                "sort": [{ "@timestamp": { "order": "asc" } }],
+                "pit": { "id": "pit-1", "keep_alive": "1m" }
+            }),
+        );
+
+        cursor.advance(&response("pit-2", Some(json!([1_600_000_000_000u64, 42]))));
+
+        assert_serialize(
+            cursor.next_request().unwrap(),
+            json!({
+                "size": 2,
+                "sort": [{ "@timestamp": { "order": "asc" } }],
+                "search_after": [1_600_000_000_000u64, 42],
+                "pit": { "id": "pit-2", "keep_alive": "1m" }
+            }),
+        );
+
+        cursor.advance(&response("pit-3", None));
+
+        assert!(cursor.is_exhausted());
+        assert!(cursor.next_request().is_none());
+        assert_eq!(cursor.pit_id(), "pit-3");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::util::*;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::de::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Result of a `date_histogram` aggregation
+///
+/// To read the buckets of a `date_histogram` aggregation named `sales_over_time`:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let response: SearchResponse = serde_json::from_value(serde_json::json!({
+/// #     "took": 1,
+/// #     "timed_out": false,
+/// #     "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+/// #     "hits": { "hits": [] },
+/// #     "aggregations": {
+/// #         "sales_over_time": {
+/// #             "buckets": [{ "key_as_string": "2015-01-01", "key": 1420070400000u64, "doc_count": 3 }]
+/// #         }
+/// #     }
+/// # })).unwrap();
+/// let histogram = response
+///     .aggregation::<DateHistogramResponse>("sales_over_time")
+///     .unwrap()
+///     .unwrap();
+///
+/// for bucket in histogram.buckets {
+///     println!("{}: {}", bucket.date().unwrap(), bucket.doc_count);
+/// }
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-datehistogram-aggregation.html#datehistogram-aggregation-response>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DateHistogramResponse {
+    /// Buckets ordered by key, also when the aggregation was requested as `keyed`
+    #[serde(deserialize_with = "deserialize_buckets")]
+    pub buckets: Vec<DateHistogramBucket>,
+}
+
+/// A single `date_histogram` bucket
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DateHistogramBucket {
+    /// Start of the bucket as milliseconds since the epoch
+    pub key: i64,
+
+    /// Start of the bucket formatted with the `format` of the request, or the date format of the
+    /// field when the request does not set one
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub key_as_string: Option<String>,
+
+    /// Number of documents in the bucket
+    pub doc_count: u64,
+
+    /// Results of the sub-aggregations, by aggregation name
+    #[serde(flatten)]
+    pub aggregations: BTreeMap<String, Value>,
+}
+
+impl DateHistogramBucket {
+    /// Start of the bucket as a UTC date, `None` when out of range for [`DateTime`]
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        Utc.timestamp_millis_opt(self.key).single()
+    }
+}
+
+fn deserialize_buckets<'de, D>(deserializer: D) -> Result<Vec<DateHistogramBucket>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Buckets {
+        List(Vec<DateHistogramBucket>),
+        Keyed(BTreeMap<String, DateHistogramBucket>),
+    }
+
+    Ok(match Buckets::deserialize(deserializer)? {
+        Buckets::List(buckets) => buckets,
+        Buckets::Keyed(buckets) => {
+            let mut buckets: Vec<_> = buckets.into_values().collect();
+            buckets.sort_by_key(|bucket| bucket.key);
+            buckets
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: DateHistogramResponse = serde_json::from_value(json!({
+            "buckets": [
+                {
+                    "key_as_string": "2015/01/01 00:00:00",
+                    "key": 1420070400000u64,
+                    "doc_count": 3,
+                    "total_sales": { "value": 550.0 }
+                },
+                {
+                    "key": 1422748800000u64,
+                    "doc_count": 2
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(response.buckets.len(), 2);
+        assert_eq!(
+            response.buckets[0].key_as_string.as_deref(),
+            Some("2015/01/01 00:00:00")
+        );
+        assert_eq!(
+            response.buckets[0].date(),
+            Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).single()
+        );
+        assert_eq!(
+            response.buckets[0].aggregations["total_sales"],
+            json!({ "value": 550.0 })
+        );
+        assert_eq!(
+            response.buckets[1].date(),
+            Utc.with_ymd_and_hms(2015, 2, 1, 0, 0, 0).single()
+        );
+    }
+
+    #[test]
+    fn deserializes_keyed_buckets() {
+        let response: DateHistogramResponse = serde_json::from_value(json!({
+            "buckets": {
+                "2015-02-01": { "key_as_string": "2015-02-01", "key": 1422748800000u64, "doc_count": 2 },
+                "2015-01-01": { "key_as_string": "2015-01-01", "key": 1420070400000u64, "doc_count": 3 }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response
+                .buckets
+                .iter()
+                .map(|bucket| bucket.doc_count)
+                .collect::<Vec<_>>(),
+            [3, 2]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::util::*;
+
+/// Explain API response
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainResponse {
+    /// Index of the explained document
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// ID of the explained document
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the document matches the query
+    pub matched: bool,
+
+    /// How the score of the document was computed
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub explanation: Option<Explanation>,
+}
+
+/// Score computation of a document, as returned by the explain API or in the `_explanation` of
+/// each hit when searching with `explain` enabled.
+///
+/// Each node holds the value it contributes and the explanations of the values it was computed
+/// from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Explanation {
+    /// Computed value
+    pub value: f64,
+
+    /// Human readable description of the computation
+    pub description: String,
+
+    /// Explanations of the values this one is computed from
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub details: Vec<Explanation>,
+}
+
+/// Single node of a flattened [`Explanation`] tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExplanationEntry<'a> {
+    /// Distance from the root explanation, the root itself has depth `0`
+    pub depth: usize,
+
+    /// The explanation node
+    pub explanation: &'a Explanation,
+}
+
+impl Explanation {
+    /// Flattens the tree in depth-first order, parents preceding their details
+    pub fn flatten(&self) -> Vec<ExplanationEntry<'_>> {
+        let mut entries = Vec::new();
+        let mut stack = vec![(0, self)];
+
+        while let Some((depth, explanation)) = stack.pop() {
+            entries.push(ExplanationEntry { depth, explanation });
+            stack.extend(
+                explanation
+                    .details
+                    .iter()
+                    .rev()
+                    .map(|detail| (depth + 1, detail)),
+            );
+        }
+
+        entries
+    }
+
+    /// Explanations without further details, i.e. the individual scoring contributions such as
+    /// the weight of each matched term
+    pub fn leaves(&self) -> Vec<&Explanation> {
+        self.flatten()
+            .into_iter()
+            .map(|entry| entry.explanation)
+            .filter(|explanation| explanation.details.is_empty())
+            .collect()
+    }
+
+    /// Explanations whose description contains `pattern`, e.g. `weight(title:` to find the
+    /// contribution of terms matched in the `title` field
+    pub fn find(&self, pattern: &str) -> Vec<&Explanation> {
+        self.flatten()
+            .into_iter()
+            .map(|entry| entry.explanation)
+            .filter(|explanation| explanation.description.contains(pattern))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanation() -> Explanation {
+        serde_json::from_value(json!({
+            "value": 1.6943598,
+            "description": "weight(message:elasticsearch in 0) [PerFieldSimilarity], result of:",
+            "details": [
+                {
+                    "value": 1.6943598,
+                    "description": "score(freq=1.0), computed as boost * idf * tf from:",
+                    "details": [
+                        { "value": 2.2, "description": "boost", "details": [] },
+                        {
+                            "value": 1.3862944,
+                            "description": "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5)) from:",
+                            "details": [
+                                { "value": 1, "description": "n, number of documents containing term", "details": [] },
+                                { "value": 5, "description": "N, total number of documents with field", "details": [] }
+                            ]
+                        },
+                        { "value": 0.5555556, "description": "tf", "details": [] }
+                    ]
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: ExplainResponse = serde_json::from_value(json!({
+            "_index": "my-index-000001",
+            "_id": "0",
+            "matched": true,
+            "explanation": {
+                "value": 1.6943598,
+                "description": "weight(message:elasticsearch in 0) [PerFieldSimilarity], result of:",
+                "details": []
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.index, "my-index-000001");
+        assert!(response.matched);
+        assert_eq!(response.explanation.unwrap().value, 1.6943598);
+
+        let response: ExplainResponse = serde_json::from_value(json!({
+            "_index": "my-index-000001",
+            "_id": "1",
+            "matched": false
+        }))
+        .unwrap();
+
+        assert!(response.explanation.is_none());
+    }
+
+    #[test]
+    fn flattens() {
+        let explanation = explanation();
+
+        assert_eq!(
+            explanation
+                .flatten()
+                .iter()
+                .map(|entry| (entry.depth, entry.explanation.value))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, 1.6943598),
+                (1, 1.6943598),
+                (2, 2.2),
+                (2, 1.3862944),
+                (3, 1.0),
+                (3, 5.0),
+                (2, 0.5555556),
+            ]
+        );
+
+        assert_eq!(
+            explanation
+                .leaves()
+                .iter()
+                .map(|explanation| explanation.description.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "boost",
+                "n, number of documents containing term",
+                "N, total number of documents with field",
+                "tf",
+            ]
+        );
+
+        assert_eq!(explanation.find("idf, computed").len(), 1);
+        assert_eq!(explanation.find("weight(message:").len(), 1);
+    }
+}
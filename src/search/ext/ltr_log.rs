@@ -0,0 +1,121 @@
+use crate::util::*;
+
+/// Feature logging extension of the
+/// [Learning to Rank plugin](https://elasticsearch-learning-to-rank.readthedocs.io/en/latest/logging-features.html).
+/// Logs the feature scores of a named `sltr` query for each returned hit, which is used to build
+/// training sets.
+///
+/// To log features of a named query:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new().ltr_log(LtrLog::new().log_spec(
+///     LtrLogSpec::named_query("log_entry1", "logged_featureset").missing_as_zero(true),
+/// ));
+/// ```
+/// <https://elasticsearch-learning-to-rank.readthedocs.io/en/latest/logging-features.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct LtrLog {
+    log_specs: Vec<LtrLogSpec>,
+}
+
+/// A single feature log specification of the [`LtrLog`] extension
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LtrLogSpec {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    named_query: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rescore_index: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_as_zero: Option<bool>,
+}
+
+impl LtrLog {
+    /// Creates an instance of [`LtrLog`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a feature log specification
+    pub fn log_spec(mut self, log_spec: LtrLogSpec) -> Self {
+        self.log_specs.push(log_spec);
+        self
+    }
+}
+
+impl LtrLogSpec {
+    /// Logs the features of an `sltr` query referenced by its `_name`
+    ///
+    /// - `name` - Name of the log entry in the response
+    /// - `named_query` - Name of the `sltr` query to log
+    pub fn named_query(name: impl ToString, named_query: impl ToString) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            named_query: Some(named_query.to_string()),
+            rescore_index: None,
+            missing_as_zero: None,
+        }
+    }
+
+    /// Logs the features of an `sltr` query used as a rescore query
+    ///
+    /// - `name` - Name of the log entry in the response
+    /// - `rescore_index` - Position of the rescorer in the `rescore` array
+    pub fn rescore_index(name: impl ToString, rescore_index: u32) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            named_query: None,
+            rescore_index: Some(rescore_index),
+            missing_as_zero: None,
+        }
+    }
+
+    /// Logs `0` instead of omitting features that did not match.
+    ///
+    /// Defaults to `false`.
+    pub fn missing_as_zero(mut self, missing_as_zero: bool) -> Self {
+        self.missing_as_zero = Some(missing_as_zero);
+        self
+    }
+}
+
+impl ShouldSkip for LtrLog {
+    fn should_skip(&self) -> bool {
+        self.log_specs.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            LtrLog::new()
+                .log_spec(
+                    LtrLogSpec::named_query("log_entry1", "logged_featureset")
+                        .missing_as_zero(true),
+                )
+                .log_spec(LtrLogSpec::rescore_index("log_entry2", 0)),
+            json!({
+                "log_specs": [
+                    {
+                        "name": "log_entry1",
+                        "named_query": "logged_featureset",
+                        "missing_as_zero": true
+                    },
+                    {
+                        "name": "log_entry2",
+                        "rescore_index": 0
+                    }
+                ]
+            }),
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Plugins can extend the search request body with their own sections under `ext`. Arbitrary
+//! sections can be attached with [`Search::ext`](crate::Search::ext), while commonly used plugin
+//! extensions have typed helpers.
+
+mod ltr_log;
+
+pub use self::ltr_log::*;
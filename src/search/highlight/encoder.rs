@@ -1,6 +1,6 @@
 /// Indicates if the snippet should be HTML encoded.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
-#[serde(untagged, rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum Encoder {
     /// No encoding
     Default,
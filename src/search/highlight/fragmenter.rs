@@ -1,6 +1,6 @@
 /// Specifies how text should be broken up in highlight snippets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
-#[serde(untagged, rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum Fragmenter {
     /// Breaks up text into same-sized fragments.
     Simple,
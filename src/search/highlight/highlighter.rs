@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::convert::TryInto;
 
 /// Highlighter settings
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -39,6 +40,9 @@ pub struct DefaultHighlighter {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight_query: Option<Query>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_analyzed_offset: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     no_match_size: Option<u32>,
 
@@ -59,6 +63,16 @@ pub struct DefaultHighlighter {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
     tags: Option<Tags>,
+
+    // Field level overrides
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    boundary_scanner: Option<FvhBoundaryScanner>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fragmenter: Option<Fragmenter>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    matched_fields: Option<MatchedFields>,
 }
 
 /// The `fvh` highlighter uses the Lucene Fast Vector highlighter. This highlighter can be used on
@@ -101,6 +115,9 @@ pub struct FastVectorHighlighter {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight_query: Option<Query>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_analyzed_offset: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     no_match_size: Option<u32>,
 
@@ -173,6 +190,9 @@ pub struct PlainHighlighter {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight_query: Option<Query>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_analyzed_offset: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     no_match_size: Option<u32>,
 
@@ -228,6 +248,9 @@ pub struct UnifiedHighlighter {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight_query: Option<Query>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_analyzed_offset: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     no_match_size: Option<u32>,
 
@@ -254,6 +277,9 @@ pub struct UnifiedHighlighter {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
     boundary_scanner: Option<UnifiedBoundaryScanner>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    matched_fields: Option<MatchedFields>,
 }
 
 impl Default for Highlighter {
@@ -283,6 +309,17 @@ impl Highlighter {
     pub fn unified() -> UnifiedHighlighter {
         UnifiedHighlighter::default()
     }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        let highlight_query = match self {
+            Self::Default(highlighter) => &highlighter.highlight_query,
+            Self::Fvh(highlighter) => &highlighter.highlight_query,
+            Self::Plain(highlighter) => &highlighter.highlight_query,
+            Self::Unified(highlighter) => &highlighter.highlight_query,
+        };
+
+        highlight_query.iter().collect()
+    }
 }
 
 impl Default for FastVectorHighlighter {
@@ -295,6 +332,7 @@ impl Default for FastVectorHighlighter {
             force_source: None,
             fragment_size: None,
             highlight_query: None,
+            max_analyzed_offset: None,
             no_match_size: None,
             number_of_fragments: None,
             order: None,
@@ -320,6 +358,7 @@ impl Default for PlainHighlighter {
             force_source: None,
             fragment_size: None,
             highlight_query: None,
+            max_analyzed_offset: None,
             no_match_size: None,
             number_of_fragments: None,
             order: None,
@@ -342,6 +381,7 @@ impl Default for UnifiedHighlighter {
             force_source: None,
             fragment_size: None,
             highlight_query: None,
+            max_analyzed_offset: None,
             no_match_size: None,
             number_of_fragments: None,
             order: None,
@@ -350,6 +390,7 @@ impl Default for UnifiedHighlighter {
             require_field_match: None,
             tags: None,
             boundary_scanner: None,
+            matched_fields: None,
         }
     }
 }
@@ -423,6 +464,17 @@ macro_rules! add_highlighter_methods {
             self
         }
 
+        /// Limits the number of characters analyzed for highlighting to this value. Matches past
+        /// the limit are not highlighted, and the request no longer fails when the field exceeds
+        /// the index setting `index.highlight.max_analyzed_offset`. Values greater than the index
+        /// setting are ignored by Elasticsearch.
+        pub fn max_analyzed_offset(mut self, max_analyzed_offset: impl TryInto<u64>) -> Self {
+            if let Ok(max_analyzed_offset) = max_analyzed_offset.try_into() {
+                self.max_analyzed_offset = Some(max_analyzed_offset);
+            }
+            self
+        }
+
         /// The amount of text you want to return from the beginning of the field if there are no
         /// matching fragments to highlight. Defaults to `0` (nothing is returned).
         pub fn no_match_size(mut self, no_match_size: u32) -> Self {
@@ -468,7 +520,7 @@ macro_rules! add_highlighter_methods {
 }
 
 macro_rules! convert_to_highlighter {
-    ($method:tt, $struct:tt) => {
+    ($method:tt, $struct:tt $(, $field:ident)*) => {
         /// Converts [Highlighter](Highlighter) to specific highlighter
         pub fn $method(self) -> $struct {
             $struct {
@@ -478,6 +530,7 @@ macro_rules! convert_to_highlighter {
                 force_source: self.force_source,
                 fragment_size: self.fragment_size,
                 highlight_query: self.highlight_query,
+                max_analyzed_offset: self.max_analyzed_offset,
                 no_match_size: self.no_match_size,
                 number_of_fragments: self.number_of_fragments,
                 order: self.order,
@@ -485,6 +538,7 @@ macro_rules! convert_to_highlighter {
                 post_tags: self.post_tags,
                 require_field_match: self.require_field_match,
                 tags: self.tags,
+                $($field: self.$field,)*
                 ..Default::default()
             }
         }
@@ -498,9 +552,30 @@ impl DefaultHighlighter {
     }
 
     add_highlighter_methods!();
-    convert_to_highlighter!(fvh, FastVectorHighlighter);
-    convert_to_highlighter!(plain, PlainHighlighter);
-    convert_to_highlighter!(unified, UnifiedHighlighter);
+    convert_to_highlighter!(fvh, FastVectorHighlighter, boundary_scanner, matched_fields);
+    convert_to_highlighter!(plain, PlainHighlighter, fragmenter);
+    convert_to_highlighter!(unified, UnifiedHighlighter, matched_fields);
+
+    /// Specifies how to break the highlighted fragments. Useful when overriding a field of a
+    /// globally configured `fvh` or `unified` highlighter.
+    pub fn boundary_scanner(mut self, boundary_scanner: FvhBoundaryScanner) -> Self {
+        self.boundary_scanner = Some(boundary_scanner);
+        self
+    }
+
+    /// Specifies how text should be broken up in highlight snippets. Useful when overriding a
+    /// field of a globally configured `plain` highlighter.
+    pub fn fragmenter(mut self, fragmenter: Fragmenter) -> Self {
+        self.fragmenter = Some(fragmenter);
+        self
+    }
+
+    /// Combine matches on multiple fields to highlight a single field. Useful when overriding a
+    /// field of a globally configured `fvh` or `unified` highlighter.
+    pub fn matched_fields(mut self, matched_fields: impl Into<MatchedFields>) -> Self {
+        self.matched_fields = Some(matched_fields.into());
+        self
+    }
 }
 
 impl FastVectorHighlighter {
@@ -570,4 +645,12 @@ impl UnifiedHighlighter {
         self.boundary_scanner = Some(boundary_scanner);
         self
     }
+
+    /// Combine matches on multiple fields to highlight a single field. This is most intuitive for
+    /// multi-fields that analyze the same string in different ways. Requires `index_options` to
+    /// be set to `offsets` on all matched fields.
+    pub fn matched_fields(mut self, matched_fields: impl Into<MatchedFields>) -> Self {
+        self.matched_fields = Some(matched_fields.into());
+        self
+    }
 }
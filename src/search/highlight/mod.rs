@@ -45,6 +45,7 @@ mod matched_fields;
 mod order;
 mod tags;
 
+use crate::search::*;
 use crate::util::*;
 
 pub use self::boundary_scanner::*;
@@ -100,11 +101,20 @@ impl Highlight {
             .push(KeyValuePair::new(field.to_string(), highlighter.into()));
         self
     }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.highlighter
+            .iter()
+            .chain(self.fields.iter().map(|field| &field.value))
+            .flat_map(Highlighter::embedded_queries)
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Query;
 
     #[test]
     fn serialization() {
@@ -190,5 +200,69 @@ mod tests {
                 ]
             }),
         );
+
+        assert_serialize(
+            Highlight::new()
+                .highlighter(Highlighter::new().fvh())
+                .field_highlighter(
+                    "comment",
+                    Highlighter::new()
+                        .matched_fields(["comment", "comment.plain"])
+                        .boundary_scanner(FvhBoundaryScanner::Word(Some("en-US".into())))
+                        .highlight_query(Query::match_phrase("comment.plain", "search"))
+                        .no_match_size(150),
+                )
+                .field_highlighter(
+                    "title",
+                    Highlighter::new().plain().fragmenter(Fragmenter::Simple),
+                )
+                .field_highlighter(
+                    "body",
+                    Highlighter::new()
+                        .matched_fields(["body", "body.english"])
+                        .unified(),
+                ),
+            json!({
+                "type": "fvh",
+                "fields": [
+                    {
+                        "comment": {
+                            "highlight_query": {
+                                "match_phrase": {
+                                    "comment.plain": { "query": "search" }
+                                }
+                            },
+                            "no_match_size": 150,
+                            "boundary_scanner": "word",
+                            "boundary_scanner_locale": "en-US",
+                            "matched_fields": ["comment", "comment.plain"]
+                        }
+                    },
+                    { "title": { "type": "plain", "fragmenter": "simple" } },
+                    { "body": { "type": "unified", "matched_fields": ["body", "body.english"] } },
+                ]
+            }),
+        );
+
+        assert_serialize(
+            Highlight::new()
+                .highlighter(
+                    Highlighter::new()
+                        .encoder(Encoder::Html)
+                        .tags(Tags::Styled)
+                        .require_field_match(false)
+                        .max_analyzed_offset(1_000_000),
+                )
+                .field("content"),
+            json!({
+                "encoder": "html",
+                "max_analyzed_offset": 1_000_000,
+                "require_field_match": false,
+                "tags_schema": "styled",
+                "fields": [
+                    { "content": {} }
+                ]
+            }),
+        );
     }
 }
@@ -0,0 +1,228 @@
+//! A k-nearest neighbor (kNN) search finds the k nearest vectors to a query vector, as measured by
+//! a similarity metric.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html>
+
+mod query_vector_builder;
+
+pub use self::query_vector_builder::*;
+
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// Performs an approximate kNN search on a `dense_vector` field. The query vector can either be
+/// provided directly or computed by Elasticsearch at query time with a
+/// [`QueryVectorBuilder`].
+///
+/// To create a kNN search with a raw query vector:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let knn =
+/// Knn::query_vector("image-vector", [54.0, 10.0, -2.0])
+///     .k(5)
+///     .num_candidates(50);
+/// ```
+/// To create a kNN search with a vector computed from text:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let knn =
+/// Knn::query_vector_builder(
+///     "content-vector",
+///     QueryVectorBuilder::text_embedding("my-text-embedding-model", "The opposite of blue"),
+/// )
+/// .k(10)
+/// .num_candidates(100);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html#approximate-knn>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Knn {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<Vec<f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector_builder: Option<QueryVectorBuilder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    k: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    num_candidates: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Queries,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<InnerHits>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+}
+
+impl Knn {
+    /// Creates an instance of [`Knn`] with a raw query vector
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector` - Query vector. Must have the same number of dimensions as the vector
+    ///   field you are searching against.
+    pub fn query_vector<T>(field: impl ToString, query_vector: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<f32>,
+    {
+        Self {
+            field: field.to_string(),
+            query_vector: Some(query_vector.into_iter().map(Into::into).collect()),
+            query_vector_builder: None,
+            k: None,
+            num_candidates: None,
+            filter: Default::default(),
+            similarity: None,
+            inner_hits: None,
+            boost: None,
+        }
+    }
+
+    /// Creates an instance of [`Knn`] with a query vector built by Elasticsearch
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector_builder` - A configuration object indicating how to build a query vector
+    ///   before executing the request
+    pub fn query_vector_builder(
+        field: impl ToString,
+        query_vector_builder: impl Into<QueryVectorBuilder>,
+    ) -> Self {
+        Self {
+            field: field.to_string(),
+            query_vector: None,
+            query_vector_builder: Some(query_vector_builder.into()),
+            k: None,
+            num_candidates: None,
+            filter: Default::default(),
+            similarity: None,
+            inner_hits: None,
+            boost: None,
+        }
+    }
+
+    /// Number of nearest neighbors to return as top hits. This value must be less than
+    /// `num_candidates`.
+    ///
+    /// Defaults to `size`.
+    pub fn k(mut self, k: impl TryInto<u64>) -> Self {
+        if let Ok(k) = k.try_into() {
+            self.k = Some(k);
+        }
+        self
+    }
+
+    /// The number of nearest neighbor candidates to consider per shard. Needs to be greater than
+    /// `k`, or `size` if `k` is omitted, and cannot exceed 10,000.
+    pub fn num_candidates(mut self, num_candidates: impl TryInto<u64>) -> Self {
+        if let Ok(num_candidates) = num_candidates.try_into() {
+            self.num_candidates = Some(num_candidates);
+        }
+        self
+    }
+
+    /// Query to filter the documents that can match. The kNN search will return the top `k`
+    /// documents that also match this filter.
+    pub fn filter(mut self, filter: impl Into<Queries>) -> Self {
+        self.filter.extend(filter);
+        self
+    }
+
+    /// The minimum similarity required for a document to be considered a match
+    pub fn similarity(mut self, similarity: impl Into<f32>) -> Self {
+        self.similarity = Some(similarity.into());
+        self
+    }
+
+    /// Returns the nested vectors that caused each hit to match
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits = Some(inner_hits);
+        self
+    }
+
+    /// Floating point number used to weight the kNN scores when combined with a `query`.
+    ///
+    /// Defaults to `1.0`.
+    pub fn boost(mut self, boost: impl TryInto<Boost>) -> Self {
+        if let Ok(boost) = boost.try_into() {
+            self.boost = non_default(boost, 1);
+        }
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.filter
+            .iter()
+            .chain(self.inner_hits.iter().flat_map(InnerHits::embedded_queries))
+            .collect()
+    }
+}
+
+impl ShouldSkip for Knn {
+    fn should_skip(&self) -> bool {
+        self.field.should_skip()
+            || (self.query_vector.should_skip() && self.query_vector_builder.should_skip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Knn::query_vector("image-vector", [54.0, 10.0, -2.0])
+                .k(5)
+                .num_candidates(50)
+                .filter(Query::term("file-type", "png"))
+                .similarity(36.0)
+                .boost(2),
+            json!({
+                "field": "image-vector",
+                "query_vector": [54.0, 10.0, -2.0],
+                "k": 5,
+                "num_candidates": 50,
+                "filter": [
+                    { "term": { "file-type": { "value": "png" } } }
+                ],
+                "similarity": 36.0,
+                "boost": 2
+            }),
+        );
+
+        assert_serialize(
+            Knn::query_vector_builder(
+                "dense-vector-field",
+                QueryVectorBuilder::text_embedding(
+                    "my-text-embedding-model",
+                    "The opposite of blue",
+                ),
+            )
+            .k(10)
+            .num_candidates(100)
+            .inner_hits(InnerHits::new().size(1)),
+            json!({
+                "field": "dense-vector-field",
+                "query_vector_builder": {
+                    "text_embedding": {
+                        "model_id": "my-text-embedding-model",
+                        "model_text": "The opposite of blue"
+                    }
+                },
+                "k": 10,
+                "num_candidates": 100,
+                "inner_hits": { "size": 1 }
+            }),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+/// A configuration object indicating how to build a query vector before executing a kNN search.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html#knn-semantic-search>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryVectorBuilder {
+    /// Computes the query vector with a deployed text embedding model
+    TextEmbedding(TextEmbedding),
+}
+
+/// Builds a query vector by running `model_text` through the text embedding model `model_id`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TextEmbedding {
+    model_id: String,
+
+    model_text: String,
+}
+
+impl QueryVectorBuilder {
+    /// Creates an instance of [`QueryVectorBuilder::TextEmbedding`]
+    ///
+    /// - `model_id` - The ID of the text embedding model to use to generate the dense vectors
+    ///   from the query string
+    /// - `model_text` - The query string from which the model generates the dense vector
+    ///   representation
+    pub fn text_embedding(model_id: impl ToString, model_text: impl ToString) -> Self {
+        Self::TextEmbedding(TextEmbedding {
+            model_id: model_id.to_string(),
+            model_text: model_text.to_string(),
+        })
+    }
+}
+
+impl From<TextEmbedding> for QueryVectorBuilder {
+    fn from(value: TextEmbedding) -> Self {
+        Self::TextEmbedding(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            QueryVectorBuilder::text_embedding(
+                "sentence-transformers__msmarco",
+                "How is the weather?",
+            ),
+            json!({
+                "text_embedding": {
+                    "model_id": "sentence-transformers__msmarco",
+                    "model_text": "How is the weather?"
+                }
+            }),
+        );
+    }
+}
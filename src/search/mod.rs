@@ -10,26 +10,56 @@
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search.html>
 
 // Private modules
+mod aggregations_handler;
+mod canonical;
+mod count_response;
+mod date_histogram_response;
+mod explain_response;
+mod msearch_response;
+mod redaction;
+mod referenced_fields;
 mod response;
+mod search_diff;
+mod search_target;
+mod url_params;
 
 // Public modules
 pub mod aggregations;
+pub mod collapse;
+pub mod cursor;
+pub mod ext;
 pub mod highlight;
+pub mod knn;
 pub mod params;
 pub mod queries;
 pub mod request;
 pub mod rescoring;
+pub mod retrievers;
 pub mod runtime_mappings;
 pub mod sort;
 
 // Public re-exports
 pub use self::aggregations::*;
+pub use self::aggregations_handler::*;
+pub use self::collapse::*;
+pub use self::count_response::*;
+pub use self::cursor::*;
+pub use self::date_histogram_response::*;
+pub use self::explain_response::*;
+pub use self::ext::*;
 pub use self::highlight::*;
+pub use self::knn::*;
+pub use self::msearch_response::*;
 pub use self::params::*;
 pub use self::queries::params::*;
 pub use self::queries::*;
+pub use self::redaction::*;
 pub use self::request::*;
 pub use self::rescoring::*;
 pub use self::response::*;
+pub use self::retrievers::*;
 pub use self::runtime_mappings::*;
+pub use self::search_diff::*;
+pub use self::search_target::*;
 pub use self::sort::*;
+pub use self::url_params::*;
@@ -0,0 +1,126 @@
+use crate::errors::*;
+use crate::search::*;
+use serde_json::Value;
+
+/// Multi search response
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MsearchResponse<H = Value, IH = Value> {
+    /// The time that it took Elasticsearch to process all searches
+    pub took: u64,
+
+    /// Responses of the individual searches, in the same order as the request bodies
+    pub responses: Vec<MsearchResponseItem<H, IH>>,
+}
+
+/// Response of a single search within a multi search request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum MsearchResponseItem<H = Value, IH = Value> {
+    /// The search failed
+    Error(ErrorResponse),
+
+    /// The search completed
+    Success(SearchResponse<H, IH>),
+}
+
+impl<H, IH> MsearchResponse<H, IH> {
+    /// Converts the individual responses into results, preserving their order
+    pub fn into_results(self) -> Vec<Result<SearchResponse<H, IH>, ErrorResponse>> {
+        self.responses
+            .into_iter()
+            .map(MsearchResponseItem::into_result)
+            .collect()
+    }
+
+    /// Failed searches along with their position in the request
+    pub fn errors(&self) -> impl Iterator<Item = (usize, &ErrorResponse)> {
+        self.responses
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| item.as_result().err().map(|error| (index, error)))
+    }
+}
+
+impl<H, IH> MsearchResponseItem<H, IH> {
+    /// Borrows the response as a result
+    pub fn as_result(&self) -> Result<&SearchResponse<H, IH>, &ErrorResponse> {
+        match self {
+            Self::Error(error) => Err(error),
+            Self::Success(response) => Ok(response),
+        }
+    }
+
+    /// Converts the response into a result
+    pub fn into_result(self) -> Result<SearchResponse<H, IH>, ErrorResponse> {
+        match self {
+            Self::Error(error) => Err(error),
+            Self::Success(response) => Ok(response),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Message {
+        message: String,
+    }
+
+    #[test]
+    fn deserializes_successfully() {
+        let response: MsearchResponse<Message> = serde_json::from_value(json!({
+            "took": 12,
+            "responses": [
+                {
+                    "took": 5,
+                    "timed_out": false,
+                    "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                    "hits": {
+                        "total": { "value": 1, "relation": "eq" },
+                        "max_score": 1.0,
+                        "hits": [
+                            {
+                                "_index": "logs",
+                                "_id": "1",
+                                "_score": 1.0,
+                                "_source": { "message": "hello" }
+                            }
+                        ]
+                    },
+                    "status": 200
+                },
+                {
+                    "error": {
+                        "type": "index_not_found_exception",
+                        "reason": "no such index [missing]",
+                        "index": "missing"
+                    },
+                    "status": 404
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(response.took, 12);
+        assert_eq!(response.responses.len(), 2);
+
+        let errors: Vec<_> = response.errors().collect();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(errors[0].1.kind(), &ErrorKind::IndexNotFound);
+
+        let results = response.into_results();
+        let first = results[0].as_ref().unwrap();
+
+        assert_eq!(
+            first.hits.hits[0].source,
+            Some(Message {
+                message: "hello".into()
+            })
+        );
+        assert_eq!(results[1].as_ref().unwrap_err().status, 404);
+    }
+}
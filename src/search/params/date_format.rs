@@ -0,0 +1,183 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// [Format](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+/// used to parse and render date values, either one of the built-in formats or a custom
+/// [`java.time.format.DateTimeFormatter`](https://docs.oracle.com/javase/8/docs/api/java/time/format/DateTimeFormatter.html)
+/// pattern. Several formats are tried in turn when combined with [`or`](Self::or).
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let format =
+/// DateFormat::StrictDateOptionalTime.or(DateFormat::EpochMillis);
+/// # let format =
+/// DateFormat::custom("yyyy-MM-dd HH:mm:ss");
+/// # assert_eq!(
+/// #     DateFormat::from("yyyy-MM-dd||epoch_millis").to_string(),
+/// #     DateFormat::custom("yyyy-MM-dd").or(DateFormat::EpochMillis).to_string(),
+/// # );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DateFormat {
+    /// `strict_date_optional_time`, a date with an optional time in ISO 8601 format, such as
+    /// `2015-01-01` or `2015-01-01T12:10:30Z`. Together with [`EpochMillis`](Self::EpochMillis),
+    /// the default format of date fields
+    StrictDateOptionalTime,
+
+    /// `strict_date_optional_time_nanos`, same as
+    /// [`StrictDateOptionalTime`](Self::StrictDateOptionalTime) with up to nanosecond fractions
+    StrictDateOptionalTimeNanos,
+
+    /// `date_optional_time`, a lenient version of
+    /// [`StrictDateOptionalTime`](Self::StrictDateOptionalTime)
+    DateOptionalTime,
+
+    /// `epoch_millis`, milliseconds since the epoch
+    EpochMillis,
+
+    /// `epoch_second`, seconds since the epoch
+    EpochSecond,
+
+    /// `strict_date`, a four digit year, two digit month and two digit day, `yyyy-MM-dd`
+    StrictDate,
+
+    /// `strict_date_time`, a full date and time with milliseconds and time zone,
+    /// `yyyy-MM-dd'T'HH:mm:ss.SSSZ`
+    StrictDateTime,
+
+    /// `strict_date_time_no_millis`, a full date and time without milliseconds,
+    /// `yyyy-MM-dd'T'HH:mm:ssZ`
+    StrictDateTimeNoMillis,
+
+    /// `strict_date_hour_minute_second`, `yyyy-MM-dd'T'HH:mm:ss`
+    StrictDateHourMinuteSecond,
+
+    /// `basic_date`, a compact date, `yyyyMMdd`
+    BasicDate,
+
+    /// `basic_date_time`, a compact date and time, `yyyyMMdd'T'HHmmss.SSSZ`
+    BasicDateTime,
+
+    /// `strict_year`, a four digit year, `yyyy`
+    StrictYear,
+
+    /// `strict_year_month`, `yyyy-MM`
+    StrictYearMonth,
+
+    /// A custom pattern, such as `yyyy/MM/dd`, or a built-in format not listed above
+    Custom(String),
+}
+
+impl DateFormat {
+    /// Creates an instance of [`DateFormat::Custom`]
+    pub fn custom<T>(pattern: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Custom(pattern.to_string())
+    }
+
+    /// Falls back to `format` for values that can't be parsed with this format
+    pub fn or<T>(self, format: T) -> Self
+    where
+        T: Into<Self>,
+    {
+        Self::Custom(format!("{}||{}", self, format.into()))
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Self::StrictDateOptionalTime => "strict_date_optional_time",
+            Self::StrictDateOptionalTimeNanos => "strict_date_optional_time_nanos",
+            Self::DateOptionalTime => "date_optional_time",
+            Self::EpochMillis => "epoch_millis",
+            Self::EpochSecond => "epoch_second",
+            Self::StrictDate => "strict_date",
+            Self::StrictDateTime => "strict_date_time",
+            Self::StrictDateTimeNoMillis => "strict_date_time_no_millis",
+            Self::StrictDateHourMinuteSecond => "strict_date_hour_minute_second",
+            Self::BasicDate => "basic_date",
+            Self::BasicDateTime => "basic_date_time",
+            Self::StrictYear => "strict_year",
+            Self::StrictYearMonth => "strict_year_month",
+            Self::Custom(pattern) => pattern,
+        }
+    }
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Serialize for DateFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl From<&str> for DateFormat {
+    fn from(value: &str) -> Self {
+        match value {
+            "strict_date_optional_time" => Self::StrictDateOptionalTime,
+            "strict_date_optional_time_nanos" => Self::StrictDateOptionalTimeNanos,
+            "date_optional_time" => Self::DateOptionalTime,
+            "epoch_millis" => Self::EpochMillis,
+            "epoch_second" => Self::EpochSecond,
+            "strict_date" => Self::StrictDate,
+            "strict_date_time" => Self::StrictDateTime,
+            "strict_date_time_no_millis" => Self::StrictDateTimeNoMillis,
+            "strict_date_hour_minute_second" => Self::StrictDateHourMinuteSecond,
+            "basic_date" => Self::BasicDate,
+            "basic_date_time" => Self::BasicDateTime,
+            "strict_year" => Self::StrictYear,
+            "strict_year_month" => Self::StrictYearMonth,
+            _ => Self::custom(value),
+        }
+    }
+}
+
+impl From<String> for DateFormat {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            DateFormat::StrictDateOptionalTime,
+            json!("strict_date_optional_time"),
+        );
+        assert_serialize(DateFormat::BasicDate, json!("basic_date"));
+        assert_serialize(DateFormat::custom("yyyy/MM/dd"), json!("yyyy/MM/dd"));
+        assert_serialize(
+            DateFormat::StrictDateOptionalTime
+                .or(DateFormat::EpochMillis)
+                .or("yyyy/MM/dd"),
+            json!("strict_date_optional_time||epoch_millis||yyyy/MM/dd"),
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(DateFormat::from("epoch_second"), DateFormat::EpochSecond);
+        assert_eq!(
+            DateFormat::from(String::from("strict_year_month")),
+            DateFormat::StrictYearMonth
+        );
+        assert_eq!(
+            DateFormat::from("dd.MM.yyyy"),
+            DateFormat::Custom("dd.MM.yyyy".into())
+        );
+    }
+}
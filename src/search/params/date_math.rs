@@ -0,0 +1,54 @@
+/// A [date math](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#date-math)
+/// expression, such as `now-1h` or `2014-07-08||+1M/d`, resolved by Elasticsearch at query time.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let date_math =
+/// DateMath::now();
+/// # let date_math =
+/// DateMath::new("now-1d/d");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct DateMath(String);
+
+impl DateMath {
+    /// Creates an instance of [`DateMath`]
+    ///
+    /// - `expression` - An anchor date, either `now` or a date string ending with `||`, followed
+    ///   by optional math expressions
+    pub fn new<T>(expression: T) -> Self
+    where
+        T: ToString,
+    {
+        Self(expression.to_string())
+    }
+
+    /// The current time, `now`
+    pub fn now() -> Self {
+        Self::new("now")
+    }
+}
+
+impl From<&str> for DateMath {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for DateMath {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DateMath::now(), json!("now"));
+        assert_serialize(DateMath::from("now-1h/h"), json!("now-1h/h"));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::search::*;
+use crate::util::*;
+use std::{convert::TryInto, fmt};
+
+/// Name of a document field, as accepted by the field lists of
+/// [`multi_match`](crate::MultiMatchQuery), [`combined_fields`](crate::CombinedFieldsQuery),
+/// [`query_string`](crate::QueryStringQuery) and
+/// [`simple_query_string`](crate::SimpleQueryStringQuery).
+///
+/// Boosting a field weighs its matches using the `^` notation:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let query =
+/// Query::multi_match(
+///     [Field::new("title").boost(3), BoostedField::from("body")],
+///     "this is a test",
+/// );
+/// # assert_eq!(Field::new("title").boost(3).to_string(), "title^3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Field(String);
+
+/// A [`Field`] with an optional boost, serialized as `field^boost`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoostedField {
+    field: Field,
+    boost: Option<Boost>,
+}
+
+impl Field {
+    /// Creates an instance of [`Field`]
+    ///
+    /// - `name` - Field name, field wildcard patterns such as `title*` are allowed
+    pub fn new<T>(name: T) -> Self
+    where
+        T: ToString,
+    {
+        Self(name.to_string())
+    }
+
+    /// Multiplies the score of matches in this field by `boost`. Invalid boosts are ignored and
+    /// leave the field unboosted.
+    pub fn boost<T>(self, boost: T) -> BoostedField
+    where
+        T: TryInto<Boost>,
+    {
+        BoostedField {
+            field: self,
+            boost: boost
+                .try_into()
+                .ok()
+                .and_then(|boost| non_default(boost, 1)),
+        }
+    }
+}
+
+impl BoostedField {
+    /// The boosted field
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// The boost of the field, if any
+    pub fn boost(&self) -> Option<Boost> {
+        self.boost
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for BoostedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.boost {
+            Some(boost) => write!(f, "{}^{}", self.field, boost),
+            None => self.field.fmt(f),
+        }
+    }
+}
+
+impl serde::Serialize for BoostedField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Field {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<T> for BoostedField
+where
+    T: Into<Field>,
+{
+    fn from(value: T) -> Self {
+        Self {
+            field: value.into(),
+            boost: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Field::new("title"), json!("title"));
+        assert_serialize(Field::new("title").boost(3), json!("title^3"));
+        assert_serialize(Field::new("title").boost(1.5), json!("title^1.5"));
+        assert_serialize(Field::new("title").boost(-1), json!("title"));
+        assert_serialize(BoostedField::from("body"), json!("body"));
+    }
+
+    #[test]
+    fn accepted_by_field_lists() {
+        let fields = || [Field::new("title").boost(3), BoostedField::from("body*")];
+
+        let expected = json!(["title^3", "body*"]);
+
+        assert_serialize(
+            Query::multi_match(fields(), "rust"),
+            json!({ "multi_match": { "fields": expected, "query": "rust" } }),
+        );
+        assert_serialize(
+            Query::combined_fields(fields(), "rust"),
+            json!({ "combined_fields": { "fields": expected, "query": "rust" } }),
+        );
+        assert_serialize(
+            Query::query_string("rust").fields(fields()),
+            json!({ "query_string": { "fields": expected, "query": "rust" } }),
+        );
+        assert_serialize(
+            Query::simple_query_string("rust").fields(fields()),
+            json!({ "simple_query_string": { "fields": expected, "query": "rust" } }),
+        );
+    }
+}
@@ -8,14 +8,130 @@ pub struct GeoCoordinate {
     longitude: f32,
 }
 
+/// Reasons for a latitude-longitude pair to be rejected as a [`GeoCoordinate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoCoordinateError {
+    /// Latitude is outside of `-90..=90` or not finite
+    InvalidLatitude,
+
+    /// Longitude is outside of `-180..=180` or not finite
+    InvalidLongitude,
+}
+
+impl Display for GeoCoordinateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLatitude => "Latitude must be between -90 and 90".fmt(f),
+            Self::InvalidLongitude => "Longitude must be between -180 and 180".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for GeoCoordinateError {}
+
 impl GeoCoordinate {
     /// Creates an instance of [`GeoCoordinate`]
+    ///
+    /// Values are not validated, use [`try_new`](Self::try_new) or
+    /// [`normalized`](Self::normalized) for user provided coordinates.
     pub fn new(latitude: f32, longitude: f32) -> Self {
         Self {
             latitude,
             longitude,
         }
     }
+
+    /// Creates an instance of [`GeoCoordinate`], rejecting latitudes outside of `-90..=90` and
+    /// longitudes outside of `-180..=180`, which Elasticsearch rejects at query time
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// assert!(GeoCoordinate::try_new(52.37, 4.89).is_ok());
+    /// assert_eq!(
+    ///     GeoCoordinate::try_new(91., 4.89),
+    ///     Err(GeoCoordinateError::InvalidLatitude)
+    /// );
+    /// assert_eq!(
+    ///     GeoCoordinate::try_new(52.37, 184.89),
+    ///     Err(GeoCoordinateError::InvalidLongitude)
+    /// );
+    /// ```
+    pub fn try_new(latitude: f32, longitude: f32) -> Result<Self, GeoCoordinateError> {
+        validate(latitude, longitude).map(|(latitude, longitude)| Self::new(latitude, longitude))
+    }
+
+    /// Creates an instance of [`GeoCoordinate`], wrapping out of range values around the globe
+    /// the same way Elasticsearch does with `validation_method: COERCE`. Latitudes beyond
+    /// the poles continue on the opposite side of the globe.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// assert_eq!(
+    ///     GeoCoordinate::normalized(52.5, 364.5),
+    ///     GeoCoordinate::new(52.5, 4.5),
+    /// );
+    /// assert_eq!(
+    ///     GeoCoordinate::normalized(100., 0.),
+    ///     GeoCoordinate::new(80., 180.),
+    /// );
+    /// ```
+    pub fn normalized(latitude: f32, longitude: f32) -> Self {
+        let (latitude, longitude) = normalize(latitude, longitude);
+
+        Self::new(latitude, longitude)
+    }
+
+    /// Latitude of the coordinate
+    pub fn latitude(&self) -> f32 {
+        self.latitude
+    }
+
+    /// Longitude of the coordinate
+    pub fn longitude(&self) -> f32 {
+        self.longitude
+    }
+}
+
+/// Checks that the latitude and longitude are within their ranges
+pub(crate) fn validate(latitude: f32, longitude: f32) -> Result<(f32, f32), GeoCoordinateError> {
+    if !(-90. ..=90.).contains(&latitude) {
+        Err(GeoCoordinateError::InvalidLatitude)
+    } else if !(-180. ..=180.).contains(&longitude) {
+        Err(GeoCoordinateError::InvalidLongitude)
+    } else {
+        Ok((latitude, longitude))
+    }
+}
+
+/// Wraps the latitude into `-90..=90` and the longitude into `-180..=180`
+pub(crate) fn normalize(latitude: f32, longitude: f32) -> (f32, f32) {
+    let mut latitude = centered_modulus(latitude, 360.);
+    let mut longitude = longitude;
+
+    if latitude > 90. {
+        latitude = 180. - latitude;
+        longitude += 180.;
+    } else if latitude < -90. {
+        latitude = -180. - latitude;
+        longitude += 180.;
+    }
+
+    if !(-180. ..=180.).contains(&longitude) {
+        longitude = centered_modulus(longitude, 360.);
+    }
+
+    (latitude, longitude)
+}
+
+/// Remainder of the division in `-modulus / 2..=modulus / 2`
+fn centered_modulus(value: f32, modulus: f32) -> f32 {
+    let value = value.rem_euclid(modulus);
+
+    if value > modulus / 2. {
+        value - modulus
+    } else {
+        value
+    }
 }
 
 impl Display for GeoCoordinate {
@@ -97,4 +213,56 @@ mod tests {
         assert!(GeoCoordinate::from_str("1.1,2.2,3").is_err());
         assert!(GeoCoordinate::from_str("abc").is_err());
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            GeoCoordinate::try_new(-90., 180.),
+            Ok(GeoCoordinate::new(-90., 180.))
+        );
+        assert_eq!(
+            GeoCoordinate::try_new(-90.5, 0.),
+            Err(GeoCoordinateError::InvalidLatitude)
+        );
+        assert_eq!(
+            GeoCoordinate::try_new(0., -180.5),
+            Err(GeoCoordinateError::InvalidLongitude)
+        );
+        assert_eq!(
+            GeoCoordinate::try_new(f32::NAN, 0.),
+            Err(GeoCoordinateError::InvalidLatitude)
+        );
+        assert_eq!(
+            GeoCoordinate::try_new(0., f32::INFINITY),
+            Err(GeoCoordinateError::InvalidLongitude)
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(
+            GeoCoordinate::normalized(45., 90.),
+            GeoCoordinate::new(45., 90.)
+        );
+        assert_eq!(
+            GeoCoordinate::normalized(45., 180.),
+            GeoCoordinate::new(45., 180.)
+        );
+        assert_eq!(
+            GeoCoordinate::normalized(45., 190.),
+            GeoCoordinate::new(45., -170.)
+        );
+        assert_eq!(
+            GeoCoordinate::normalized(45., -540.),
+            GeoCoordinate::new(45., 180.)
+        );
+        assert_eq!(
+            GeoCoordinate::normalized(-100., 10.),
+            GeoCoordinate::new(-80., -170.)
+        );
+        assert_eq!(
+            GeoCoordinate::normalized(400., 0.),
+            GeoCoordinate::new(40., 0.)
+        );
+    }
 }
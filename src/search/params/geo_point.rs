@@ -1,3 +1,5 @@
+use super::geo_coordinate::{normalize, validate};
+use crate::search::*;
 use serde::ser::{Serialize, Serializer};
 
 /// Fields of type geo_point accept latitude-longitude pairs.
@@ -40,6 +42,9 @@ pub enum GeoPoint {
 
 impl GeoPoint {
     /// Creates an instance of [GeoPoint](GeoPoint)
+    ///
+    /// Values are not validated, use [`try_coordinates`](Self::try_coordinates) or
+    /// [`normalized_coordinates`](Self::normalized_coordinates) for user provided coordinates.
     pub fn coordinates(latitude: f32, longitude: f32) -> Self {
         Self::Coordinates {
             latitude,
@@ -47,6 +52,21 @@ impl GeoPoint {
         }
     }
 
+    /// Creates an instance of [GeoPoint](GeoPoint), rejecting latitudes outside of `-90..=90`
+    /// and longitudes outside of `-180..=180`, see [`GeoCoordinate::try_new`]
+    pub fn try_coordinates(latitude: f32, longitude: f32) -> Result<Self, GeoCoordinateError> {
+        validate(latitude, longitude)
+            .map(|(latitude, longitude)| Self::coordinates(latitude, longitude))
+    }
+
+    /// Creates an instance of [GeoPoint](GeoPoint), wrapping out of range values around the
+    /// globe, see [`GeoCoordinate::normalized`]
+    pub fn normalized_coordinates(latitude: f32, longitude: f32) -> Self {
+        let (latitude, longitude) = normalize(latitude, longitude);
+
+        Self::coordinates(latitude, longitude)
+    }
+
     /// Creates an instance of [GeoPoint](GeoPoint)
     pub fn geohash(geohash: impl Into<String>) -> Self {
         Self::Geohash(geohash.into())
@@ -83,6 +103,22 @@ mod tests {
         assert_eq!(result, expectation);
     }
 
+    #[test]
+    fn validates_coordinates() {
+        assert_eq!(
+            GeoPoint::try_coordinates(52.37, 4.89),
+            Ok(GeoPoint::coordinates(52.37, 4.89))
+        );
+        assert_eq!(
+            GeoPoint::try_coordinates(-91., 4.89),
+            Err(GeoCoordinateError::InvalidLatitude)
+        );
+        assert_eq!(
+            GeoPoint::normalized_coordinates(52.37, -355.),
+            GeoPoint::coordinates(52.37, 5.)
+        );
+    }
+
     #[test]
     fn serializes_geohash_successfully() {
         let geo_point = GeoPoint::geohash("ww8p1r4t8");
@@ -2,10 +2,15 @@
 
 mod coordinate;
 mod date;
+mod date_format;
+mod date_math;
+mod field;
 mod geo_coordinate;
 mod geo_point;
 mod geo_shape;
 mod number;
+mod page;
+mod point_in_time;
 mod search_filter;
 mod shape;
 mod term;
@@ -16,10 +21,15 @@ mod units;
 
 pub use self::coordinate::*;
 pub use self::date::*;
+pub use self::date_format::*;
+pub use self::date_math::*;
+pub use self::field::*;
 pub use self::geo_coordinate::*;
 pub use self::geo_point::*;
 pub use self::geo_shape::*;
 pub use self::number::*;
+pub use self::page::*;
+pub use self::point_in_time::*;
 pub use self::search_filter::*;
 pub use self::shape::*;
 pub use self::term::*;
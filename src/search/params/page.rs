@@ -0,0 +1,169 @@
+use std::{convert::TryFrom, fmt};
+
+/// Page based pagination helper which computes `from` and `size` for a search request.
+///
+/// By default, you cannot use `from` and `size` to page through more than 10,000 hits, which is
+/// controlled by the `index.max_result_window` index setting. Pages reaching beyond this window
+/// are rejected and should be retrieved with `search_after` instead.
+///
+/// To request the third page of twenty hits:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new().page(Page::new(3, 20).unwrap());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    number: u64,
+    size: u64,
+}
+
+/// Reasons for a page number and size to be rejected as a [`Page`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageError {
+    /// Page number is `0`, pages start at `1`
+    ZeroNumber,
+
+    /// Page size is `0`
+    ZeroSize,
+
+    /// Page ends beyond the result window, `from + size` exceeds `max`
+    BeyondResultWindow {
+        /// Starting document offset of the page
+        from: u64,
+
+        /// Number of hits per page
+        size: u64,
+
+        /// Maximum value of `from + size` accepted by the index
+        max: u64,
+    },
+}
+
+impl fmt::Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroNumber => "Page number must start at 1".fmt(f),
+            Self::ZeroSize => "Page size must be greater than 0".fmt(f),
+            Self::BeyondResultWindow { from, size, max } => write!(
+                f,
+                "Page from {from} with size {size} is beyond the result window of {max}, \
+                 use search_after for deep pagination"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PageError {}
+
+impl Page {
+    /// Default value of the `index.max_result_window` index setting
+    pub const MAX_RESULT_WINDOW: u64 = 10_000;
+
+    /// Creates an instance of [`Page`] validated against the default result window
+    ///
+    /// - `number` - Page number, starting at `1`
+    /// - `size` - Number of hits per page
+    pub fn new(number: u64, size: u64) -> Result<Self, PageError> {
+        Self::with_max_result_window(number, size, Self::MAX_RESULT_WINDOW)
+    }
+
+    /// Creates an instance of [`Page`] validated against a custom `index.max_result_window`
+    ///
+    /// - `number` - Page number, starting at `1`
+    /// - `size` - Number of hits per page
+    /// - `max_result_window` - Maximum value of `from + size` accepted by the index
+    pub fn with_max_result_window(
+        number: u64,
+        size: u64,
+        max_result_window: u64,
+    ) -> Result<Self, PageError> {
+        if number == 0 {
+            return Err(PageError::ZeroNumber);
+        }
+
+        if size == 0 {
+            return Err(PageError::ZeroSize);
+        }
+
+        match number.checked_mul(size) {
+            Some(end) if end <= max_result_window => Ok(Self { number, size }),
+            _ => Err(PageError::BeyondResultWindow {
+                from: (number - 1).saturating_mul(size),
+                size,
+                max: max_result_window,
+            }),
+        }
+    }
+
+    /// Page number, starting at `1`
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Number of hits per page
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Starting document offset of the page
+    pub fn from(&self) -> u64 {
+        (self.number - 1) * self.size
+    }
+}
+
+impl TryFrom<(u64, u64)> for Page {
+    type Error = PageError;
+
+    fn try_from((number, size): (u64, u64)) -> Result<Self, Self::Error> {
+        Self::new(number, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_offsets() {
+        let page = Page::new(3, 20).unwrap();
+
+        assert_eq!(page.number(), 3);
+        assert_eq!(page.size(), 20);
+        assert_eq!(page.from(), 40);
+        assert_eq!(Page::new(1, 10).unwrap().from(), 0);
+    }
+
+    #[test]
+    fn validates_result_window() {
+        assert_eq!(Page::new(0, 10), Err(PageError::ZeroNumber));
+        assert_eq!(Page::new(1, 0), Err(PageError::ZeroSize));
+        assert!(Page::new(1000, 10).is_ok());
+        assert_eq!(
+            Page::new(1001, 10),
+            Err(PageError::BeyondResultWindow {
+                from: 10_000,
+                size: 10,
+                max: 10_000
+            })
+        );
+        assert!(Page::new(u64::MAX, u64::MAX).is_err());
+        assert!(Page::with_max_result_window(1001, 10, 20_000).is_ok());
+        assert!(Page::try_from((5, 10)).is_ok());
+    }
+
+    #[test]
+    fn formats_errors() {
+        assert_eq!(
+            PageError::BeyondResultWindow {
+                from: 10_000,
+                size: 10,
+                max: 10_000
+            }
+            .to_string(),
+            "Page from 10000 with size 10 is beyond the result window of 10000, \
+             use search_after for deep pagination"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A point in time (PIT) is a lightweight view into the state of the data as it existed when
+/// initiated. Searching against a PIT preserves the same view of the data across successive
+/// requests, which makes it the recommended way of paginating with `search_after`.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new().pit(PointInTime::new("46ToAwMDaWR5BXV1aWQy").keep_alive(Time::Minutes(1)));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PointInTime {
+    id: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keep_alive: Option<Time>,
+}
+
+impl PointInTime {
+    /// Creates an instance of [`PointInTime`]
+    ///
+    /// - `id` - ID of the point in time returned by the open point in time API or by the
+    ///   previous search response
+    pub fn new(id: impl ToString) -> Self {
+        Self {
+            id: id.to_string(),
+            keep_alive: None,
+        }
+    }
+
+    /// Extends the time to live of the point in time
+    pub fn keep_alive(mut self, keep_alive: Time) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+
+    /// ID of the point in time
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl ShouldSkip for PointInTime {
+    fn should_skip(&self) -> bool {
+        self.id.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(PointInTime::new("pit-id"), json!({ "id": "pit-id" }));
+
+        assert_serialize(
+            PointInTime::new("pit-id").keep_alive(Time::Minutes(5)),
+            json!({ "id": "pit-id", "keep_alive": "5m" }),
+        );
+    }
+}
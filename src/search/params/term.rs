@@ -394,8 +394,8 @@ mod tests {
             (Term::from(1f64), Term::from(1f32)),
             (Term::from(1f64), Term::from(1f64)),
             (
-                Term::from(Utc.ymd(2021, 3, 10).and_hms(10, 42, 0)),
-                Term::from(Utc.ymd(2021, 3, 10).and_hms(10, 42, 0)),
+                Term::from(Utc.with_ymd_and_hms(2021, 3, 10, 10, 42, 0).unwrap()),
+                Term::from(Utc.with_ymd_and_hms(2021, 3, 10, 10, 42, 0).unwrap()),
             ),
         ];
 
@@ -21,6 +21,38 @@ where
     }
 }
 
+impl Terms {
+    /// Number of distinct terms
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` when there are no terms
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Splits the terms into collections of at most `size` terms each
+    pub(crate) fn chunks(self, size: usize) -> Vec<Self> {
+        let mut chunks = Vec::new();
+        let mut chunk = std::collections::BTreeSet::new();
+
+        for term in self.0 {
+            let _ = chunk.insert(term);
+
+            if chunk.len() == size {
+                chunks.push(Self(std::mem::take(&mut chunk)));
+            }
+        }
+
+        if !chunk.is_empty() {
+            chunks.push(Self(chunk));
+        }
+
+        chunks
+    }
+}
+
 impl ShouldSkip for Terms {
     fn should_skip(&self) -> bool {
         self.0.is_empty()
@@ -1,4 +1,5 @@
 use serde::ser::{Serialize, Serializer};
+use std::{convert::TryFrom, str::FromStr};
 
 /// Whenever durations need to be specified, e.g. for a `timeout` parameter,
 /// the duration must specify the unit, like `2d` for 2 days.
@@ -34,6 +35,49 @@ impl Serialize for Time {
     }
 }
 
+impl From<std::time::Duration> for Time {
+    /// Converts the duration into the largest unit that represents it exactly, e.g. 48 hours
+    /// into `2d` and 90 seconds into `90s`
+    fn from(duration: std::time::Duration) -> Self {
+        let nanos = duration.as_nanos();
+        let divisible = |unit: u128| nanos.checked_rem(unit) == Some(0);
+        let value = |unit: u128| u64::try_from(nanos / unit).unwrap_or(u64::MAX);
+
+        if divisible(86_400_000_000_000) {
+            Self::Days(value(86_400_000_000_000))
+        } else if divisible(3_600_000_000_000) {
+            Self::Hours(value(3_600_000_000_000))
+        } else if divisible(60_000_000_000) {
+            Self::Minutes(value(60_000_000_000))
+        } else if divisible(1_000_000_000) {
+            Self::Seconds(value(1_000_000_000))
+        } else if divisible(1_000_000) {
+            Self::Milliseconds(value(1_000_000))
+        } else if divisible(1_000) {
+            Self::Microseconds(value(1_000))
+        } else {
+            Self::Nanoseconds(value(1))
+        }
+    }
+}
+
+impl From<chrono::Duration> for Time {
+    /// Converts the duration into the largest unit that represents it exactly, negative
+    /// durations are converted into their absolute value
+    fn from(duration: chrono::Duration) -> Self {
+        let duration = if duration < chrono::Duration::zero() {
+            -duration
+        } else {
+            duration
+        };
+
+        duration
+            .to_std()
+            .map(Self::from)
+            .unwrap_or(Self::Milliseconds(u64::MAX))
+    }
+}
+
 /// Calendar-aware intervals are configured with the `calendar_interval` parameter. You can specify
 /// calendar intervals using the unit name, such as `month`, or as a single unit quantity, such as
 /// `1M`. For example,`day` and `1d` are equivalent. Multiple quantities, such as `2d`, are not supported.
@@ -181,3 +225,120 @@ impl Serialize for Distance {
         .serialize(serializer)
     }
 }
+
+impl FromStr for Distance {
+    type Err = String;
+
+    /// Parses distances such as `"12km"`, `"200mi"` or `"5 nmi"` using the
+    /// [distance units](https://www.elastic.co/guide/en/elasticsearch/reference/current/api-conventions.html#distance-units)
+    /// accepted by Elasticsearch. Distances without a unit are in meters.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (value, unit) = s.split_at(split);
+
+        let value: u64 = match value.parse() {
+            Ok(value) => value,
+            Err(_) => return Err(format!("Couldn't parse '{}' as distance", s)),
+        };
+
+        match unit.trim() {
+            "mi" | "miles" => Ok(Self::Miles(value)),
+            "yd" | "yards" => Ok(Self::Yards(value)),
+            "ft" | "feet" => Ok(Self::Feet(value)),
+            "in" | "inch" => Ok(Self::Inches(value)),
+            "km" | "kilometers" => Ok(Self::Kilometers(value)),
+            "" | "m" | "meters" => Ok(Self::Meters(value)),
+            "cm" | "centimeters" => Ok(Self::Centimeter(value)),
+            "mm" | "millimeters" => Ok(Self::Millimeters(value)),
+            "NM" | "nmi" | "nauticalmiles" => Ok(Self::NauticalMiles(value)),
+            _ => Err(format!("Couldn't parse '{}' as distance", s)),
+        }
+    }
+}
+
+/// Unit of a distance computed by Elasticsearch, such as the distances returned by a
+/// [`GeoDistanceSort`](crate::GeoDistanceSort). Defaults to meters.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/api-conventions.html#distance-units>
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize)]
+pub enum DistanceUnit {
+    /// Miles, `mi`
+    #[serde(rename = "mi")]
+    Miles,
+
+    /// Yards, `yd`
+    #[serde(rename = "yd")]
+    Yards,
+
+    /// Feet, `ft`
+    #[serde(rename = "ft")]
+    Feet,
+
+    /// Inches, `in`
+    #[serde(rename = "in")]
+    Inches,
+
+    /// Kilometers, `km`
+    #[serde(rename = "km")]
+    Kilometers,
+
+    /// Meters, `m`
+    #[default]
+    #[serde(rename = "m")]
+    Meters,
+
+    /// Centimeters, `cm`
+    #[serde(rename = "cm")]
+    Centimeters,
+
+    /// Millimeters, `mm`
+    #[serde(rename = "mm")]
+    Millimeters,
+
+    /// Nautical miles, `nmi`
+    #[serde(rename = "nmi")]
+    NauticalMiles,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_durations() {
+        assert_eq!(Time::from(chrono::Duration::hours(48)), Time::Days(2));
+        assert_eq!(
+            Time::from(chrono::Duration::minutes(-90)),
+            Time::Minutes(90)
+        );
+        assert_eq!(Time::from(chrono::Duration::seconds(90)), Time::Seconds(90));
+        assert_eq!(
+            Time::from(chrono::Duration::milliseconds(1_500)),
+            Time::Milliseconds(1_500)
+        );
+        assert_eq!(
+            Time::from(std::time::Duration::from_micros(1_001)),
+            Time::Microseconds(1_001)
+        );
+        assert_eq!(
+            Time::from(std::time::Duration::from_nanos(1_001)),
+            Time::Nanoseconds(1_001)
+        );
+        assert_eq!(Time::from(std::time::Duration::from_secs(0)), Time::Days(0));
+    }
+
+    #[test]
+    fn parses_distance() {
+        assert_eq!("12km".parse(), Ok(Distance::Kilometers(12)));
+        assert_eq!("200mi".parse(), Ok(Distance::Miles(200)));
+        assert_eq!(" 5 nmi ".parse(), Ok(Distance::NauticalMiles(5)));
+        assert_eq!("3NM".parse(), Ok(Distance::NauticalMiles(3)));
+        assert_eq!("300".parse(), Ok(Distance::Meters(300)));
+        assert_eq!("10meters".parse(), Ok(Distance::Meters(10)));
+
+        assert!("km".parse::<Distance>().is_err());
+        assert!("1.5km".parse::<Distance>().is_err());
+        assert!("12lightyears".parse::<Distance>().is_err());
+    }
+}
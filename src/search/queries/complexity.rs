@@ -0,0 +1,248 @@
+use crate::search::*;
+use std::fmt;
+
+impl Query {
+    /// Depth of the query tree, where a leaf query has a depth of `1` and every compound query
+    /// adds one level on top of its deepest clause. [Annotations](AnnotatedQuery) don't add a
+    /// level.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let query = Query::from(
+    ///     Query::bool().filter(Query::nested("comments", Query::term("user", "kimchy"))),
+    /// );
+    ///
+    /// assert_eq!(query.depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        let query = self.unannotated();
+        let span_clauses = query.span_clauses();
+
+        1 + query
+            .children()
+            .into_iter()
+            .chain(&span_clauses)
+            .map(Query::depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Total number of query clauses in the tree, including compound queries themselves but not
+    /// [annotations](AnnotatedQuery)
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let query = Query::from(
+    ///     Query::bool()
+    ///         .must(Query::term("user", "kimchy"))
+    ///         .filter([Query::term("tag", "tech"), Query::term("tag", "rust")]),
+    /// );
+    ///
+    /// assert_eq!(query.clause_count(), 4);
+    /// ```
+    pub fn clause_count(&self) -> usize {
+        let query = self.unannotated();
+        let span_clauses = query.span_clauses();
+
+        1 + query
+            .children()
+            .into_iter()
+            .chain(&span_clauses)
+            .map(Query::clause_count)
+            .sum::<usize>()
+    }
+}
+
+/// Limits on the complexity of a query tree, used to reject pathological queries, such as
+/// user-constructed filters, before they are sent to the cluster.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// let limit = ComplexityLimit::new().max_depth(2).max_clauses(10);
+///
+/// assert!(limit.check(&Query::term("user", "kimchy").into()).is_ok());
+/// assert_eq!(
+///     limit.check(&Query::bool().must(Query::bool().must(Query::term("user", "kimchy"))).into()),
+///     Err(ComplexityError::TooDeep { limit: 2, actual: 3 }),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexityLimit {
+    max_depth: Option<usize>,
+    max_clauses: Option<usize>,
+}
+
+impl ComplexityLimit {
+    /// Creates an instance of [`ComplexityLimit`] without any limits
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Maximum allowed [`Query::depth`]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Maximum allowed [`Query::clause_count`]
+    pub fn max_clauses(mut self, max_clauses: usize) -> Self {
+        self.max_clauses = Some(max_clauses);
+        self
+    }
+
+    /// Checks whether the query is within the configured limits
+    pub fn check(&self, query: &Query) -> Result<(), ComplexityError> {
+        if let Some(limit) = self.max_depth {
+            let actual = query.depth();
+
+            if actual > limit {
+                return Err(ComplexityError::TooDeep { limit, actual });
+            }
+        }
+
+        if let Some(limit) = self.max_clauses {
+            let actual = query.clause_count();
+
+            if actual > limit {
+                return Err(ComplexityError::TooManyClauses { limit, actual });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reasons for a query to be rejected by a [`ComplexityLimit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexityError {
+    /// [`Query::depth`] exceeds the configured maximum
+    TooDeep {
+        /// Maximum allowed depth
+        limit: usize,
+
+        /// Depth of the rejected query
+        actual: usize,
+    },
+
+    /// [`Query::clause_count`] exceeds the configured maximum
+    TooManyClauses {
+        /// Maximum allowed number of clauses
+        limit: usize,
+
+        /// Number of clauses in the rejected query
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ComplexityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooDeep { limit, actual } => write!(
+                f,
+                "Query depth of {actual} exceeds the maximum allowed depth of {limit}"
+            ),
+            Self::TooManyClauses { limit, actual } => write!(
+                f,
+                "Query with {actual} clauses exceeds the maximum allowed number of {limit} clauses"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ComplexityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_depth_and_clause_count() {
+        let leaf = Query::from(Query::term("user", "kimchy"));
+
+        assert_eq!(leaf.depth(), 1);
+        assert_eq!(leaf.clause_count(), 1);
+
+        let query = Query::from(
+            Query::bool()
+                .must(
+                    Query::dis_max()
+                        .query(Query::term("a", 1))
+                        .query(Query::term("b", 2)),
+                )
+                .should(Query::nested(
+                    "comments",
+                    Query::bool().filter(Query::term("comments.user", "kimchy")),
+                ))
+                .must_not(Query::term("c", 3)),
+        );
+
+        assert_eq!(query.depth(), 4);
+        assert_eq!(query.clause_count(), 8);
+    }
+
+    #[test]
+    fn checks_limits() {
+        let query = Query::from(Query::bool().must([Query::term("a", 1), Query::term("b", 2)]));
+
+        assert!(ComplexityLimit::new().check(&query).is_ok());
+        assert!(ComplexityLimit::new().max_depth(2).check(&query).is_ok());
+        assert_eq!(
+            ComplexityLimit::new().max_depth(1).check(&query),
+            Err(ComplexityError::TooDeep {
+                limit: 1,
+                actual: 2
+            })
+        );
+        assert!(ComplexityLimit::new().max_clauses(3).check(&query).is_ok());
+        assert_eq!(
+            ComplexityLimit::new().max_clauses(2).check(&query),
+            Err(ComplexityError::TooManyClauses {
+                limit: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn formats_errors() {
+        assert_eq!(
+            ComplexityError::TooDeep {
+                limit: 1,
+                actual: 2
+            }
+            .to_string(),
+            "Query depth of 2 exceeds the maximum allowed depth of 1"
+        );
+        assert_eq!(
+            ComplexityError::TooManyClauses {
+                limit: 2,
+                actual: 3
+            }
+            .to_string(),
+            "Query with 3 clauses exceeds the maximum allowed number of 2 clauses"
+        );
+    }
+
+    #[test]
+    fn counts_span_and_knn_clauses() {
+        let span = Query::from(Query::span_near([
+            SpanQuery::from(Query::span_term("text", "quick")),
+            SpanQuery::from(Query::span_or([
+                Query::span_term("text", "brown"),
+                Query::span_term("text", "red"),
+            ])),
+            SpanQuery::from(Query::wildcard("text", "f*x")),
+        ]));
+
+        assert_eq!(span.depth(), 3);
+        assert_eq!(span.clause_count(), 7);
+
+        let knn = Query::from(
+            Query::knn("vector", vec![1.0, 2.0])
+                .filter(Query::bool().filter(Query::term("tag", "rust"))),
+        );
+
+        assert_eq!(knn.depth(), 3);
+        assert_eq!(knn.clause_count(), 3);
+    }
+}
@@ -55,6 +55,22 @@ impl Query {
     pub fn bool() -> BoolQuery {
         BoolQuery::default()
     }
+
+    /// Creates an instance of [`BoolQuery`] excluding the documents matching any of the
+    /// `queries`, further exclusions can be added with [`BoolQuery::must_not`]
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let query =
+    /// Query::not(Query::term("status", "deleted"))
+    ///     .must_not(Query::term("status", "archived"));
+    /// ```
+    pub fn not<Q>(queries: Q) -> BoolQuery
+    where
+        Q: Into<Queries>,
+    {
+        Self::bool().must_not(queries)
+    }
 }
 
 impl BoolQuery {
@@ -100,6 +116,103 @@ impl BoolQuery {
         self
     }
 
+    /// Adds clauses of the given occurrence type, for query generators choosing the occurrence
+    /// at runtime
+    pub fn clause<Q>(mut self, occurrence: Occurrence, queries: Q) -> Self
+    where
+        Q: Into<Queries>,
+    {
+        self.clauses_mut(occurrence).extend(queries);
+        self
+    }
+
+    /// Adds every query of the iterator as a [`must`](Self::must) clause
+    pub fn musts<I>(self, queries: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Option<Query>>,
+    {
+        self.clause(Occurrence::Must, queries)
+    }
+
+    /// Adds every query of the iterator as a [`filter`](Self::filter) clause
+    pub fn filters<I>(self, queries: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Option<Query>>,
+    {
+        self.clause(Occurrence::Filter, queries)
+    }
+
+    /// Adds every query of the iterator as a [`should`](Self::should) clause
+    pub fn shoulds<I>(self, queries: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Option<Query>>,
+    {
+        self.clause(Occurrence::Should, queries)
+    }
+
+    /// Adds every query of the iterator as a [`must_not`](Self::must_not) clause
+    pub fn must_nots<I>(self, queries: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Option<Query>>,
+    {
+        self.clause(Occurrence::MustNot, queries)
+    }
+
+    /// Clauses of the given occurrence type, in the order they were added. Skipped queries
+    /// are never kept.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let query = Query::bool()
+    ///     .filters(["red", "green"].iter().map(|color| Query::term("color", *color)))
+    ///     .should(Query::r#match("title", "rust"));
+    ///
+    /// assert_eq!(query.clauses(Occurrence::Filter).len(), 2);
+    /// assert_eq!(query.should_clauses().len(), 1);
+    /// assert!(query.must_clauses().is_empty());
+    /// ```
+    pub fn clauses(&self, occurrence: Occurrence) -> &[Query] {
+        match occurrence {
+            Occurrence::Must => &self.inner.must.0,
+            Occurrence::Filter => &self.inner.filter.0,
+            Occurrence::Should => &self.inner.should.0,
+            Occurrence::MustNot => &self.inner.must_not.0,
+        }
+    }
+
+    /// Clauses added with [`must`](Self::must)
+    pub fn must_clauses(&self) -> &[Query] {
+        self.clauses(Occurrence::Must)
+    }
+
+    /// Clauses added with [`filter`](Self::filter)
+    pub fn filter_clauses(&self) -> &[Query] {
+        self.clauses(Occurrence::Filter)
+    }
+
+    /// Clauses added with [`should`](Self::should)
+    pub fn should_clauses(&self) -> &[Query] {
+        self.clauses(Occurrence::Should)
+    }
+
+    /// Clauses added with [`must_not`](Self::must_not)
+    pub fn must_not_clauses(&self) -> &[Query] {
+        self.clauses(Occurrence::MustNot)
+    }
+
+    fn clauses_mut(&mut self, occurrence: Occurrence) -> &mut Queries {
+        match occurrence {
+            Occurrence::Must => &mut self.inner.must,
+            Occurrence::Filter => &mut self.inner.filter,
+            Occurrence::Should => &mut self.inner.should,
+            Occurrence::MustNot => &mut self.inner.must_not,
+        }
+    }
+
     /// You can use the `minimum_should_match` parameter to specify the number
     /// or percentage of should clauses returned documents must match.
     ///
@@ -118,6 +231,110 @@ impl BoolQuery {
     }
 
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        self.inner
+            .must
+            .iter()
+            .chain(self.inner.filter.iter())
+            .chain(self.inner.should.iter())
+            .chain(self.inner.must_not.iter())
+            .collect()
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        self.inner
+            .must
+            .iter_mut()
+            .chain(self.inner.filter.iter_mut())
+            .chain(self.inner.should.iter_mut())
+            .chain(self.inner.must_not.iter_mut())
+            .collect()
+    }
+
+    /// Simplifies the clauses of a bool query whose clauses have already been normalized, see
+    /// [`Query::normalize`]
+    pub(crate) fn normalize(mut self) -> Query {
+        simplify(&mut self.inner.must, false);
+        simplify(&mut self.inner.should, false);
+        simplify(&mut self.inner.filter, true);
+        simplify(&mut self.inner.must_not, true);
+
+        let Inner {
+            must,
+            filter,
+            should,
+            must_not,
+            minimum_should_match,
+            boost,
+            _name,
+        } = &mut self.inner;
+
+        if minimum_should_match.is_some() || boost.is_some() || _name.is_some() {
+            return self.into();
+        }
+
+        match (
+            must.0.len(),
+            filter.0.len(),
+            should.0.len(),
+            must_not.0.len(),
+        ) {
+            (1, 0, 0, 0) => must.0.remove(0),
+            (0, 0, 1, 0) => should.0.remove(0),
+            _ => self.into(),
+        }
+    }
+
+    /// Whether the bool query consists of a single filter clause
+    fn is_single_filter(&self) -> bool {
+        self.inner.minimum_should_match.is_none()
+            && self.inner.boost.is_none()
+            && self.inner._name.is_none()
+            && self.inner.must.0.is_empty()
+            && self.inner.should.0.is_empty()
+            && self.inner.must_not.0.is_empty()
+            && self.inner.filter.0.len() == 1
+    }
+
+    /// Whether filter clauses can be added without changing which documents match, which is
+    /// not the case for a bool query with only `should` clauses since those stop being required
+    pub(crate) fn accepts_filter(&self) -> bool {
+        self.inner.should.0.is_empty()
+            || self.inner.minimum_should_match.is_some()
+            || !self.inner.must.0.is_empty()
+            || !self.inner.filter.0.is_empty()
+    }
+}
+
+/// Drops skipped clauses and, in filter context, unwraps redundant wrappers and merges duplicates
+fn simplify(queries: &mut Queries, filter_context: bool) {
+    for mut query in std::mem::take(&mut queries.0) {
+        if filter_context {
+            query = unwrap_filter(query);
+        }
+
+        if query.should_skip() || (filter_context && queries.0.contains(&query)) {
+            continue;
+        }
+
+        queries.0.push(query);
+    }
+}
+
+/// Unwraps queries that only affect scoring, which is ignored in filter context
+fn unwrap_filter(query: Query) -> Query {
+    match query {
+        Query::Bool(mut query) if query.is_single_filter() => {
+            unwrap_filter(query.inner.filter.0.remove(0))
+        }
+        Query::ConstantScore(query) => match query.into_filter() {
+            Ok(query) => unwrap_filter(query),
+            Err(query) => query.into(),
+        },
+        Query::Annotated(query) => query.map(unwrap_filter).into(),
+        query => query,
+    }
 }
 
 impl ShouldSkip for BoolQuery {
@@ -133,6 +350,33 @@ impl ShouldSkip for BoolQuery {
 mod tests {
     use super::*;
 
+    #[test]
+    fn serializes_not() {
+        assert_serialize(
+            Query::not(Query::term("status", "deleted")),
+            json!({
+                "bool": {
+                    "must_not": [{ "term": { "status": { "value": "deleted" } } }]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::not([Query::term("status", "deleted"), Query::term("status", "")])
+                .must_not(Query::term("status", "archived")),
+            json!({
+                "bool": {
+                    "must_not": [
+                        { "term": { "status": { "value": "deleted" } } },
+                        { "term": { "status": { "value": "archived" } } }
+                    ]
+                }
+            }),
+        );
+
+        assert!(Query::not(Query::term("status", "")).should_skip());
+    }
+
     #[test]
     fn serialization() {
         assert_serialize(Query::bool(), json!({ "bool": {} }));
@@ -209,4 +453,31 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn adds_clauses_from_iterators() {
+        let colors = ["red", "green", ""];
+
+        let query = Query::bool()
+            .musts(vec![Query::from(Query::term("test1", 1))])
+            .filters(colors.iter().map(|color| Query::term("color", *color)))
+            .shoulds(None::<Query>)
+            .must_nots(vec![Query::from(Query::exists("deleted"))])
+            .clause(Occurrence::Should, Query::term("test2", 2));
+
+        assert_eq!(query.must_clauses(), [Query::term("test1", 1)]);
+        assert_eq!(
+            query.filter_clauses(),
+            [Query::term("color", "red"), Query::term("color", "green")]
+        );
+        assert_eq!(query.should_clauses(), [Query::term("test2", 2)]);
+        assert_eq!(query.must_not_clauses(), [Query::exists("deleted")]);
+        assert_eq!(
+            Occurrence::ALL
+                .iter()
+                .map(|occurrence| query.clauses(*occurrence).len())
+                .sum::<usize>(),
+            5
+        );
+    }
 }
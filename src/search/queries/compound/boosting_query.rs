@@ -69,6 +69,14 @@ impl Query {
 
 impl BoostingQuery {
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.positive.as_ref(), self.inner.negative.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.positive.as_mut(), self.inner.negative.as_mut()]
+    }
 }
 
 impl ShouldSkip for BoostingQuery {
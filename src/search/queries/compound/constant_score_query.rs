@@ -54,6 +54,24 @@ impl Query {
 
 impl ConstantScoreQuery {
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.filter.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.filter.as_mut()]
+    }
+
+    /// Filter query wrapped by an unnamed constant score query, as the score of a clause in
+    /// filter context is ignored
+    pub(crate) fn into_filter(self) -> Result<Query, Self> {
+        if self.inner._name.is_none() {
+            Ok(*self.inner.filter)
+        } else {
+            Err(self)
+        }
+    }
 }
 
 impl ShouldSkip for ConstantScoreQuery {
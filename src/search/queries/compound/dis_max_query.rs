@@ -93,6 +93,14 @@ impl DisMaxQuery {
     }
 
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        self.inner.queries.iter().collect()
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        self.inner.queries.iter_mut().collect()
+    }
 }
 
 impl ShouldSkip for DisMaxQuery {
@@ -119,6 +119,14 @@ impl FunctionScoreQuery {
     }
 
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.query.as_mut()]
+    }
 }
 
 impl ShouldSkip for FunctionScoreQuery {
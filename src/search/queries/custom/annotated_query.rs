@@ -0,0 +1,260 @@
+use crate::search::*;
+use crate::util::*;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Wraps a query with client-side annotations, such as the UI filter that produced it, to help
+/// debugging generated query trees. Annotations are never sent to Elasticsearch, the query
+/// serializes as the wrapped query, and can be read back with [`Query::annotations`] while
+/// walking the tree with a [`QueryVisitor`].
+///
+/// Annotations are otherwise transparent: an annotated query equals the query it wraps, has the
+/// same [`Query::depth`] and [`Query::is_compound`], and is looked through by
+/// [`Query::normalize`] and [`Search::filter`](crate::Search::filter).
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// struct Provenance(Vec<String>);
+///
+/// impl QueryVisitor for Provenance {
+///     fn visit(&mut self, query: &Query) {
+///         if let Some(source) = query.annotations().and_then(|a| a.get("source")) {
+///             self.0.push(source.clone());
+///         }
+///     }
+/// }
+///
+/// let brand = Query::annotated(Query::term("brand", "acme")).annotation("source", "brand_facet");
+/// let price = Query::annotated(Query::range("price").lte(100)).annotation("source", "price_slider");
+///
+/// let query = Query::from(Query::bool().filter(brand).filter(price));
+///
+/// let mut provenance = Provenance(vec![]);
+/// query.walk(&mut provenance);
+///
+/// assert_eq!(provenance.0, ["brand_facet", "price_slider"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnnotatedQuery {
+    query: Box<Query>,
+    annotations: BTreeMap<String, String>,
+}
+
+impl Query {
+    /// Creates an instance of [`AnnotatedQuery`], annotating an already annotated query adds
+    /// to its annotations
+    ///
+    /// - `query` - Query to annotate
+    pub fn annotated<Q>(query: Q) -> AnnotatedQuery
+    where
+        Q: Into<Query>,
+    {
+        match query.into() {
+            Self::Annotated(query) => query,
+            query => AnnotatedQuery {
+                query: Box::new(query),
+                annotations: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Client-side annotations of an [`AnnotatedQuery`], [`None`] for other queries
+    pub fn annotations(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Annotated(query) => Some(&query.annotations),
+            _ => None,
+        }
+    }
+
+    /// The query without any annotations
+    pub(crate) fn unannotated(&self) -> &Query {
+        match self {
+            Self::Annotated(query) => query.query.unannotated(),
+            query => query,
+        }
+    }
+
+    /// The query without any annotations
+    pub(crate) fn unannotated_mut(&mut self) -> &mut Query {
+        match self {
+            Self::Annotated(query) => query.query.unannotated_mut(),
+            query => query,
+        }
+    }
+}
+
+impl AnnotatedQuery {
+    /// Sets the annotation `key` to `value`, replacing any previous value
+    pub fn annotation<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        let _ = self.annotations.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// The annotated query
+    pub fn query(&self) -> &Query {
+        &self.query
+    }
+
+    /// Replaces the annotated query, keeping the annotations
+    pub(crate) fn map<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(Query) -> Query,
+    {
+        self.query = Box::new(f(*self.query));
+        self
+    }
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.query.as_mut()]
+    }
+}
+
+impl PartialEq for AnnotatedQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.query.eq(&other.query)
+    }
+}
+
+impl PartialEq<AnnotatedQuery> for Query {
+    fn eq(&self, other: &AnnotatedQuery) -> bool {
+        self.eq(other.query())
+    }
+}
+
+impl PartialEq<Query> for AnnotatedQuery {
+    fn eq(&self, other: &Query) -> bool {
+        self.query().eq(other)
+    }
+}
+
+impl Serialize for AnnotatedQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.query.serialize(serializer)
+    }
+}
+
+impl ShouldSkip for AnnotatedQuery {
+    fn should_skip(&self) -> bool {
+        self.query.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::annotated(Query::term("brand", "acme")).annotation("source", "brand_facet"),
+            json!({ "term": { "brand": { "value": "acme" } } }),
+        );
+
+        assert_serialize(
+            Query::bool().filter(
+                Query::annotated(Query::term("brand", "acme")).annotation("source", "brand_facet"),
+            ),
+            json!({ "bool": { "filter": [{ "term": { "brand": { "value": "acme" } } }] } }),
+        );
+    }
+
+    #[test]
+    fn merges_annotations() {
+        let query = Query::from(
+            Query::annotated(
+                Query::annotated(Query::term("brand", "acme"))
+                    .annotation("source", "brand_facet")
+                    .annotation("position", 1),
+            )
+            .annotation("position", 2),
+        );
+
+        assert_eq!(
+            query.annotations().unwrap(),
+            &[
+                ("position".to_string(), "2".to_string()),
+                ("source".to_string(), "brand_facet".to_string()),
+            ]
+            .iter()
+            .cloned()
+            .collect::<BTreeMap<_, _>>()
+        );
+        assert_eq!(query.depth(), 1);
+        assert_eq!(query.clause_count(), 1);
+        assert_eq!(
+            Query::from(Query::term("brand", "acme")).annotations(),
+            None
+        );
+    }
+
+    #[test]
+    fn is_transparent() {
+        let term = Query::from(Query::term("brand", "acme"));
+        let annotated = Query::from(Query::annotated(term.clone()).annotation("source", "facet"));
+
+        assert_eq!(annotated, term);
+        assert_eq!(term, annotated);
+        assert_eq!(annotated, Query::term("brand", "acme"));
+        assert_eq!(Query::annotated(term.clone()), term);
+        assert_ne!(annotated, Query::from(Query::term("brand", "other")));
+        assert!(!annotated.is_compound());
+        assert!(Query::from(Query::annotated(Query::bool().filter(term.clone()))).is_compound());
+
+        let nested = Query::from(Query::bool().filter(annotated.clone()));
+
+        assert_eq!(nested, Query::from(Query::bool().filter(term)));
+        assert_eq!(nested.depth(), 2);
+        assert_eq!(nested.clause_count(), 2);
+    }
+
+    #[test]
+    fn is_looked_through_when_normalizing() {
+        let query = Query::from(
+            Query::bool()
+                .filter(
+                    Query::annotated(Query::bool().filter(Query::term("brand", "acme")))
+                        .annotation("source", "facet"),
+                )
+                .filter(Query::term("brand", "acme")),
+        )
+        .normalize();
+
+        assert_serialize(
+            &query,
+            json!({ "bool": { "filter": [{ "term": { "brand": { "value": "acme" } } }] } }),
+        );
+
+        let mut filters = vec![];
+
+        struct Annotations<'a>(&'a mut Vec<BTreeMap<String, String>>);
+
+        impl QueryVisitor for Annotations<'_> {
+            fn visit(&mut self, query: &Query) {
+                if let Some(annotations) = query.annotations() {
+                    self.0.push(annotations.clone());
+                }
+            }
+        }
+
+        query.walk(&mut Annotations(&mut filters));
+
+        assert_eq!(filters.len(), 1);
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::annotated(Query::term("brand", "")).should_skip());
+        assert!(!Query::annotated(Query::term("brand", "acme")).should_skip());
+    }
+}
@@ -12,6 +12,8 @@ use crate::util::*;
 /// ```
 /// **NOTE**: This is fallible and can lead to incorrect queries and
 /// rejected search requests, use ar your own risk.
+///
+/// The raw query is sent as is, so a `_name` has to be part of the JSON itself.
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct JsonQuery(serde_json::Value);
 
@@ -1,5 +1,7 @@
 //! Non official queries, such as plugins or raw JSON queries
 
+mod annotated_query;
 mod json_query;
 
+pub use self::annotated_query::*;
 pub use self::json_query::*;
@@ -166,4 +166,28 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn nests_in_compound_queries() {
+        assert_serialize(
+            Query::bool()
+                .must(Query::combined_fields(
+                    ["title", "body"],
+                    "database systems",
+                ))
+                .must(Query::combined_fields(Vec::<String>::new(), "skipped")),
+            json!({
+                "bool": {
+                    "must": [
+                        {
+                            "combined_fields": {
+                                "query": "database systems",
+                                "fields": ["title", "body"]
+                            }
+                        }
+                    ]
+                }
+            }),
+        );
+    }
 }
@@ -0,0 +1,195 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Returns documents based on the order and proximity of matching terms.
+///
+/// The `intervals` query uses **matching rules**, constructed from a small set of definitions.
+/// These rules are then applied to terms from a specified `field`. The rules nest through
+/// [`all_of`](IntervalsRule::all_of) and [`any_of`](IntervalsRule::any_of), and can be
+/// restricted with an [`IntervalsFilter`].
+///
+/// To create an intervals query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::intervals(
+///     "my_text",
+///     IntervalsRule::all_of([
+///         IntervalsRule::from(IntervalsRule::r#match("my favorite food").ordered(true)),
+///         IntervalsRule::from(IntervalsRule::any_of([
+///             IntervalsRule::r#match("hot water"),
+///             IntervalsRule::r#match("cold porridge"),
+///         ])),
+///     ])
+///     .ordered(true),
+/// )
+/// .boost(2)
+/// .name("test");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-intervals-query.html>
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalsQuery {
+    field: String,
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    #[serde(flatten)]
+    rule: IntervalsRule,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`IntervalsQuery`]
+    ///
+    /// - `field` - Field you wish to search.
+    /// - `rule` - Matching rule applied to the terms of the `field`, see [`IntervalsRule`]
+    pub fn intervals<T, U>(field: T, rule: U) -> IntervalsQuery
+    where
+        T: ToString,
+        U: Into<IntervalsRule>,
+    {
+        IntervalsQuery {
+            field: field.to_string(),
+            inner: Inner {
+                rule: rule.into(),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl IntervalsQuery {
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for IntervalsQuery {
+    fn should_skip(&self) -> bool {
+        self.field.should_skip() || self.inner.rule.should_skip()
+    }
+}
+
+impl Serialize for IntervalsQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut hash = std::collections::HashMap::new();
+        let _ = hash.insert(&self.field, &self.inner);
+
+        let mut map = serializer.serialize_struct("IntervalsQuery", 1)?;
+        map.serialize_field("intervals", &hash)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::intervals("my_text", IntervalsRule::prefix("hot")),
+            json!({
+                "intervals": {
+                    "my_text": {
+                        "prefix": {
+                            "prefix": "hot"
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::intervals(
+                "my_text",
+                IntervalsRule::all_of([
+                    IntervalsRule::from(
+                        IntervalsRule::r#match("my favorite food")
+                            .max_gaps(0)
+                            .ordered(true),
+                    ),
+                    IntervalsRule::from(IntervalsRule::any_of([
+                        IntervalsRule::r#match("hot water"),
+                        IntervalsRule::r#match("cold porridge"),
+                    ])),
+                ])
+                .ordered(true),
+            )
+            .boost(2)
+            .name("test"),
+            json!({
+                "intervals": {
+                    "my_text": {
+                        "all_of": {
+                            "ordered": true,
+                            "intervals": [
+                                {
+                                    "match": {
+                                        "query": "my favorite food",
+                                        "max_gaps": 0,
+                                        "ordered": true
+                                    }
+                                },
+                                {
+                                    "any_of": {
+                                        "intervals": [
+                                            { "match": { "query": "hot water" } },
+                                            { "match": { "query": "cold porridge" } }
+                                        ]
+                                    }
+                                }
+                            ]
+                        },
+                        "boost": 2,
+                        "_name": "test"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::intervals(
+                "my_text",
+                IntervalsRule::r#match("hot porridge").max_gaps(10).filter(
+                    IntervalsFilter::not_containing(IntervalsRule::r#match("salty")),
+                ),
+            ),
+            json!({
+                "intervals": {
+                    "my_text": {
+                        "match": {
+                            "query": "hot porridge",
+                            "max_gaps": 10,
+                            "filter": {
+                                "not_containing": {
+                                    "match": {
+                                        "query": "salty"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::intervals("", IntervalsRule::prefix("hot")).should_skip());
+        assert!(Query::intervals("my_text", IntervalsRule::wildcard("")).should_skip());
+        assert!(!Query::intervals("my_text", IntervalsRule::fuzzy("hot")).should_skip());
+    }
+}
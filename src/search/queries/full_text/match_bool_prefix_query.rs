@@ -7,6 +7,9 @@ use serde::ser::{Serialize, SerializeStruct, Serializer};
 /// [`term` query](crate::TermQuery). The last term is used in a
 /// [`prefix` query](crate::PrefixQuery).
 ///
+/// The fuzzy matching parameters apply to the `term` subqueries, which makes the query
+/// tolerate typos in all but the last, still being typed, term.
+///
 /// To create a MatchBoolPrefix query:
 /// ```
 /// # use elasticsearch_dsl::queries::*;
@@ -37,6 +40,21 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     operator: Option<Operator>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fuzziness: Option<Fuzziness>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prefix_length: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_expansions: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fuzzy_transpositions: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fuzzy_rewrite: Option<Rewrite>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<Boost>,
 
@@ -60,6 +78,11 @@ impl Query {
                 analyzer: None,
                 minimum_should_match: None,
                 operator: None,
+                fuzziness: None,
+                prefix_length: None,
+                max_expansions: None,
+                fuzzy_transpositions: None,
+                fuzzy_rewrite: None,
                 boost: None,
                 _name: None,
             },
@@ -95,6 +118,41 @@ impl MatchBoolPrefixQuery {
         self
     }
 
+    /// Maximum edit distance allowed for matching the terms other than the last one.
+    /// See [Fuzziness](Fuzziness) for valid values and more information.
+    pub fn fuzziness(mut self, fuzziness: impl Into<Fuzziness>) -> Self {
+        self.inner.fuzziness = Some(fuzziness.into());
+        self
+    }
+
+    /// Number of beginning characters left unchanged for fuzzy matching.
+    /// Defaults to `0`.
+    pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+        self.inner.prefix_length = Some(prefix_length);
+        self
+    }
+
+    /// Maximum number of terms to which the fuzzy terms will expand.
+    /// Defaults to `50`.
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
+        self.inner.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// If `true`, edits for fuzzy matching include transpositions of two
+    /// adjacent characters (ab → ba). Defaults to `true`.
+    pub fn fuzzy_transpositions(mut self, fuzzy_transpositions: bool) -> Self {
+        self.inner.fuzzy_transpositions = Some(fuzzy_transpositions);
+        self
+    }
+
+    /// Method used to rewrite the fuzzy terms. See the
+    /// [`rewrite` parameter](Rewrite) for valid values and more information.
+    pub fn fuzzy_rewrite(mut self, fuzzy_rewrite: Rewrite) -> Self {
+        self.inner.fuzzy_rewrite = Some(fuzzy_rewrite);
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -140,6 +198,11 @@ mod tests {
                 .analyzer("search_time_analyzer")
                 .minimum_should_match("12")
                 .operator(Operator::Or)
+                .fuzziness(Fuzziness::Auto)
+                .prefix_length(1)
+                .max_expansions(10)
+                .fuzzy_transpositions(false)
+                .fuzzy_rewrite(Rewrite::ConstantScore)
                 .boost(2)
                 .name("test"),
             json!({
@@ -149,6 +212,11 @@ mod tests {
                         "analyzer": "search_time_analyzer",
                         "minimum_should_match": "12",
                         "operator": "OR",
+                        "fuzziness": "AUTO",
+                        "prefix_length": 1,
+                        "max_expansions": 10,
+                        "fuzzy_transpositions": false,
+                        "fuzzy_rewrite": "constant_score",
                         "boost": 2,
                         "_name": "test"
                     }
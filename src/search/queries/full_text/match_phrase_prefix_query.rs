@@ -31,7 +31,7 @@ struct Inner {
     analyzer: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     slop: Option<u8>,
@@ -83,15 +83,15 @@ impl MatchPhrasePrefixQuery {
         self
     }
 
-    /// Maximum number of terms to which the query will expand.
+    /// Maximum number of terms to which the last provided term of the query will expand.
     /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.inner.max_expansions = Some(max_expansions);
         self
     }
 
-    /// The maximum number of intervening unmatched positions, as well as
-    /// whether matches are required to be in-order.
+    /// Maximum number of positions allowed between matching tokens.
+    /// Defaults to `0`. Transposed terms have a slop of `2`.
     pub fn slop(mut self, slop: u8) -> Self {
         self.inner.slop = Some(slop);
         self
@@ -147,7 +147,7 @@ mod tests {
         assert_serialize(
             Query::match_phrase_prefix("test", "search text")
                 .analyzer("search_time_analyzer")
-                .max_expansions(20)
+                .max_expansions(1000)
                 .slop(5)
                 .zero_terms_query(ZeroTermsQuery::None)
                 .boost(2)
@@ -157,7 +157,7 @@ mod tests {
                     "test": {
                         "query": "search text",
                         "analyzer": "search_time_analyzer",
-                        "max_expansions": 20,
+                        "max_expansions": 1000,
                         "slop": 5,
                         "zero_terms_query": "none",
                         "boost": 2,
@@ -17,6 +17,17 @@ use serde::ser::{Serialize, SerializeStruct, Serializer};
 ///     .boost(2)
 ///     .name("test");
 /// ```
+/// To tolerate typos while requiring every term to match:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let query =
+/// Query::r#match("title", "quikc brwn fox")
+///     .fuzziness(Fuzziness::Auto)
+///     .prefix_length(1)
+///     .fuzzy_rewrite(Rewrite::TopTermsBlendedFrequencies(10))
+///     .operator(Operator::And)
+///     .zero_terms_query(ZeroTermsQuery::All);
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query.html>
 #[derive(Debug, Clone, PartialEq)]
 pub struct MatchQuery {
@@ -39,7 +50,7 @@ struct Inner {
     fuzziness: Option<Fuzziness>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     prefix_length: Option<u8>,
@@ -139,8 +150,8 @@ impl MatchQuery {
     }
 
     /// Maximum number of terms to which the query will expand.
-    /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    /// Defaults to `50`, and is capped by the `indices.query.bool.max_clause_count` setting.
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.inner.max_expansions = Some(max_expansions);
         self
     }
@@ -250,7 +261,7 @@ mod tests {
                 .analyzer("search_time_analyzer")
                 .auto_generate_synonyms_phrase_query(true)
                 .fuzziness(23)
-                .max_expansions(2)
+                .max_expansions(500)
                 .prefix_length(3)
                 .fuzzy_transpositions(false)
                 .fuzzy_rewrite(Rewrite::ConstantScoreBoolean)
@@ -267,7 +278,7 @@ mod tests {
                         "analyzer": "search_time_analyzer",
                         "auto_generate_synonyms_phrase_query": true,
                         "fuzziness": 23,
-                        "max_expansions": 2,
+                        "max_expansions": 500,
                         "prefix_length": 3,
                         "fuzzy_transpositions": false,
                         "fuzzy_rewrite": "constant_score_boolean",
@@ -282,4 +293,10 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::r#match("test", "").should_skip());
+        assert!(!Query::r#match("test", "search text").should_skip());
+    }
 }
@@ -88,6 +88,12 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     time_zone: Option<String>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#type: Option<MultiMatchQueryType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tie_breaker: Option<TieBreaker>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<Boost>,
 
@@ -127,6 +133,8 @@ impl Query {
                 quote_analyzer: None,
                 rewrite: None,
                 time_zone: None,
+                r#type: None,
+                tie_breaker: None,
                 boost: None,
                 _name: None,
             },
@@ -237,8 +245,8 @@ impl QueryStringQuery {
 
     /// Maximum edit distance allowed for fuzzy matching. For fuzzy syntax, see
     /// [`Fuzziness`].
-    pub fn fuzziness(mut self, fuzziness: Fuzziness) -> Self {
-        self.inner.fuzziness = Some(fuzziness);
+    pub fn fuzziness(mut self, fuzziness: impl Into<Fuzziness>) -> Self {
+        self.inner.fuzziness = Some(fuzziness.into());
         self
     }
 
@@ -370,6 +378,21 @@ impl QueryStringQuery {
         self
     }
 
+    /// Determines how the query matches and scores documents when searching multiple
+    /// [`fields`](Self::fields), in the same way as the
+    /// [`multi_match` query type](crate::MultiMatchQuery::type).
+    ///
+    /// Defaults to [`MultiMatchQueryType::BestFields`].
+    pub fn r#type(mut self, r#type: MultiMatchQueryType) -> Self {
+        self.inner.r#type = Some(r#type);
+
+        match r#type {
+            MultiMatchQueryType::BestFields(tie_breaker) => self.inner.tie_breaker = tie_breaker,
+            _ => self.inner.tie_breaker = None,
+        }
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -382,6 +405,7 @@ impl ShouldSkip for QueryStringQuery {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryFrom;
 
     #[test]
     fn serialization() {
@@ -407,6 +431,16 @@ mod tests {
                 .lenient(true)
                 .minimum_should_match("22")
                 .quote_field_suffix("s")
+                .default_field("title")
+                .allow_leading_wildcard(false)
+                .fuzziness(Fuzziness::Auto)
+                .phrase_slop(2)
+                .quote_analyzer("quote_analyzer")
+                .time_zone("+01:00")
+                .r#type(MultiMatchQueryType::BestFields(
+                    TieBreaker::try_from(0.3).ok(),
+                ))
+                .rewrite(Rewrite::ConstantScore)
                 .boost(2)
                 .name("test"),
             json!({
@@ -423,10 +457,33 @@ mod tests {
                     "lenient": true,
                     "minimum_should_match": "22",
                     "quote_field_suffix": "s",
+                    "default_field": "title",
+                    "allow_leading_wildcard": false,
+                    "fuzziness": "AUTO",
+                    "phrase_slop": 2,
+                    "quote_analyzer": "quote_analyzer",
+                    "time_zone": "+01:00",
+                    "type": "best_fields",
+                    "tie_breaker": 0.3,
+                    "rewrite": "constant_score",
                     "boost": 2,
                     "_name": "test",
                 }
             }),
         );
+
+        assert_serialize(
+            Query::query_string("search text")
+                .r#type(MultiMatchQueryType::BestFields(
+                    TieBreaker::try_from(0.3).ok(),
+                ))
+                .r#type(MultiMatchQueryType::CrossFields),
+            json!({
+                "query_string": {
+                    "query": "search text",
+                    "type": "cross_fields"
+                }
+            }),
+        );
     }
 }
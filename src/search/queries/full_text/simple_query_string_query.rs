@@ -168,15 +168,28 @@ impl SimpleQueryStringQuery {
         self
     }
 
-    /// List of enabled operators for the simple query string syntax.
+    /// List of enabled operators for the simple query string syntax, repeated flags are
+    /// only sent once.
     ///
     /// Defaults to [ALL](SimpleQueryStringQueryFlags::All) (all operators).
     /// See [Limit operators](SimpleQueryStringQueryFlags) for valid values.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let query =
+    /// Query::simple_query_string("foo | bar + baz*").flags([
+    ///     SimpleQueryStringQueryFlags::Or,
+    ///     SimpleQueryStringQueryFlags::And,
+    ///     SimpleQueryStringQueryFlags::Prefix,
+    /// ]);
+    /// ```
     pub fn flags<I>(mut self, flags: I) -> Self
     where
         I: IntoIterator<Item = SimpleQueryStringQueryFlags>,
     {
-        self.inner.flags.extend(flags.into_iter());
+        self.inner.flags.extend(flags);
+        self.inner.flags.sort_unstable();
+        self.inner.flags.dedup();
         self
     }
 
@@ -304,5 +317,23 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::simple_query_string("search text")
+                .flags([
+                    SimpleQueryStringQueryFlags::Prefix,
+                    SimpleQueryStringQueryFlags::Or,
+                ])
+                .flags([
+                    SimpleQueryStringQueryFlags::Or,
+                    SimpleQueryStringQueryFlags::Whitespace,
+                ]),
+            json!({
+                "simple_query_string": {
+                    "query": "search text",
+                    "flags": "OR|PREFIX|WHITESPACE"
+                }
+            }),
+        );
     }
 }
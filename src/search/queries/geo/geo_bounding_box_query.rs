@@ -112,6 +112,7 @@ mod tests {
             }),
         );
 
+        #[cfg(not(feature = "compact"))]
         assert_serialize(
             Query::geo_bounding_box(
                 "pin.location",
@@ -138,6 +139,34 @@ mod tests {
                     }
                 }
             }),
-        )
+        );
+
+        #[cfg(feature = "compact")]
+        assert_serialize(
+            Query::geo_bounding_box(
+                "pin.location",
+                GeoBoundingBox::Vertices {
+                    top: 40.73,
+                    left: -74.1,
+                    bottom: 40.01,
+                    right: -71.12,
+                },
+            )
+            .validation_method(ValidationMethod::Strict)
+            .name("test_name")
+            .boost(1),
+            json!({
+                "geo_bounding_box": {
+                    "validation_method": "STRICT",
+                    "_name": "test_name",
+                    "pin.location": {
+                        "top": 40.73,
+                        "left": -74.1,
+                        "bottom": 40.01,
+                        "right": -71.12
+                    }
+                }
+            }),
+        );
     }
 }
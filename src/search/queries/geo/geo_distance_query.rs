@@ -6,6 +6,21 @@ use serde::Serialize;
 /// and [geo_shape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
 /// values within a given distance of a geopoint.
 ///
+/// To create a geo distance query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # use elasticsearch_dsl::*;
+/// # fn main() -> Result<(), String> {
+/// # let query =
+/// Query::geo_distance("pin.location", GeoPoint::coordinates(40.12, -71.34), "12km".parse::<Distance>()?)
+///     .distance_type(DistanceType::Plane)
+///     .validation_method(ValidationMethod::IgnoreMalformed)
+///     .ignore_unmapped(true)
+///     .name("nearby");
+/// # Ok(())
+/// # }
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-distance-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GeoDistanceQuery {
@@ -26,6 +41,9 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     validation_method: Option<ValidationMethod>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_unmapped: Option<bool>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<Boost>,
 
@@ -38,7 +56,8 @@ impl Query {
     ///
     /// - `field` - Field you wish to search
     /// - `origin` - GeoPoint to measure distance to
-    /// - `distance` - Distance threshold
+    /// - `distance` - Distance threshold, distance strings such as `"12km"` can be parsed into
+    ///   a [`Distance`]
     pub fn geo_distance(
         field: impl Into<String>,
         origin: impl Into<GeoPoint>,
@@ -50,6 +69,7 @@ impl Query {
                 distance: distance.into(),
                 distance_type: None,
                 validation_method: None,
+                ignore_unmapped: None,
                 boost: None,
                 _name: None,
             },
@@ -72,6 +92,15 @@ impl GeoDistanceQuery {
         self
     }
 
+    /// When set to `true` the `ignore_unmapped` option will ignore an unmapped field and will
+    /// not match any documents for this query. This can be useful when querying multiple indexes
+    /// which might have different mappings. When set to `false` (the default value) the query
+    /// will throw an exception if the field is not mapped.
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -118,6 +147,7 @@ mod tests {
             }),
         );
 
+        #[cfg(not(feature = "compact"))]
         assert_serialize(
             Query::geo_distance(
                 "pin.location",
@@ -129,6 +159,7 @@ mod tests {
             )
             .distance_type(DistanceType::Plane)
             .validation_method(ValidationMethod::Strict)
+            .ignore_unmapped(true)
             .name("test_name")
             .boost(1),
             json!({
@@ -137,10 +168,52 @@ mod tests {
                     "distance_type": "plane",
                     "pin.location": [-71.34, 40.12],
                     "validation_method": "STRICT",
+                    "ignore_unmapped": true,
                     "_name": "test_name",
                     "boost": 1,
                 }
             }),
         );
+
+        #[cfg(feature = "compact")]
+        assert_serialize(
+            Query::geo_distance(
+                "pin.location",
+                GeoPoint::Coordinates {
+                    latitude: 40.12,
+                    longitude: -71.34,
+                },
+                Distance::Kilometers(300),
+            )
+            .distance_type(DistanceType::Plane)
+            .validation_method(ValidationMethod::Strict)
+            .ignore_unmapped(true)
+            .name("test_name")
+            .boost(1),
+            json!({
+                "geo_distance": {
+                    "distance": "300km",
+                    "distance_type": "plane",
+                    "pin.location": [-71.34, 40.12],
+                    "validation_method": "STRICT",
+                    "ignore_unmapped": true,
+                    "_name": "test_name",
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::geo_distance(
+                "pin.location",
+                GeoPoint::coordinates(40.12, -71.34),
+                "12km".parse::<Distance>().unwrap(),
+            ),
+            json!({
+                "geo_distance": {
+                    "distance": "12km",
+                    "pin.location": [-71.34, 40.12],
+                }
+            }),
+        );
     }
 }
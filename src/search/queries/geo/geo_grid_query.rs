@@ -0,0 +1,119 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// Matches `geo_point` and `geo_shape` values that intersect a grid cell from a geo grid
+/// aggregation.
+///
+/// The query is designed to match the documents that fall within a bucket of a geo grid
+/// aggregation, e.g. to drill down into a cell of a map, by providing the key of the bucket.
+///
+/// To create a geo grid query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::geo_grid("location", GeoGrid::geotile("6/32/22"))
+///     .boost(2)
+///     .name("test");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-grid-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeoGridQuery {
+    #[serde(rename = "geo_grid")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    #[serde(flatten)]
+    pair: KeyValuePair<String, GeoGrid>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`GeoGridQuery`]
+    ///
+    /// - `field` - Field you wish to search
+    /// - `grid` - Grid cell the values must intersect
+    pub fn geo_grid<S, T>(field: S, grid: T) -> GeoGridQuery
+    where
+        S: ToString,
+        T: Into<GeoGrid>,
+    {
+        GeoGridQuery {
+            inner: Inner {
+                pair: KeyValuePair::new(field.to_string(), grid.into()),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl GeoGridQuery {
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for GeoGridQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.pair.key.should_skip() || self.inner.pair.value.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::geo_grid("location", GeoGrid::geohash("u0")),
+            json!({
+                "geo_grid": {
+                    "location": {
+                        "geohash": "u0"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::geo_grid("location", GeoGrid::geotile_coordinates(6, 32, 22))
+                .boost(2)
+                .name("test"),
+            json!({
+                "geo_grid": {
+                    "location": {
+                        "geotile": "6/32/22"
+                    },
+                    "boost": 2,
+                    "_name": "test"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::geo_grid("location", GeoGrid::geohex("811fbffffffffff")),
+            json!({
+                "geo_grid": {
+                    "location": {
+                        "geohex": "811fbffffffffff"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::geo_grid("", GeoGrid::geohash("u0")).should_skip());
+        assert!(Query::geo_grid("location", GeoGrid::geotile("")).should_skip());
+        assert!(!Query::geo_grid("location", GeoGrid::geohex("811fbffffffffff")).should_skip());
+    }
+}
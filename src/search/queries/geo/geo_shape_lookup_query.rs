@@ -132,7 +132,7 @@ impl GeoShapeLookupQuery {
     /// mappings. When set to `false` (the default value) the query will throw
     /// an exception if the field is not mapped.
     pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
-        self.inner.ignore_unmapped = Some(ignore_unmapped);
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
         self
     }
 
@@ -11,7 +11,17 @@ use crate::util::*;
 /// # let query =
 /// Query::has_child("child", Query::term("tag", "elasticsearch"));
 /// ```
-/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-HasChild-query.html>
+/// To return parents with a bounded number of matching children, along with those children:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let query =
+/// Query::has_child("answer", Query::r#match("body", "rust"))
+///     .min_children(2u32)
+///     .max_children(10u32)
+///     .score_mode(HasChildScoreMode::Sum)
+///     .inner_hits(InnerHits::new().size(3));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-has-child-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct HasChildQuery {
     #[serde(rename = "has_child")]
@@ -36,6 +46,9 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     score_mode: Option<HasChildScoreMode>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<Box<InnerHits>>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<Boost>,
 
@@ -58,6 +71,7 @@ impl Query {
                 max_children: None,
                 min_children: None,
                 score_mode: None,
+                inner_hits: None,
                 boost: None,
                 _name: None,
             },
@@ -73,7 +87,7 @@ impl HasChildQuery {
     ///
     /// You can use this parameter to query multiple indices that may not contain the `type`.
     pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
-        self.inner.ignore_unmapped = Some(ignore_unmapped);
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
         self
     }
 
@@ -99,7 +113,32 @@ impl HasChildQuery {
         self
     }
 
+    /// Returns the child documents that caused each hit to match, using the same options as
+    /// [`NestedQuery::inner_hits`].
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html>
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner.inner_hits = Some(Box::new(inner_hits));
+        self
+    }
+
     add_boost_and_name!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner
+            .inner_hits
+            .iter()
+            .flat_map(|inner_hits| inner_hits.embedded_queries())
+            .collect()
+    }
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.query.as_mut()]
+    }
 }
 
 impl ShouldSkip for HasChildQuery {
@@ -137,7 +176,8 @@ mod tests {
                 .ignore_unmapped(true)
                 .max_children(3u32)
                 .min_children(2u32)
-                .score_mode(HasChildScoreMode::Max),
+                .score_mode(HasChildScoreMode::Max)
+                .inner_hits(InnerHits::new().name("children").size(2)),
             json!({
                 "has_child": {
                     "type": "child",
@@ -152,10 +192,21 @@ mod tests {
                             }
                         }
                     },
+                    "inner_hits": {
+                        "name": "children",
+                        "size": 2
+                    },
                     "boost": 2,
                     "_name": "test"
                 }
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::has_child("", Query::term("tag", "elasticsearch")).should_skip());
+        assert!(Query::has_child("child", Query::term("tag", "")).should_skip());
+        assert!(!Query::has_child("child", Query::term("tag", "elasticsearch")).should_skip());
+    }
 }
@@ -11,7 +11,7 @@ use crate::util::*;
 /// # let query =
 /// Query::has_parent("parent", Query::term("tag", "elasticsearch"));
 /// ```
-/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-HasParent-query.html>
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-has-parent-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct HasParentQuery {
     #[serde(rename = "has_parent")]
@@ -30,6 +30,9 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     ignore_unmapped: Option<bool>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<Box<InnerHits>>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<Boost>,
 
@@ -40,7 +43,7 @@ struct Inner {
 impl Query {
     /// Creates an instance of [`HasParentQuery`]
     ///
-    /// - `parent-type` - Name of the parent relationship mapped for the join field.
+    /// - `parent_type` - Name of the parent relationship mapped for the join field.
     /// - `query` - Query you wish to run on parent documents of the `parent_type` field. If a
     /// parent document matches the search, the query returns its child documents.
     pub fn has_parent(parent_type: impl ToString, query: impl Into<Query>) -> HasParentQuery {
@@ -50,6 +53,7 @@ impl Query {
                 query: Box::new(query.into()),
                 score: None,
                 ignore_unmapped: None,
+                inner_hits: None,
                 boost: None,
                 _name: None,
             },
@@ -68,7 +72,7 @@ impl HasParentQuery {
     /// If `true`, the relevance score of the matching parent document is aggregated into its child
     /// documents' relevance scores.
     pub fn score(mut self, score: bool) -> Self {
-        self.inner.score = Some(score);
+        self.inner.score = non_default(score, false);
         self
     }
 
@@ -79,11 +83,36 @@ impl HasParentQuery {
     ///
     /// You can use this parameter to query multiple indices that may not contain the `parent_type`.
     pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
-        self.inner.ignore_unmapped = Some(ignore_unmapped);
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
+        self
+    }
+
+    /// Returns the parent documents that caused each hit to match, using the same options as
+    /// [`NestedQuery::inner_hits`].
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html>
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner.inner_hits = Some(Box::new(inner_hits));
         self
     }
 
     add_boost_and_name!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner
+            .inner_hits
+            .iter()
+            .flat_map(|inner_hits| inner_hits.embedded_queries())
+            .collect()
+    }
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.query.as_mut()]
+    }
 }
 
 impl ShouldSkip for HasParentQuery {
@@ -119,7 +148,8 @@ mod tests {
                 .boost(2)
                 .name("test")
                 .ignore_unmapped(true)
-                .score(true),
+                .score(true)
+                .inner_hits(InnerHits::new().name("parents").size(1)),
             json!({
                 "has_parent": {
                     "parent_type": "parent",
@@ -132,10 +162,21 @@ mod tests {
                             }
                         }
                     },
+                    "inner_hits": {
+                        "name": "parents",
+                        "size": 1
+                    },
                     "boost": 2,
                     "_name": "test"
                 }
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::has_parent("", Query::term("tag", "elasticsearch")).should_skip());
+        assert!(Query::has_parent("parent", Query::term("tag", "")).should_skip());
+        assert!(!Query::has_parent("parent", Query::term("tag", "elasticsearch")).should_skip());
+    }
 }
@@ -102,7 +102,7 @@ impl NestedQuery {
     /// You can use this parameter to query multiple indices that may not
     /// contain the field `path`.
     pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
-        self.inner.ignore_unmapped = Some(ignore_unmapped);
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
         self
     }
 
@@ -130,6 +130,22 @@ impl NestedQuery {
     }
 
     add_boost_and_name!();
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner
+            .inner_hits
+            .iter()
+            .flat_map(|inner_hits| inner_hits.embedded_queries())
+            .collect()
+    }
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.query.as_mut()]
+    }
 }
 
 impl ShouldSkip for NestedQuery {
@@ -207,4 +223,66 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_default_values() {
+        let query = Query::nested("vehicles", Query::term("vehicles.license", "ABC123"))
+            .ignore_unmapped(false)
+            .boost(1);
+
+        #[cfg(not(feature = "compact"))]
+        assert_serialize(
+            query,
+            json!({
+                "nested": {
+                    "path": "vehicles",
+                    "query": { "term": { "vehicles.license": { "value": "ABC123" } } },
+                    "ignore_unmapped": false,
+                    "boost": 1
+                }
+            }),
+        );
+
+        #[cfg(feature = "compact")]
+        assert_serialize(
+            query,
+            json!({
+                "nested": {
+                    "path": "vehicles",
+                    "query": { "term": { "vehicles.license": { "value": "ABC123" } } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_inner_hits() {
+        assert_serialize(
+            Query::nested("comments", Query::r#match("comments.text", "rust")).inner_hits(
+                InnerHits::new()
+                    .name("matching_comments")
+                    .from(1)
+                    .size(2)
+                    .sort(Sort::new("comments.date").order(SortOrder::Desc))
+                    .source(["comments.author"])
+                    .highlight(Highlight::new().field("comments.text"))
+                    .docvalue_fields(["comments.date"]),
+            ),
+            json!({
+                "nested": {
+                    "path": "comments",
+                    "query": { "match": { "comments.text": { "query": "rust" } } },
+                    "inner_hits": {
+                        "name": "matching_comments",
+                        "from": 1,
+                        "size": 2,
+                        "sort": [{ "comments.date": { "order": "desc" } }],
+                        "_source": ["comments.author"],
+                        "highlight": { "fields": [{ "comments.text": {} }] },
+                        "docvalue_fields": ["comments.date"]
+                    }
+                }
+            }),
+        );
+    }
 }
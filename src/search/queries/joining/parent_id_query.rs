@@ -11,7 +11,7 @@ use crate::util::*;
 /// # let query =
 /// Query::parent_id("test", 1);
 /// ```
-/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-ParentId-query.html>
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-parent-id-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ParentIdQuery {
     #[serde(rename = "parent_id")]
@@ -61,7 +61,7 @@ impl ParentIdQuery {
     ///
     /// You can use this parameter to query multiple indices that may not contain the `type`.
     pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
-        self.inner.ignore_unmapped = Some(ignore_unmapped);
+        self.inner.ignore_unmapped = non_default(ignore_unmapped, false);
         self
     }
 
@@ -106,4 +106,28 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn nests_in_compound_queries() {
+        assert_serialize(
+            Query::bool()
+                .filter(Query::parent_id("answer", "question-1"))
+                .must(Query::term("status", "accepted")),
+            json!({
+                "bool": {
+                    "must": [
+                        { "term": { "status": { "value": "accepted" } } }
+                    ],
+                    "filter": [
+                        { "parent_id": { "type": "answer", "id": "question-1" } }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::bool().filter(Query::parent_id("answer", "")),
+            json!({ "bool": {} }),
+        );
+    }
 }
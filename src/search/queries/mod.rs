@@ -42,12 +42,44 @@ mod match_none_query;
 pub use self::match_all_query::*;
 pub use self::match_none_query::*;
 
+// Query tree inspection
+mod complexity;
+mod normalize;
+mod visitor;
+
+pub use self::complexity::*;
+pub use self::visitor::*;
+
 use crate::util::*;
 
+macro_rules! query_partial_eq {
+    // Annotations are transparent, see `custom/annotated_query.rs`
+    (Annotated($query:ty)) => {};
+    ($variant:ident($query:ty)) => {
+        impl PartialEq<$query> for Query {
+            fn eq(&self, other: &$query) -> bool {
+                match self.unannotated() {
+                    Self::$variant(query) => query.eq(other),
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Query> for $query {
+            fn eq(&self, other: &Query) -> bool {
+                match other.unannotated() {
+                    Query::$variant(query) => self.eq(query),
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
 macro_rules! query {
     ($($variant:ident($query:ty)),+ $(,)?) => {
         /// A container enum for supported Elasticsearch query types
-        #[derive(Clone, PartialEq, Serialize)]
+        #[derive(Clone, Serialize)]
         #[serde(untagged)]
         #[allow(missing_docs)]
         pub enum Query {
@@ -89,6 +121,17 @@ macro_rules! query {
             }
         }
 
+        impl PartialEq for Query {
+            fn eq(&self, other: &Self) -> bool {
+                match (self.unannotated(), other.unannotated()) {
+                    $(
+                        (Self::$variant(a), Self::$variant(b)) => a.eq(b),
+                    )+
+                    _ => false,
+                }
+            }
+        }
+
         $(
             impl From<$query> for Query {
                 fn from(q: $query) -> Self {
@@ -96,23 +139,7 @@ macro_rules! query {
                 }
             }
 
-            impl PartialEq<$query> for Query {
-                fn eq(&self, other: &$query) -> bool {
-                    match self {
-                        Self::$variant(query) => query.eq(other),
-                        _ => false,
-                    }
-                }
-            }
-
-            impl PartialEq<Query> for $query {
-                fn eq(&self, other: &Query) -> bool {
-                    match other {
-                        Query::$variant(query) => self.eq(query),
-                        _ => false,
-                    }
-                }
-            }
+            query_partial_eq!($variant($query));
 
             impl From<$query> for Option<Query> {
                 fn from(q: $query) -> Self {
@@ -152,17 +179,24 @@ query!(
     ConstantScore(ConstantScoreQuery),
     DistanceFeatureDate(DistanceFeatureQuery<chrono::DateTime<chrono::Utc>>),
     DistanceFeatureGeo(DistanceFeatureQuery<crate::GeoPoint>),
+    DistanceFeatureDateMath(DistanceFeatureQuery<crate::DateMath>),
     Match(MatchQuery),
     MatchBoolPrefix(MatchBoolPrefixQuery),
     MatchPhrasePrefix(MatchPhrasePrefixQuery),
     MatchAll(MatchAllQuery),
     MatchNone(MatchNoneQuery),
     MatchPhrase(MatchPhraseQuery),
+    Intervals(IntervalsQuery),
     MultiMatch(MultiMatchQuery),
+    CombinedFields(CombinedFieldsQuery),
     Nested(NestedQuery),
+    HasChild(HasChildQuery),
+    HasParent(HasParentQuery),
+    ParentId(ParentIdQuery),
     Boosting(BoostingQuery),
     DisMax(DisMaxQuery),
     Pinned(PinnedQuery),
+    Rule(RuleQuery),
     Percolate(PercolateQuery),
     PercolateLookup(PercolateLookupQuery),
     FunctionScore(FunctionScoreQuery),
@@ -175,14 +209,29 @@ query!(
     Fuzzy(FuzzyQuery),
     GeoDistance(GeoDistanceQuery),
     GeoBoundingBox(GeoBoundingBoxQuery),
+    GeoGrid(GeoGridQuery),
     GeoShapeLookup(GeoShapeLookupQuery),
     GeoShape(GeoShapeQuery),
     ShapeLookup(ShapeLookupQuery),
     Shape(ShapeQuery),
     Json(JsonQuery),
+    Annotated(AnnotatedQuery),
     Wrapper(WrapperQuery),
+    Knn(KnnQuery),
+    TextExpansion(TextExpansionQuery),
+    SparseVector(SparseVectorQuery),
+    WeightedTokens(WeightedTokensQuery),
     Script(ScriptQuery),
     ScriptScore(ScriptScoreQuery),
+    SpanContaining(SpanContainingQuery),
+    SpanFieldMasking(SpanFieldMaskingQuery),
+    SpanFirst(SpanFirstQuery),
+    SpanMulti(SpanMultiQuery),
+    SpanNear(SpanNearQuery),
+    SpanNot(SpanNotQuery),
+    SpanOr(SpanOrQuery),
+    SpanTerm(SpanTermQuery),
+    SpanWithin(SpanWithinQuery),
 );
 
 /// A collection of queries
@@ -225,6 +274,113 @@ impl Queries {
     {
         self.0.extend(queries.into().0)
     }
+
+    pub(crate) fn iter(&self) -> std::slice::Iter<'_, Query> {
+        self.0.iter()
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> std::slice::IterMut<'_, Query> {
+        self.0.iter_mut()
+    }
+}
+
+impl Query {
+    /// Queries directly wrapped by a compound query, empty for leaf queries
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        match self {
+            Self::Annotated(query) => query.children(),
+            Self::Bool(query) => query.children(),
+            Self::Boosting(query) => query.children(),
+            Self::ConstantScore(query) => query.children(),
+            Self::DisMax(query) => query.children(),
+            Self::FunctionScore(query) => query.children(),
+            Self::HasChild(query) => query.children(),
+            Self::HasParent(query) => query.children(),
+            Self::Knn(query) => query.children(),
+            Self::Nested(query) => query.children(),
+            Self::Pinned(query) => query.children(),
+            Self::Rule(query) => query.children(),
+            Self::ScriptScore(query) => query.children(),
+            _ => vec![],
+        }
+    }
+
+    /// Queries directly wrapped by a compound query, empty for leaf queries
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        match self {
+            Self::Annotated(query) => query.children_mut(),
+            Self::Bool(query) => query.children_mut(),
+            Self::Boosting(query) => query.children_mut(),
+            Self::ConstantScore(query) => query.children_mut(),
+            Self::DisMax(query) => query.children_mut(),
+            Self::FunctionScore(query) => query.children_mut(),
+            Self::HasChild(query) => query.children_mut(),
+            Self::HasParent(query) => query.children_mut(),
+            Self::Knn(query) => query.children_mut(),
+            Self::Nested(query) => query.children_mut(),
+            Self::Pinned(query) => query.children_mut(),
+            Self::Rule(query) => query.children_mut(),
+            Self::ScriptScore(query) => query.children_mut(),
+            _ => vec![],
+        }
+    }
+
+    /// Clauses of a compound span query, converted into queries, empty for other queries
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        match self {
+            Self::SpanContaining(query) => query.span_clauses(),
+            Self::SpanFieldMasking(query) => query.span_clauses(),
+            Self::SpanFirst(query) => query.span_clauses(),
+            Self::SpanMulti(query) => query.span_clauses(),
+            Self::SpanNear(query) => query.span_clauses(),
+            Self::SpanNot(query) => query.span_clauses(),
+            Self::SpanOr(query) => query.span_clauses(),
+            Self::SpanWithin(query) => query.span_clauses(),
+            _ => vec![],
+        }
+    }
+
+    /// Clauses of a compound span query, empty for other queries
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        match self {
+            Self::SpanContaining(query) => query.span_clauses_mut(),
+            Self::SpanFieldMasking(query) => query.span_clauses_mut(),
+            Self::SpanFirst(query) => query.span_clauses_mut(),
+            Self::SpanMulti(query) => query.span_clauses_mut(),
+            Self::SpanNear(query) => query.span_clauses_mut(),
+            Self::SpanNot(query) => query.span_clauses_mut(),
+            Self::SpanOr(query) => query.span_clauses_mut(),
+            Self::SpanWithin(query) => query.span_clauses_mut(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether the query wraps other queries, [annotations](AnnotatedQuery) are ignored
+    pub fn is_compound(&self) -> bool {
+        matches!(
+            self.unannotated(),
+            Self::Bool(_)
+                | Self::Boosting(_)
+                | Self::ConstantScore(_)
+                | Self::DisMax(_)
+                | Self::FunctionScore(_)
+                | Self::HasChild(_)
+                | Self::HasParent(_)
+                | Self::Knn(_)
+                | Self::Nested(_)
+                | Self::Pinned(_)
+                | Self::Rule(_)
+                | Self::ScriptScore(_)
+                | Self::SpanContaining(_)
+                | Self::SpanFieldMasking(_)
+                | Self::SpanFirst(_)
+                | Self::SpanMulti(_)
+                | Self::SpanNear(_)
+                | Self::SpanNot(_)
+                | Self::SpanOr(_)
+                | Self::SpanWithin(_)
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,116 @@
+use crate::search::*;
+
+impl Query {
+    /// Simplifies the query tree without changing which documents match or how they are scored,
+    /// producing smaller and cache-friendlier request bodies:
+    ///
+    /// - clauses that would be skipped during serialization, such as empty `bool` queries, are
+    ///   removed
+    /// - `bool` queries wrapping a single `must` or `should` clause are replaced by that clause
+    /// - in filter context, `bool` queries wrapping a single `filter` clause and unnamed
+    ///   `constant_score` queries are replaced by the query they wrap
+    /// - duplicate `filter` and `must_not` clauses are merged
+    ///
+    /// Queries carrying a `boost`, a `_name` or `minimum_should_match` are never unwrapped.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let query = Query::from(
+    ///     Query::bool()
+    ///         .must(Query::bool().must(Query::term("user", "kimchy")))
+    ///         .filter(Query::constant_score(Query::term("tag", "tech")))
+    ///         .filter(Query::term("tag", "tech"))
+    ///         .must_not(Query::bool()),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     query.normalize(),
+    ///     Query::from(
+    ///         Query::bool()
+    ///             .must(Query::term("user", "kimchy"))
+    ///             .filter(Query::term("tag", "tech"))
+    ///     )
+    /// );
+    /// ```
+    pub fn normalize(mut self) -> Self {
+        for child in self.children_mut() {
+            let query = std::mem::replace(child, Query::match_all().into());
+            *child = query.normalize();
+        }
+
+        match self {
+            Self::Bool(query) => query.normalize(),
+            query => query,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn normalizes_nested_queries() {
+        assert_serialize(
+            Query::from(Query::nested(
+                "comments",
+                Query::bool().should(Query::bool().should(Query::term("comments.user", "kimchy"))),
+            ))
+            .normalize(),
+            json!({
+                "nested": {
+                    "path": "comments",
+                    "query": { "term": { "comments.user": { "value": "kimchy" } } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn keeps_scoring_semantics() {
+        let query = Query::from(
+            Query::bool()
+                .must(Query::bool().filter(Query::term("user", "kimchy")))
+                .must(Query::constant_score(Query::term("tag", "tech")).boost(2))
+                .should([Query::term("tag", "rust"), Query::term("tag", "rust")]),
+        );
+
+        assert_eq!(query.clone().normalize(), query);
+
+        let query = Query::from(Query::bool().must(Query::term("user", "kimchy")).boost(2));
+
+        assert_eq!(query.clone().normalize(), query);
+    }
+
+    #[test]
+    fn simplifies_filter_context() {
+        assert_serialize(
+            Query::from(
+                Query::bool()
+                    .filter(Query::bool().filter(Query::constant_score(Query::term("a", 1))))
+                    .filter(Query::term("a", 1))
+                    .filter(Query::constant_score(Query::term("b", 2)).name("b"))
+                    .must_not([Query::term("c", 3), Query::term("c", 3)])
+                    .must_not(Query::bool().must(Query::bool())),
+            )
+            .normalize(),
+            json!({
+                "bool": {
+                    "filter": [
+                        { "term": { "a": { "value": 1 } } },
+                        {
+                            "constant_score": {
+                                "filter": { "term": { "b": { "value": 2 } } },
+                                "_name": "b"
+                            }
+                        }
+                    ],
+                    "must_not": [
+                        { "term": { "c": { "value": 3 } } }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,40 @@
+/// Occurrence type of a [`BoolQuery`](crate::BoolQuery) clause, deciding whether and how the
+/// clause contributes to matching and scoring
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Occurrence {
+    /// The clause must appear in matching documents and contributes to the score
+    Must,
+
+    /// The clause must appear in matching documents, executed in filter context without
+    /// scoring
+    Filter,
+
+    /// The clause should appear in matching documents
+    Should,
+
+    /// The clause must not appear in matching documents, executed in filter context without
+    /// scoring
+    MustNot,
+}
+
+impl Occurrence {
+    /// All occurrence types, in the order their clauses are serialized
+    pub const ALL: [Self; 4] = [Self::Must, Self::Filter, Self::Should, Self::MustNot];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Occurrence::ALL,
+            json!(["must", "filter", "should", "must_not"]),
+        );
+    }
+}
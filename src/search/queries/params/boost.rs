@@ -1,13 +1,64 @@
 //! A container type for boost values
 
-use std::{cmp::Ordering, convert::TryFrom, fmt};
-
-const ERROR_MSG: &str = "Boost value cannot be negative";
-
-/// A container type for boost values
+use std::{
+    cmp::Ordering,
+    convert::{Infallible, TryFrom, TryInto},
+    fmt,
+};
+
+/// A container type for boost values, which must be finite and non-negative
+///
+/// Every `.boost()` builder accepts integers, `f32` and `f64` values and ignores invalid ones,
+/// while [`Boost::new`] reports why a value was rejected:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// assert!(Boost::new(2).is_ok());
+/// assert!(Boost::new(1.5_f32).is_ok());
+/// assert_eq!(Boost::new(-1), Err(BoostError::Negative));
+/// assert_eq!(Boost::new(f64::NAN), Err(BoostError::NotFinite));
+/// ```
 #[derive(Clone, Copy, PartialEq, PartialOrd, Serialize)]
 pub struct Boost(Inner);
 
+/// Reasons for a value to be rejected as a [`Boost`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoostError {
+    /// Boost value is below zero
+    Negative,
+
+    /// Boost value is `NaN` or infinite
+    NotFinite,
+}
+
+impl fmt::Display for BoostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Negative => "Boost value cannot be negative".fmt(f),
+            Self::NotFinite => "Boost value must be finite".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BoostError {}
+
+impl From<Infallible> for BoostError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl Boost {
+    /// Creates an instance of [`Boost`] from any integer or floating point number, rejecting
+    /// negative, `NaN` and infinite values
+    pub fn new<T>(value: T) -> Result<Self, BoostError>
+    where
+        T: TryInto<Self>,
+        BoostError: From<T::Error>,
+    {
+        Ok(value.try_into()?)
+    }
+}
+
 impl fmt::Debug for Boost {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0 {
@@ -71,11 +122,11 @@ impl PartialOrd for Inner {
 // i8
 
 impl TryFrom<i8> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: i8) -> Result<Self, Self::Error> {
         if value < 0 {
-            Err(ERROR_MSG)
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::U64(value as u64)))
         }
@@ -111,11 +162,11 @@ impl PartialOrd<i8> for Boost {
 // i16
 
 impl TryFrom<i16> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: i16) -> Result<Self, Self::Error> {
         if value < 0 {
-            Err(ERROR_MSG)
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::U64(value as u64)))
         }
@@ -151,11 +202,11 @@ impl PartialOrd<i16> for Boost {
 // i32
 
 impl TryFrom<i32> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {
         if value < 0 {
-            Err(ERROR_MSG)
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::U64(value as u64)))
         }
@@ -191,11 +242,11 @@ impl PartialOrd<i32> for Boost {
 // i64
 
 impl TryFrom<i64> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: i64) -> Result<Self, Self::Error> {
         if value < 0 {
-            Err(ERROR_MSG)
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::U64(value as u64)))
         }
@@ -343,11 +394,13 @@ impl PartialOrd<u64> for Boost {
 // f32
 
 impl TryFrom<f32> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        if value < 0. {
-            Err(ERROR_MSG)
+        if !value.is_finite() {
+            Err(BoostError::NotFinite)
+        } else if value < 0. {
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::F32(value)))
         }
@@ -377,11 +430,13 @@ impl PartialOrd<f32> for Boost {
 // f64
 
 impl TryFrom<f64> for Boost {
-    type Error = &'static str;
+    type Error = BoostError;
 
     fn try_from(value: f64) -> Result<Self, Self::Error> {
-        if value < 0. {
-            Err(ERROR_MSG)
+        if !value.is_finite() {
+            Err(BoostError::NotFinite)
+        } else if value < 0. {
+            Err(BoostError::Negative)
         } else {
             Ok(Self(Inner::F64(value)))
         }
@@ -420,6 +475,9 @@ mod tests {
         assert!(Boost::try_from(-1_i64).is_err());
         assert!(Boost::try_from(-1_f32).is_err());
         assert!(Boost::try_from(-1_f64).is_err());
+        assert_eq!(Boost::try_from(f32::NAN), Err(BoostError::NotFinite));
+        assert_eq!(Boost::try_from(f64::INFINITY), Err(BoostError::NotFinite));
+        assert_eq!(Boost::try_from(-0.5_f64), Err(BoostError::Negative));
     }
 
     #[test]
@@ -108,6 +108,8 @@ function!(Function {
     DecayU16(Decay<u16>),
     DecayU32(Decay<u32>),
     DecayU64(Decay<u64>),
+    DecayF32(Decay<f32>),
+    DecayF64(Decay<f64>),
     Script(Script),
 });
 
@@ -390,6 +392,7 @@ pub struct Decay<T: Origin> {
 struct DecayFieldInner<T: Origin> {
     field: String,
     inner: DecayInner<T>,
+    multi_value_mode: Option<MultiValueMode>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -418,6 +421,15 @@ impl<T: Origin> Decay<T> {
     /// computed score will equal `decay` parameter. For geo fields: Can be defined as number+unit
     /// (1km, 12m,…​). Default unit is meters. For date fields: Can to be defined as a number+unit
     /// ("1h", "10d",…​). Default unit is milliseconds. For numeric field: Any number.
+    ///
+    /// Date scales and offsets can be converted from `chrono` and `std` durations:
+    /// ```
+    /// # use elasticsearch_dsl::queries::params::*;
+    /// # use chrono::prelude::*;
+    /// # let decay =
+    /// Decay::gauss("published_at", Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(), chrono::Duration::days(30).into())
+    ///     .offset(chrono::Duration::hours(12).into());
+    /// ```
     pub fn new(
         function: DecayFunction,
         field: impl Into<String>,
@@ -434,10 +446,29 @@ impl<T: Origin> Decay<T> {
                     offset: None,
                     decay: None,
                 },
+                multi_value_mode: None,
             },
         }
     }
 
+    /// Creates an instance of [Decay](Decay) with a [gauss](DecayFunction::Gauss) decay, see
+    /// [`Decay::new`]
+    pub fn gauss(field: impl Into<String>, origin: T, scale: <T as Origin>::Scale) -> Self {
+        Self::new(DecayFunction::Gauss, field, origin, scale)
+    }
+
+    /// Creates an instance of [Decay](Decay) with an [exponential](DecayFunction::Exp) decay, see
+    /// [`Decay::new`]
+    pub fn exp(field: impl Into<String>, origin: T, scale: <T as Origin>::Scale) -> Self {
+        Self::new(DecayFunction::Exp, field, origin, scale)
+    }
+
+    /// Creates an instance of [Decay](Decay) with a [linear](DecayFunction::Linear) decay, see
+    /// [`Decay::new`]
+    pub fn linear(field: impl Into<String>, origin: T, scale: <T as Origin>::Scale) -> Self {
+        Self::new(DecayFunction::Linear, field, origin, scale)
+    }
+
     /// If an `offset` is defined, the decay function will only compute the decay function for
     /// documents with a distance greater than the defined `offset`.
     ///
@@ -449,8 +480,21 @@ impl<T: Origin> Decay<T> {
 
     /// The `decay` parameter defines how documents are scored at the distance given at `scale`. If
     /// no `decay` is defined, documents at the distance `scale` will be scored `0.5`.
+    ///
+    /// Values outside of the exclusive range between `0` and `1` are ignored.
     pub fn decay(mut self, decay: f32) -> Self {
-        self.inner.inner.decay = Some(decay);
+        if decay > 0. && decay < 1. {
+            self.inner.inner.decay = Some(decay);
+        }
+        self
+    }
+
+    /// If the field used for computing the decay contains multiple values, the distance is
+    /// computed from the values combined according to `multi_value_mode`.
+    ///
+    /// Defaults to [min](MultiValueMode::Min).
+    pub fn multi_value_mode(mut self, multi_value_mode: MultiValueMode) -> Self {
+        self.inner.multi_value_mode = Some(multi_value_mode);
         self
     }
 }
@@ -473,10 +517,14 @@ impl<T: Origin> Serialize for DecayFieldInner<T> {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(1))?;
+        let mut map = serializer.serialize_map(None)?;
 
         map.serialize_entry(&self.field, &self.inner)?;
 
+        if let Some(multi_value_mode) = &self.multi_value_mode {
+            map.serialize_entry("multi_value_mode", multi_value_mode)?;
+        }
+
         map.end()
     }
 }
@@ -497,6 +545,25 @@ pub enum DecayFunction {
     Gauss,
 }
 
+/// Defines how the distance of a multi-valued field is computed by a [Decay](Decay) function
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html#_detailed_example>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MultiValueMode {
+    /// Distance is the minimum distance
+    Min,
+
+    /// Distance is the maximum distance
+    Max,
+
+    /// Distance is the average distance
+    Avg,
+
+    /// Distance is the sum of all distances
+    Sum,
+}
+
 /// The script_score function allows you to wrap another query and customize the scoring of it
 /// optionally with a computation derived from other numeric field values in the doc using a script
 /// expression
@@ -550,7 +617,7 @@ mod tests {
             Decay::new(
                 DecayFunction::Gauss,
                 "test",
-                Utc.ymd(2014, 7, 8).and_hms(9, 1, 0),
+                Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(),
                 Time::Days(7),
             ),
             json!({
@@ -563,6 +630,24 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Decay::linear(
+                "test",
+                Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(),
+                chrono::Duration::weeks(2).into(),
+            )
+            .offset(chrono::Duration::minutes(90).into()),
+            json!({
+                "linear": {
+                    "test": {
+                        "origin": "2014-07-08T09:01:00Z",
+                        "scale": "14d",
+                        "offset": "90m",
+                    }
+                }
+            }),
+        );
+
         assert_serialize(
             Decay::new(
                 DecayFunction::Exp,
@@ -591,5 +676,35 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Decay::gauss("price", 25.5, 10.0)
+                .offset(2.5)
+                .decay(0.3)
+                .multi_value_mode(MultiValueMode::Avg),
+            json!({
+                "gauss": {
+                    "price": {
+                        "origin": 25.5,
+                        "scale": 10.0,
+                        "offset": 2.5,
+                        "decay": 0.3
+                    },
+                    "multi_value_mode": "avg"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Decay::exp("rating", 5_u8, 2).decay(1.5),
+            json!({
+                "exp": {
+                    "rating": {
+                        "origin": 5,
+                        "scale": 2
+                    }
+                }
+            }),
+        );
     }
 }
@@ -1,4 +1,5 @@
 use crate::search::*;
+use crate::util::*;
 use serde::Serialize;
 
 /// Strategies to verify the correctness of coordinates
@@ -67,10 +68,65 @@ pub enum DistanceType {
     Plane,
 }
 
+/// Cell of a geo grid, as returned in the bucket keys of the geo grid aggregations
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoGrid {
+    /// [Geohash](https://en.wikipedia.org/wiki/Geohash) cell, e.g. `u0`, as returned by the
+    /// [`GeohashGridAggregation`](crate::GeohashGridAggregation)
+    Geohash(String),
+
+    /// Map tile in the `{zoom}/{x}/{y}` format, e.g. `6/32/22`, as returned by the
+    /// [`GeotileGridAggregation`](crate::GeotileGridAggregation)
+    Geotile(String),
+
+    /// [H3](https://h3geo.org/docs/) cell, e.g. `811fbffffffffff`, as returned by the
+    /// [`GeohexGridAggregation`](crate::GeohexGridAggregation)
+    Geohex(String),
+}
+
+impl GeoGrid {
+    /// Creates a geohash cell
+    pub fn geohash<T>(geohash: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geohash(geohash.to_string())
+    }
+
+    /// Creates a map tile from its `{zoom}/{x}/{y}` key
+    pub fn geotile<T>(geotile: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geotile(geotile.to_string())
+    }
+
+    /// Creates an H3 cell
+    pub fn geohex<T>(geohex: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geohex(geohex.to_string())
+    }
+
+    /// Creates a map tile from its zoom level and coordinates
+    pub fn geotile_coordinates(zoom: u8, x: u32, y: u32) -> Self {
+        Self::Geotile(format!("{}/{}/{}", zoom, x, y))
+    }
+}
+
+impl ShouldSkip for GeoGrid {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Geohash(key) | Self::Geotile(key) | Self::Geohex(key) => key.should_skip(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::util::*;
 
     #[test]
     fn serialization() {
@@ -130,5 +186,18 @@ mod tests {
             [DistanceType::Arc, DistanceType::Plane],
             json!(["arc", "plane"]),
         );
+
+        assert_serialize(
+            [
+                GeoGrid::geohash("u0"),
+                GeoGrid::geotile_coordinates(6, 32, 22),
+                GeoGrid::geohex("811fbffffffffff"),
+            ],
+            json!([
+                { "geohash": "u0" },
+                { "geotile": "6/32/22" },
+                { "geohex": "811fbffffffffff" }
+            ]),
+        );
     }
 }
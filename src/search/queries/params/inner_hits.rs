@@ -17,11 +17,25 @@ use std::convert::TryInto;
 /// search hit to match in a different scope.
 ///
 /// Inner hits can be used by defining an `inner_hits` definition on a `nested`, `has_child`
-/// or `has_parent` query and filter.
+/// or `has_parent` query and filter, as well as on a [`Collapse`] definition to expand each
+/// collapsed group.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let inner_hits =
+/// InnerHits::new()
+///     .name("comments")
+///     .size(3)
+///     .sort(Sort::new("date").order(SortOrder::Desc))
+///     .docvalue_fields(["comments.author"]);
+/// ```
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html>
 #[derive(Debug, Default, Clone, PartialEq, Serialize)]
 pub struct InnerHits {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    name: Option<String>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     _source: Option<SourceFilter>,
 
@@ -36,6 +50,12 @@ pub struct InnerHits {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight: Option<Highlight>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    docvalue_fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collapse: Option<Box<Collapse>>,
 }
 
 impl InnerHits {
@@ -44,6 +64,15 @@ impl InnerHits {
         Default::default()
     }
 
+    /// The name to be used for the particular inner hit definition in the response. Useful when
+    /// multiple inner hits have been defined in a single search request.
+    ///
+    /// Defaults to the query's `path`, `type` or `parent_type`.
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
     /// Indicates which source fields are returned for matching documents
     pub fn source(mut self, source: impl Into<SourceFilter>) -> Self {
         self._source = Some(source.into());
@@ -81,4 +110,71 @@ impl InnerHits {
         self.highlight = Some(highlight.into());
         self
     }
+
+    /// Returns the doc value representation of a field for each hit
+    pub fn docvalue_fields<I>(mut self, docvalue_fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.docvalue_fields
+            .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Collapses inner hits based on field values, enabling a second level of collapsing when
+    /// used within a [`Collapse`] definition
+    pub fn collapse(mut self, collapse: impl Into<Collapse>) -> Self {
+        let collapse = collapse.into();
+
+        if !collapse.should_skip() {
+            self.collapse = Some(Box::new(collapse));
+        }
+
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.highlight
+            .iter()
+            .flat_map(Highlight::embedded_queries)
+            .chain(
+                self.collapse
+                    .iter()
+                    .flat_map(|collapse| collapse.embedded_queries()),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(InnerHits::new(), json!({}));
+
+        assert_serialize(
+            InnerHits::new()
+                .name("comments")
+                .source(false)
+                .from(1)
+                .size(3)
+                .sort(Sort::new("comments.date").order(SortOrder::Desc))
+                .docvalue_fields(["comments.author", "comments.number"])
+                .collapse("comments.author"),
+            json!({
+                "name": "comments",
+                "_source": false,
+                "from": 1,
+                "size": 3,
+                "sort": [
+                    { "comments.date": { "order": "desc" } }
+                ],
+                "docvalue_fields": ["comments.author", "comments.number"],
+                "collapse": { "field": "comments.author" }
+            }),
+        );
+    }
 }
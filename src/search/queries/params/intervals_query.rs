@@ -0,0 +1,543 @@
+//! Rules of the [`intervals`](crate::IntervalsQuery) query
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-intervals-query.html>
+
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// A rule matching intervals of terms, combined recursively through
+/// [`all_of`](IntervalsRule::all_of) and [`any_of`](IntervalsRule::any_of)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsRule {
+    /// Matches analyzed text
+    Match(IntervalsMatch),
+
+    /// Matches terms that start with a specified set of characters
+    Prefix(IntervalsPrefix),
+
+    /// Matches terms using a wildcard pattern
+    Wildcard(IntervalsWildcard),
+
+    /// Matches terms that are similar to the provided term, within an edit distance
+    Fuzzy(IntervalsFuzzy),
+
+    /// Returns matches that span a combination of other rules
+    AllOf(IntervalsAllOf),
+
+    /// Returns intervals produced by any of its sub-rules
+    AnyOf(IntervalsAnyOf),
+}
+
+/// Restricts the intervals returned by a rule depending on their relation to the intervals of
+/// another rule, or on a script
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsFilter {
+    /// Produces intervals that follow an interval from the filter rule
+    After(Box<IntervalsRule>),
+
+    /// Produces intervals that occur before an interval from the filter rule
+    Before(Box<IntervalsRule>),
+
+    /// Produces intervals contained by an interval from the filter rule
+    ContainedBy(Box<IntervalsRule>),
+
+    /// Produces intervals that contain an interval from the filter rule
+    Containing(Box<IntervalsRule>),
+
+    /// Produces intervals that are **not** contained by an interval from the filter rule
+    NotContainedBy(Box<IntervalsRule>),
+
+    /// Produces intervals that do **not** contain an interval from the filter rule
+    NotContaining(Box<IntervalsRule>),
+
+    /// Produces intervals that do **not** overlap with an interval from the filter rule
+    NotOverlapping(Box<IntervalsRule>),
+
+    /// Produces intervals that overlap with an interval from the filter rule
+    Overlapping(Box<IntervalsRule>),
+
+    /// Script used to return matching intervals. The script has access to an `interval`
+    /// variable exposing its `start`, `end` and `gaps`.
+    Script(Script),
+}
+
+/// Matches analyzed text, see [`IntervalsRule::r#match`](IntervalsRule::match)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsMatch {
+    query: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_gaps: Option<i64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ordered: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<IntervalsFilter>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    use_field: Option<String>,
+}
+
+/// Matches terms that start with a set of characters, see [`IntervalsRule::prefix`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsPrefix {
+    prefix: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    use_field: Option<String>,
+}
+
+/// Matches terms using a wildcard pattern, see [`IntervalsRule::wildcard`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsWildcard {
+    pattern: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    use_field: Option<String>,
+}
+
+/// Matches terms similar to the provided term, see [`IntervalsRule::fuzzy`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsFuzzy {
+    term: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prefix_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    transpositions: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fuzziness: Option<Fuzziness>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    use_field: Option<String>,
+}
+
+/// Returns matches that span a combination of other rules, see [`IntervalsRule::all_of`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsAllOf {
+    intervals: Vec<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_gaps: Option<i64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ordered: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<IntervalsFilter>,
+}
+
+/// Returns intervals produced by any of its sub-rules, see [`IntervalsRule::any_of`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntervalsAnyOf {
+    intervals: Vec<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<IntervalsFilter>,
+}
+
+impl IntervalsRule {
+    /// Creates an instance of [`IntervalsMatch`]
+    ///
+    /// - `query` - Text you wish to find in the provided field
+    pub fn r#match<T>(query: T) -> IntervalsMatch
+    where
+        T: ToString,
+    {
+        IntervalsMatch {
+            query: query.to_string(),
+            max_gaps: None,
+            ordered: None,
+            analyzer: None,
+            filter: None,
+            use_field: None,
+        }
+    }
+
+    /// Creates an instance of [`IntervalsPrefix`]
+    ///
+    /// - `prefix` - Beginning characters of terms you wish to find in the top-level field
+    pub fn prefix<T>(prefix: T) -> IntervalsPrefix
+    where
+        T: ToString,
+    {
+        IntervalsPrefix {
+            prefix: prefix.to_string(),
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    /// Creates an instance of [`IntervalsWildcard`]
+    ///
+    /// - `pattern` - Wildcard pattern used to find matching terms. Supports `?` for any single
+    ///   character and `*` for zero or more characters.
+    pub fn wildcard<T>(pattern: T) -> IntervalsWildcard
+    where
+        T: ToString,
+    {
+        IntervalsWildcard {
+            pattern: pattern.to_string(),
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    /// Creates an instance of [`IntervalsFuzzy`]
+    ///
+    /// - `term` - The term to match
+    pub fn fuzzy<T>(term: T) -> IntervalsFuzzy
+    where
+        T: ToString,
+    {
+        IntervalsFuzzy {
+            term: term.to_string(),
+            prefix_length: None,
+            transpositions: None,
+            fuzziness: None,
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    /// Creates an instance of [`IntervalsAllOf`]
+    ///
+    /// - `intervals` - Rules to combine. All rules must produce a match in a document for the
+    ///   overall source to match.
+    pub fn all_of<I>(intervals: I) -> IntervalsAllOf
+    where
+        I: IntoIterator,
+        I::Item: Into<IntervalsRule>,
+    {
+        IntervalsAllOf {
+            intervals: intervals.into_iter().map(Into::into).collect(),
+            max_gaps: None,
+            ordered: None,
+            filter: None,
+        }
+    }
+
+    /// Creates an instance of [`IntervalsAnyOf`]
+    ///
+    /// - `intervals` - Rules to match
+    pub fn any_of<I>(intervals: I) -> IntervalsAnyOf
+    where
+        I: IntoIterator,
+        I::Item: Into<IntervalsRule>,
+    {
+        IntervalsAnyOf {
+            intervals: intervals.into_iter().map(Into::into).collect(),
+            filter: None,
+        }
+    }
+}
+
+impl IntervalsFilter {
+    /// Creates an [`IntervalsFilter::After`] filter
+    pub fn after(rule: impl Into<IntervalsRule>) -> Self {
+        Self::After(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::Before`] filter
+    pub fn before(rule: impl Into<IntervalsRule>) -> Self {
+        Self::Before(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::ContainedBy`] filter
+    pub fn contained_by(rule: impl Into<IntervalsRule>) -> Self {
+        Self::ContainedBy(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::Containing`] filter
+    pub fn containing(rule: impl Into<IntervalsRule>) -> Self {
+        Self::Containing(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::NotContainedBy`] filter
+    pub fn not_contained_by(rule: impl Into<IntervalsRule>) -> Self {
+        Self::NotContainedBy(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::NotContaining`] filter
+    pub fn not_containing(rule: impl Into<IntervalsRule>) -> Self {
+        Self::NotContaining(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::NotOverlapping`] filter
+    pub fn not_overlapping(rule: impl Into<IntervalsRule>) -> Self {
+        Self::NotOverlapping(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::Overlapping`] filter
+    pub fn overlapping(rule: impl Into<IntervalsRule>) -> Self {
+        Self::Overlapping(Box::new(rule.into()))
+    }
+
+    /// Creates an [`IntervalsFilter::Script`] filter
+    pub fn script(script: Script) -> Self {
+        Self::Script(script)
+    }
+}
+
+macro_rules! add_analyzer_and_use_field {
+    () => {
+        /// [Analyzer](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html)
+        /// used to analyze the terms of the rule. Defaults to the top-level field's analyzer.
+        pub fn analyzer<T>(mut self, analyzer: T) -> Self
+        where
+            T: ToString,
+        {
+            self.analyzer = Some(analyzer.to_string());
+            self
+        }
+
+        /// If specified, match intervals from this field rather than the top-level field. The
+        /// terms are analyzed using the search analyzer from this field.
+        pub fn use_field<T>(mut self, use_field: T) -> Self
+        where
+            T: ToString,
+        {
+            self.use_field = Some(use_field.to_string());
+            self
+        }
+    };
+}
+
+impl IntervalsMatch {
+    /// Maximum number of positions between the matching terms. Terms further apart than this
+    /// are not considered matches. Defaults to `-1`, meaning no restriction.
+    pub fn max_gaps(mut self, max_gaps: i64) -> Self {
+        self.max_gaps = Some(max_gaps);
+        self
+    }
+
+    /// If `true`, matching terms must appear in their specified order. Defaults to `false`.
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = Some(ordered);
+        self
+    }
+
+    /// Restricts the returned intervals, see [`IntervalsFilter`]
+    pub fn filter(mut self, filter: IntervalsFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    add_analyzer_and_use_field!();
+}
+
+impl IntervalsPrefix {
+    add_analyzer_and_use_field!();
+}
+
+impl IntervalsWildcard {
+    add_analyzer_and_use_field!();
+}
+
+impl IntervalsFuzzy {
+    /// Number of beginning characters left unchanged when creating expansions. Defaults to `0`.
+    pub fn prefix_length(mut self, prefix_length: impl TryInto<u64>) -> Self {
+        if let Ok(prefix_length) = prefix_length.try_into() {
+            self.prefix_length = Some(prefix_length);
+        }
+        self
+    }
+
+    /// Indicates whether edits include transpositions of two adjacent characters (ab → ba).
+    /// Defaults to `true`.
+    pub fn transpositions(mut self, transpositions: bool) -> Self {
+        self.transpositions = Some(transpositions);
+        self
+    }
+
+    /// Maximum edit distance allowed for matching. Defaults to `auto`.
+    pub fn fuzziness(mut self, fuzziness: impl Into<Fuzziness>) -> Self {
+        self.fuzziness = Some(fuzziness.into());
+        self
+    }
+
+    add_analyzer_and_use_field!();
+}
+
+impl IntervalsAllOf {
+    /// Maximum number of positions between the matching terms. Intervals produced by the rules
+    /// further apart than this are not considered matches. Defaults to `-1`, meaning no
+    /// restriction.
+    pub fn max_gaps(mut self, max_gaps: i64) -> Self {
+        self.max_gaps = Some(max_gaps);
+        self
+    }
+
+    /// If `true`, intervals produced by the rules should appear in the order in which they are
+    /// specified. Defaults to `false`.
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = Some(ordered);
+        self
+    }
+
+    /// Restricts the returned intervals, see [`IntervalsFilter`]
+    pub fn filter(mut self, filter: IntervalsFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+impl IntervalsAnyOf {
+    /// Restricts the returned intervals, see [`IntervalsFilter`]
+    pub fn filter(mut self, filter: IntervalsFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+macro_rules! intervals_rule {
+    ($($variant:ident($rule:ty)),+ $(,)?) => {
+        $(
+            impl From<$rule> for IntervalsRule {
+                fn from(rule: $rule) -> Self {
+                    Self::$variant(rule)
+                }
+            }
+        )+
+    };
+}
+
+intervals_rule!(
+    Match(IntervalsMatch),
+    Prefix(IntervalsPrefix),
+    Wildcard(IntervalsWildcard),
+    Fuzzy(IntervalsFuzzy),
+    AllOf(IntervalsAllOf),
+    AnyOf(IntervalsAnyOf),
+);
+
+impl ShouldSkip for IntervalsRule {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Match(rule) => rule.query.should_skip(),
+            Self::Prefix(rule) => rule.prefix.should_skip(),
+            Self::Wildcard(rule) => rule.pattern.should_skip(),
+            Self::Fuzzy(rule) => rule.term.should_skip(),
+            Self::AllOf(rule) => rule.intervals.is_empty(),
+            Self::AnyOf(rule) => rule.intervals.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            IntervalsRule::from(
+                IntervalsRule::r#match("my favorite food")
+                    .max_gaps(0)
+                    .ordered(true)
+                    .analyzer("standard")
+                    .use_field("my_text.stemmed"),
+            ),
+            json!({
+                "match": {
+                    "query": "my favorite food",
+                    "max_gaps": 0,
+                    "ordered": true,
+                    "analyzer": "standard",
+                    "use_field": "my_text.stemmed"
+                }
+            }),
+        );
+
+        assert_serialize(
+            IntervalsRule::from(
+                IntervalsRule::fuzzy("salty")
+                    .prefix_length(1)
+                    .transpositions(false)
+                    .fuzziness(Fuzziness::Auto),
+            ),
+            json!({
+                "fuzzy": {
+                    "term": "salty",
+                    "prefix_length": 1,
+                    "transpositions": false,
+                    "fuzziness": "AUTO"
+                }
+            }),
+        );
+
+        assert_serialize(
+            IntervalsRule::from(IntervalsRule::any_of([
+                IntervalsRule::from(IntervalsRule::prefix("hot")),
+                IntervalsRule::from(IntervalsRule::wildcard("c*d")),
+            ])),
+            json!({
+                "any_of": {
+                    "intervals": [
+                        { "prefix": { "prefix": "hot" } },
+                        { "wildcard": { "pattern": "c*d" } }
+                    ]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_filters() {
+        let rule = || IntervalsRule::r#match("salty");
+
+        for (filter, key) in [
+            (IntervalsFilter::after(rule()), "after"),
+            (IntervalsFilter::before(rule()), "before"),
+            (IntervalsFilter::contained_by(rule()), "contained_by"),
+            (IntervalsFilter::containing(rule()), "containing"),
+            (
+                IntervalsFilter::not_contained_by(rule()),
+                "not_contained_by",
+            ),
+            (IntervalsFilter::not_containing(rule()), "not_containing"),
+            (IntervalsFilter::not_overlapping(rule()), "not_overlapping"),
+            (IntervalsFilter::overlapping(rule()), "overlapping"),
+        ] {
+            assert_serialize(filter, json!({ key: { "match": { "query": "salty" } } }));
+        }
+
+        assert_serialize(
+            IntervalsFilter::script(Script::source(
+                "interval.start > 10 && interval.end < 20 && interval.gaps == 0",
+            )),
+            json!({
+                "script": {
+                    "source": "interval.start > 10 && interval.end < 20 && interval.gaps == 0"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(IntervalsRule::from(IntervalsRule::r#match("")).should_skip());
+        assert!(
+            IntervalsRule::from(IntervalsRule::all_of(Vec::<IntervalsRule>::new())).should_skip()
+        );
+        assert!(!IntervalsRule::from(IntervalsRule::prefix("hot")).should_skip());
+    }
+}
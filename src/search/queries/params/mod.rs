@@ -14,8 +14,10 @@ mod tie_breaker;
 mod zero_terms_query;
 
 // Query specific parameters
+mod bool_query;
 mod function_score_query;
 mod geo_query;
+mod intervals_query;
 mod multi_match_query;
 mod nested_query;
 mod percolate_query;
@@ -25,14 +27,18 @@ mod regexp_query;
 mod shape_query;
 mod simple_query_string_query;
 mod terms_set_query;
+mod text_expansion_query;
+mod wrapper_query;
 
 // Public re-exports
+pub use self::bool_query::*;
 pub use self::boost::*;
 pub use self::function_score_query::*;
 pub use self::fuzziness::*;
 pub use self::geo_query::*;
 pub use self::has_child_query::*;
 pub use self::inner_hits::*;
+pub use self::intervals_query::*;
 pub use self::minimum_should_match::*;
 pub use self::multi_match_query::*;
 pub use self::negative_boost::*;
@@ -47,5 +53,11 @@ pub use self::script_object::*;
 pub use self::shape_query::*;
 pub use self::simple_query_string_query::*;
 pub use self::terms_set_query::*;
+pub use self::text_expansion_query::*;
 pub use self::tie_breaker::*;
+pub use self::wrapper_query::*;
 pub use self::zero_terms_query::*;
+
+// `function_score_query` declares its own `Script` function, the shared script object takes
+// precedence when referred to by name
+pub use self::script_object::Script;
@@ -1,15 +1,13 @@
-use std::collections::BTreeSet;
-
 /// Ids or documents to filter by
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PinnedQueryValues {
     /// [Document IDs](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-id-field.html)
     /// listed in the order they are to appear in results.
-    Ids(BTreeSet<String>),
+    Ids(Vec<String>),
 
     /// Documents listed in the order they are to appear in results.
-    Docs(BTreeSet<PinnedDocument>),
+    Docs(Vec<PinnedDocument>),
 }
 
 /// Pinned document
@@ -35,19 +33,39 @@ impl PinnedDocument {
 
 impl PinnedQueryValues {
     /// Creates an instance of [`PinnedQueryValues`] with [`PinnedQueryValues::Ids`]
+    ///
+    /// Keeps the given order, repeated IDs are dropped.
     pub fn ids<I>(ids: I) -> Self
     where
         I: IntoIterator,
         I::Item: ToString,
     {
-        Self::Ids(ids.into_iter().map(|x| x.to_string()).collect())
+        Self::Ids(unique(ids.into_iter().map(|x| x.to_string())))
     }
 
     /// Creates an instance of [`PinnedQueryValues`] with [`PinnedQueryValues::Docs`]
+    ///
+    /// Keeps the given order, repeated documents are dropped.
     pub fn docs<I>(docs: I) -> Self
     where
         I: IntoIterator<Item = PinnedDocument>,
     {
-        Self::Docs(docs.into_iter().collect())
+        Self::Docs(unique(docs))
     }
 }
+
+fn unique<T, I>(values: I) -> Vec<T>
+where
+    T: PartialEq,
+    I: IntoIterator<Item = T>,
+{
+    let mut unique = Vec::new();
+
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+
+    unique
+}
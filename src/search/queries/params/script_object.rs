@@ -78,17 +78,90 @@ impl Script {
 
     /// Specifies any named parameters that are passed into the script as variables. [Use parameters](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#prefer-params)
     /// instead of hard-coded values to decrease compile time.
+    ///
+    /// Parameters, including the keys of nested objects, are serialized in a deterministic
+    /// order so that identical scripts produce identical request bodies, e.g. for the request
+    /// cache.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let script =
+    /// Script::source("params.ids.contains(doc['id'].value)")
+    ///     .param("ids", &[1, 2, 3])
+    ///     .param("threshold", 0.5);
+    /// ```
     pub fn param<T, S>(mut self, name: S, param: T) -> Self
     where
         S: ToString,
         T: Serialize,
     {
         if let Ok(param) = serde_json::to_value(param) {
-            let _ = self.params.entry(name.to_string()).or_insert(param);
+            let _ = self
+                .params
+                .entry(name.to_string())
+                .or_insert_with(|| sort_keys(param));
         }
         self
     }
+
+    /// Binds `value` to the `{name}` placeholders of an inline script, which are replaced with
+    /// an accessor of the named parameter, e.g. `params['name']`. Values are passed as
+    /// [parameters](Self::param) rather than spliced into the source, so scripts assembled
+    /// from user input can't inject code, and identical scripts with different values share
+    /// a compiled script.
+    ///
+    /// Braces that don't enclose a bound name, such as painless blocks, are left untouched.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let script =
+    /// Script::source("if (doc[{field}].size() == 0) { return 0 } doc[{field}].value * {factor}")
+    ///     .bind("field", "price")
+    ///     .bind("factor", 1.2);
+    /// # assert_eq!(
+    /// #     script,
+    /// #     Script::source(
+    /// #         "if (doc[params['field']].size() == 0) { return 0 } doc[params['field']].value * params['factor']"
+    /// #     )
+    /// #     .param("field", "price")
+    /// #     .param("factor", 1.2)
+    /// # );
+    /// ```
+    pub fn bind<S, T>(mut self, name: S, value: T) -> Self
+    where
+        S: ToString,
+        T: Serialize,
+    {
+        let name = name.to_string();
+
+        if let ScriptSource::Source(source) = &mut self.source {
+            *source = source.replace(&format!("{{{}}}", name), &painless_param(&name));
+        }
+
+        self.param(name, value)
+    }
+
+    /// Specifies multiple named parameters at once, see [`param`](Self::param)
+    pub fn params<I, S, T>(self, params: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: ToString,
+        T: Serialize,
+    {
+        params
+            .into_iter()
+            .fold(self, |script, (name, param)| script.param(name, param))
+    }
+}
+
+/// Painless accessor of the parameter `name`, escaped as a string literal
+fn painless_param(name: &str) -> String {
+    format!(
+        "params['{}']",
+        name.replace('\\', "\\\\").replace('\'', "\\'")
+    )
 }
+
 /// Available scripting language
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScriptLang {
@@ -203,4 +276,60 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn binds_placeholders_to_params() {
+        assert_serialize(
+            Script::source("{ doc[{field}].value * {factor} + {unbound} }")
+                .bind("field", "price")
+                .bind("factor", 2),
+            json!({
+                "source": "{ doc[params['field']].value * params['factor'] + {unbound} }",
+                "params": {
+                    "factor": 2,
+                    "field": "price"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Script::source("{it's} * {a\\b}")
+                .bind("it's", 1)
+                .bind("a\\b", 2),
+            json!({
+                "source": "params['it\\'s'] * params['a\\\\b']",
+                "params": {
+                    "a\\b": 2,
+                    "it's": 1
+                }
+            }),
+        );
+
+        assert_serialize(
+            Script::id("calculate-score").bind("factor", 2),
+            json!({ "id": "calculate-score", "params": { "factor": 2 } }),
+        );
+    }
+
+    #[test]
+    fn serializes_params_in_deterministic_order() {
+        let nested: std::collections::HashMap<&str, i32> = [("zeta", 1), ("alpha", 2), ("mid", 3)]
+            .iter()
+            .copied()
+            .collect();
+
+        let script = Script::source("params.weights")
+            .params([("weights", nested.clone()), ("boosts", nested)])
+            .param("ids", vec![1, 2, 3]);
+
+        assert_eq!(
+            serde_json::to_string(&script).unwrap(),
+            concat!(
+                r#"{"source":"params.weights","params":{"#,
+                r#""boosts":{"alpha":2,"mid":3,"zeta":1},"#,
+                r#""ids":[1,2,3],"#,
+                r#""weights":{"alpha":2,"mid":3,"zeta":1}}}"#
+            )
+        );
+    }
 }
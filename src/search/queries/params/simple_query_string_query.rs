@@ -1,7 +1,10 @@
 use serde::ser::{Serialize, Serializer};
 
-/// You can use the flags parameter to enable more optional operators for Lucene’s regular
-/// expression engine.
+/// Operators of the [simple query string syntax](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-simple-query-string-query.html#simple-query-string-syntax)
+/// that can be enabled with [`SimpleQueryStringQuery::flags`](crate::SimpleQueryStringQuery::flags),
+/// combined into a pipe-delimited string such as `OR|AND|PREFIX`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-simple-query-string-query.html#supported-flags>
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SimpleQueryStringQueryFlags {
     /// Enables all optional operators.
@@ -1,3 +1,4 @@
+use crate::search::*;
 use serde_json::Value;
 
 /// Number of matching terms to be required
@@ -16,7 +17,7 @@ pub enum TermsSetMinimumShouldMatch {
     /// For an example query using the `minimum_should_match_script` parameter, see
     /// [How to use the `minimum_should_match_script` parameter](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-set-query.html#terms-set-query-script).
     #[serde(rename = "minimum_should_match_script")]
-    Script(TermsSetScript),
+    Script(Script),
 }
 
 impl From<String> for TermsSetMinimumShouldMatch {
@@ -31,17 +32,31 @@ impl<'a> From<&'a str> for TermsSetMinimumShouldMatch {
     }
 }
 
+impl From<Script> for TermsSetMinimumShouldMatch {
+    fn from(script: Script) -> Self {
+        Self::Script(script)
+    }
+}
+
 impl From<TermsSetScript> for TermsSetMinimumShouldMatch {
     fn from(script: TermsSetScript) -> Self {
-        Self::Script(script)
+        let TermsSetScript { source, params } = script;
+
+        let params = match params {
+            Some(Value::Object(params)) => params,
+            _ => Default::default(),
+        };
+
+        Self::Script(Script::source(source).params(params))
     }
 }
 
-/// Custom script containing the number of matching terms required to return a document.
+/// Inline script containing the number of matching terms required to return a document, a
+/// shorthand for a [`Script`] with its parameters given as a JSON object.
 ///
 /// For parameters and valid values, see
 /// [Scripting](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting.html).
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TermsSetScript {
     source: String,
     params: Option<Value>,
@@ -74,7 +89,7 @@ impl TermsSetScript {
         }
     }
 
-    /// Assign params
+    /// Assign params, given as a JSON object of named parameters
     pub fn params(mut self, params: Value) -> Self {
         self.params = Some(params);
         self
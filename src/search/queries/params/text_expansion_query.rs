@@ -0,0 +1,78 @@
+use crate::util::*;
+use std::convert::TryInto;
+
+/// Token pruning configuration of a [`TextExpansionQuery`](crate::TextExpansionQuery),
+/// [`SparseVectorQuery`](crate::SparseVectorQuery) or
+/// [`WeightedTokensQuery`](crate::WeightedTokensQuery).
+///
+/// Pruning leaves out the tokens that are frequent across the corpus and carry little weight,
+/// which speeds up the query significantly while keeping most of its relevance.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-text-expansion-query.html#text-expansion-query-params>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct PruningConfig {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokens_freq_ratio_threshold: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokens_weight_threshold: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    only_score_pruned_tokens: Option<bool>,
+}
+
+impl PruningConfig {
+    /// Creates an instance of [`PruningConfig`] with the Elasticsearch defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tokens whose frequency is more than `tokens_freq_ratio_threshold` times the average
+    /// frequency of all tokens in the specified field are considered outliers and pruned.
+    /// Must be between 1 and 100, defaults to `5`.
+    pub fn tokens_freq_ratio_threshold(
+        mut self,
+        tokens_freq_ratio_threshold: impl TryInto<u64>,
+    ) -> Self {
+        if let Ok(tokens_freq_ratio_threshold) = tokens_freq_ratio_threshold.try_into() {
+            self.tokens_freq_ratio_threshold = Some(tokens_freq_ratio_threshold);
+        }
+        self
+    }
+
+    /// Tokens whose weight is less than `tokens_weight_threshold` are considered nonrelevant
+    /// and pruned. Must be between 0 and 1, defaults to `0.4`.
+    pub fn tokens_weight_threshold(mut self, tokens_weight_threshold: impl Into<f32>) -> Self {
+        self.tokens_weight_threshold = Some(tokens_weight_threshold.into());
+        self
+    }
+
+    /// If `true`, only the pruned tokens are scored, which is useful to inspect what pruning
+    /// leaves out. Defaults to `false`.
+    pub fn only_score_pruned_tokens(mut self, only_score_pruned_tokens: bool) -> Self {
+        self.only_score_pruned_tokens = Some(only_score_pruned_tokens);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(PruningConfig::new(), json!({}));
+
+        assert_serialize(
+            PruningConfig::new()
+                .tokens_freq_ratio_threshold(5)
+                .tokens_weight_threshold(0.4)
+                .only_score_pruned_tokens(false),
+            json!({
+                "tokens_freq_ratio_threshold": 5,
+                "tokens_weight_threshold": 0.4,
+                "only_score_pruned_tokens": false
+            }),
+        );
+    }
+}
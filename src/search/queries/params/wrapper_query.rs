@@ -0,0 +1,108 @@
+use crate::search::*;
+use crate::util::*;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64 encoded query embedded by a [`WrapperQuery`](crate::WrapperQuery).
+///
+/// Strings are taken as already encoded, while JSON values and queries are serialized and encoded.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct WrappedQuery(String);
+
+impl WrappedQuery {
+    /// Creates an instance of [`WrappedQuery`] from an already base64 encoded query
+    pub fn base64<S>(query: S) -> Self
+    where
+        S: ToString,
+    {
+        Self(query.to_string())
+    }
+
+    /// Creates an instance of [`WrappedQuery`] by base64 encoding the serialized value
+    pub fn json<T>(query: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        match serde_json::to_vec(&query) {
+            Ok(bytes) => Self(encode(&bytes)),
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+impl ShouldSkip for WrappedQuery {
+    fn should_skip(&self) -> bool {
+        self.0.should_skip()
+    }
+}
+
+impl From<&str> for WrappedQuery {
+    fn from(value: &str) -> Self {
+        Self::base64(value)
+    }
+}
+
+impl From<String> for WrappedQuery {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&String> for WrappedQuery {
+    fn from(value: &String) -> Self {
+        Self::base64(value)
+    }
+}
+
+impl From<serde_json::Value> for WrappedQuery {
+    fn from(value: serde_json::Value) -> Self {
+        Self::json(value)
+    }
+}
+
+impl From<Query> for WrappedQuery {
+    fn from(value: Query) -> Self {
+        Self::json(value)
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as usize;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+
+        encoded.push(ALPHABET[b0 >> 2] as char);
+        encoded.push(ALPHABET[((b0 & 0x03) << 4) | (b1 >> 4)] as char);
+
+        if chunk.len() > 1 {
+            encoded.push(ALPHABET[((b1 & 0x0f) << 2) | (b2 >> 6)] as char);
+        } else {
+            encoded.push('=');
+        }
+
+        if chunk.len() > 2 {
+            encoded.push(ALPHABET[b2 & 0x3f] as char);
+        } else {
+            encoded.push('=');
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_base64() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+}
@@ -9,3 +9,25 @@
 //! spans.
 //!
 //! Span queries cannot be mixed with non-span queries (with the exception of the span_multi query).
+
+mod span_containing_query;
+mod span_field_masking_query;
+mod span_first_query;
+mod span_multi_query;
+mod span_near_query;
+mod span_not_query;
+mod span_or_query;
+mod span_query;
+mod span_term_query;
+mod span_within_query;
+
+pub use self::span_containing_query::*;
+pub use self::span_field_masking_query::*;
+pub use self::span_first_query::*;
+pub use self::span_multi_query::*;
+pub use self::span_near_query::*;
+pub use self::span_not_query::*;
+pub use self::span_or_query::*;
+pub use self::span_query::*;
+pub use self::span_term_query::*;
+pub use self::span_within_query::*;
@@ -0,0 +1,151 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Returns matches which enclose another span query. The span containing query maps to
+/// Lucene `SpanContainingQuery`.
+///
+/// To create a span containing query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_containing(
+///     Query::span_near([
+///         Query::span_term("field1", "bar"),
+///         Query::span_term("field1", "baz"),
+///     ])
+///     .slop(5)
+///     .in_order(true),
+///     Query::span_term("field1", "foo"),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-containing-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanContainingQuery {
+    #[serde(rename = "span_containing")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    big: Box<SpanQuery>,
+
+    little: Box<SpanQuery>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanContainingQuery`], returning the `big` matches
+    ///
+    /// - `big` - Span query whose matches must contain the `little` matches
+    /// - `little` - Span query whose matches must be contained in the `big` matches
+    pub fn span_containing(
+        big: impl Into<SpanQuery>,
+        little: impl Into<SpanQuery>,
+    ) -> SpanContainingQuery {
+        SpanContainingQuery {
+            inner: Inner {
+                big: Box::new(big.into()),
+                little: Box::new(little.into()),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanContainingQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![
+            self.inner.big.as_ref().clone().into(),
+            self.inner.little.as_ref().clone().into(),
+        ]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![
+            SpanClauseMut::Span(self.inner.big.as_mut()),
+            SpanClauseMut::Span(self.inner.little.as_mut()),
+        ]
+    }
+}
+
+impl ShouldSkip for SpanContainingQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.big.should_skip() || self.inner.little.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        #[cfg(not(feature = "compact"))]
+        assert_serialize(
+            Query::span_containing(
+                Query::span_near([
+                    Query::span_term("field1", "bar"),
+                    Query::span_term("field1", "baz"),
+                ])
+                .slop(5)
+                .in_order(true),
+                Query::span_term("field1", "foo"),
+            )
+            .boost(2),
+            json!({
+                "span_containing": {
+                    "big": {
+                        "span_near": {
+                            "clauses": [
+                                { "span_term": { "field1": { "value": "bar" } } },
+                                { "span_term": { "field1": { "value": "baz" } } }
+                            ],
+                            "slop": 5,
+                            "in_order": true
+                        }
+                    },
+                    "little": { "span_term": { "field1": { "value": "foo" } } },
+                    "boost": 2
+                }
+            }),
+        );
+
+        #[cfg(feature = "compact")]
+        assert_serialize(
+            Query::span_containing(
+                Query::span_near([
+                    Query::span_term("field1", "bar"),
+                    Query::span_term("field1", "baz"),
+                ])
+                .slop(5)
+                .in_order(true),
+                Query::span_term("field1", "foo"),
+            )
+            .boost(2),
+            json!({
+                "span_containing": {
+                    "big": {
+                        "span_near": {
+                            "clauses": [
+                                { "span_term": { "field1": { "value": "bar" } } },
+                                { "span_term": { "field1": { "value": "baz" } } }
+                            ],
+                            "slop": 5,
+                        }
+                    },
+                    "little": { "span_term": { "field1": { "value": "foo" } } },
+                    "boost": 2
+                }
+            }),
+        );
+    }
+}
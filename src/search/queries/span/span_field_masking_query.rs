@@ -0,0 +1,96 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Wrapper to allow span queries to participate in composite single-field span queries by lying
+/// about their search field.
+///
+/// This can be used to support queries like `span_near` or `span_or` across different fields,
+/// which is not ordinarily permitted.
+///
+/// To create a span field masking query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_near([
+///     SpanQuery::from(Query::span_term("text", "quick brown")),
+///     Query::span_field_masking(Query::span_term("text.stems", "fox"), "text").into(),
+/// ])
+/// .slop(5)
+/// .in_order(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-field-masking-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanFieldMaskingQuery {
+    #[serde(rename = "span_field_masking")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    query: Box<SpanQuery>,
+
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanFieldMaskingQuery`]
+    ///
+    /// - `query` - Span query to mask
+    /// - `field` - Field the span query pretends to search
+    pub fn span_field_masking(
+        query: impl Into<SpanQuery>,
+        field: impl Into<String>,
+    ) -> SpanFieldMaskingQuery {
+        SpanFieldMaskingQuery {
+            inner: Inner {
+                query: Box::new(query.into()),
+                field: field.into(),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanFieldMaskingQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![self.inner.query.as_ref().clone().into()]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![SpanClauseMut::Span(self.inner.query.as_mut())]
+    }
+}
+
+impl ShouldSkip for SpanFieldMaskingQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.query.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_field_masking(Query::span_term("text.stems", "fox"), "text"),
+            json!({
+                "span_field_masking": {
+                    "query": { "span_term": { "text.stems": { "value": "fox" } } },
+                    "field": "text"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Matches spans near the beginning of a field.
+///
+/// To create a span first query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_first(Query::span_term("user.id", "kimchy"), 3);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-first-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanFirstQuery {
+    #[serde(rename = "span_first")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    r#match: Box<SpanQuery>,
+
+    end: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanFirstQuery`]
+    ///
+    /// - `match` - Span query to match
+    /// - `end` - Maximum end position permitted in a match
+    pub fn span_first(r#match: impl Into<SpanQuery>, end: u32) -> SpanFirstQuery {
+        SpanFirstQuery {
+            inner: Inner {
+                r#match: Box::new(r#match.into()),
+                end,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanFirstQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![self.inner.r#match.as_ref().clone().into()]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![SpanClauseMut::Span(self.inner.r#match.as_mut())]
+    }
+}
+
+impl ShouldSkip for SpanFirstQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.r#match.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_first(Query::span_term("user.id", "kimchy"), 3),
+            json!({
+                "span_first": {
+                    "match": { "span_term": { "user.id": { "value": "kimchy" } } },
+                    "end": 3
+                }
+            }),
+        );
+    }
+}
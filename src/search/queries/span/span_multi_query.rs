@@ -0,0 +1,113 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Allows you to wrap a multi term query (one of wildcard, fuzzy, prefix, range or regexp query)
+/// as a span query, so it can be nested.
+///
+/// To create a span multi query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_multi(Query::prefix("user.id", "ki"));
+/// ```
+/// Multi-term queries given as span clauses are wrapped automatically:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_or([Query::prefix("user.id", "ki"), Query::prefix("user.id", "el")]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-multi-term-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanMultiQuery {
+    #[serde(rename = "span_multi")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    r#match: MultiTermQuery,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanMultiQuery`]
+    ///
+    /// - `query` - Multi term query to wrap
+    pub fn span_multi(query: impl Into<MultiTermQuery>) -> SpanMultiQuery {
+        SpanMultiQuery {
+            inner: Inner {
+                r#match: query.into(),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanMultiQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![self.inner.r#match.clone().into()]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![SpanClauseMut::MultiTerm(&mut self.inner.r#match)]
+    }
+}
+
+impl ShouldSkip for SpanMultiQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.r#match.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_multi(Query::prefix("user.id", "ki")).name("test"),
+            json!({
+                "span_multi": {
+                    "match": { "prefix": { "user.id": { "value": "ki" } } },
+                    "_name": "test"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::span_multi(Query::regexp("user.id", "k.*y")).boost(2),
+            json!({
+                "span_multi": {
+                    "match": { "regexp": { "user.id": { "value": "k.*y" } } },
+                    "boost": 2
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::span_multi(Query::range("age").gte(10)),
+            json!({
+                "span_multi": {
+                    "match": { "range": { "age": { "gte": 10 } } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::span_multi(Query::prefix("user.id", "")).should_skip());
+        assert!(!Query::span_multi(Query::prefix("user.id", "ki")).should_skip());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Matches spans which are near one another. One can specify `slop`, the maximum number of
+/// intervening unmatched positions, as well as whether matches are required to be in-order.
+///
+/// To create a span near query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_near([
+///     Query::span_term("field", "value1"),
+///     Query::span_term("field", "value2"),
+///     Query::span_term("field", "value3"),
+/// ])
+/// .slop(12)
+/// .in_order(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-near-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanNearQuery {
+    #[serde(rename = "span_near")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    clauses: Vec<SpanQuery>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slop: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    in_order: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanNearQuery`]
+    ///
+    /// - `clauses` - Span queries which must be near one another
+    pub fn span_near<T>(clauses: T) -> SpanNearQuery
+    where
+        T: IntoIterator,
+        T::Item: Into<SpanQuery>,
+    {
+        SpanNearQuery {
+            inner: Inner {
+                clauses: clauses
+                    .into_iter()
+                    .map(Into::into)
+                    .filter(|clause: &SpanQuery| !clause.should_skip())
+                    .collect(),
+                slop: None,
+                in_order: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanNearQuery {
+    /// Maximum number of intervening unmatched positions
+    pub fn slop(mut self, slop: u32) -> Self {
+        self.inner.slop = Some(slop);
+        self
+    }
+
+    /// Whether the clauses are required to match in order
+    pub fn in_order(mut self, in_order: bool) -> Self {
+        self.inner.in_order = non_default(in_order, true);
+        self
+    }
+
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        self.inner
+            .clauses
+            .iter()
+            .cloned()
+            .map(Query::from)
+            .collect()
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        self.inner
+            .clauses
+            .iter_mut()
+            .map(SpanClauseMut::Span)
+            .collect()
+    }
+}
+
+impl ShouldSkip for SpanNearQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.clauses.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_near([
+                Query::span_term("field", "value1"),
+                Query::span_term("field", "value2"),
+            ])
+            .slop(12)
+            .in_order(false)
+            .name("test"),
+            json!({
+                "span_near": {
+                    "clauses": [
+                        { "span_term": { "field": { "value": "value1" } } },
+                        { "span_term": { "field": { "value": "value2" } } }
+                    ],
+                    "slop": 12,
+                    "in_order": false,
+                    "_name": "test"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Removes matches which overlap with another span query or which are within x tokens before
+/// (controlled by the parameter `pre`) or y tokens after (controlled by the parameter `post`)
+/// another span query.
+///
+/// To create a span not query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_not(
+///     Query::span_term("field1", "hoya"),
+///     Query::span_near([
+///         Query::span_term("field1", "la"),
+///         Query::span_term("field1", "hoya"),
+///     ])
+///     .slop(0)
+///     .in_order(true),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-not-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanNotQuery {
+    #[serde(rename = "span_not")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    include: Box<SpanQuery>,
+
+    exclude: Box<SpanQuery>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pre: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    post: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dist: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanNotQuery`]
+    ///
+    /// - `include` - Span query whose matches are filtered
+    /// - `exclude` - Span query whose matches must not overlap those returned
+    pub fn span_not(include: impl Into<SpanQuery>, exclude: impl Into<SpanQuery>) -> SpanNotQuery {
+        SpanNotQuery {
+            inner: Inner {
+                include: Box::new(include.into()),
+                exclude: Box::new(exclude.into()),
+                pre: None,
+                post: None,
+                dist: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanNotQuery {
+    /// If set the amount of tokens before the include span can’t have overlap with the exclude
+    /// span.
+    ///
+    /// Defaults to `0`.
+    pub fn pre(mut self, pre: u32) -> Self {
+        self.inner.pre = Some(pre);
+        self
+    }
+
+    /// If set the amount of tokens after the include span can’t have overlap with the exclude
+    /// span.
+    ///
+    /// Defaults to `0`.
+    pub fn post(mut self, post: u32) -> Self {
+        self.inner.post = Some(post);
+        self
+    }
+
+    /// If set the amount of tokens from within the include span can’t have overlap with the
+    /// exclude span. Equivalent of setting both `pre` and `post`.
+    pub fn dist(mut self, dist: u32) -> Self {
+        self.inner.dist = Some(dist);
+        self
+    }
+
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![
+            self.inner.include.as_ref().clone().into(),
+            self.inner.exclude.as_ref().clone().into(),
+        ]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![
+            SpanClauseMut::Span(self.inner.include.as_mut()),
+            SpanClauseMut::Span(self.inner.exclude.as_mut()),
+        ]
+    }
+}
+
+impl ShouldSkip for SpanNotQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.include.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_not(
+                Query::span_term("field1", "hoya"),
+                Query::span_term("field1", "la"),
+            )
+            .pre(1)
+            .post(2),
+            json!({
+                "span_not": {
+                    "include": { "span_term": { "field1": { "value": "hoya" } } },
+                    "exclude": { "span_term": { "field1": { "value": "la" } } },
+                    "pre": 1,
+                    "post": 2
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::span_not(
+                Query::span_term("field1", "hoya"),
+                Query::span_term("field1", "la"),
+            )
+            .dist(3),
+            json!({
+                "span_not": {
+                    "include": { "span_term": { "field1": { "value": "hoya" } } },
+                    "exclude": { "span_term": { "field1": { "value": "la" } } },
+                    "dist": 3
+                }
+            }),
+        );
+    }
+}
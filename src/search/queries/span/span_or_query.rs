@@ -0,0 +1,114 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Matches the union of its span clauses.
+///
+/// To create a span or query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_or([
+///     Query::span_term("field", "value1"),
+///     Query::span_term("field", "value2"),
+///     Query::span_term("field", "value3"),
+/// ]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-or-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanOrQuery {
+    #[serde(rename = "span_or")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    clauses: Vec<SpanQuery>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanOrQuery`]
+    ///
+    /// - `clauses` - Span queries to match any of
+    pub fn span_or<T>(clauses: T) -> SpanOrQuery
+    where
+        T: IntoIterator,
+        T::Item: Into<SpanQuery>,
+    {
+        SpanOrQuery {
+            inner: Inner {
+                clauses: clauses
+                    .into_iter()
+                    .map(Into::into)
+                    .filter(|clause: &SpanQuery| !clause.should_skip())
+                    .collect(),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanOrQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        self.inner
+            .clauses
+            .iter()
+            .cloned()
+            .map(Query::from)
+            .collect()
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        self.inner
+            .clauses
+            .iter_mut()
+            .map(SpanClauseMut::Span)
+            .collect()
+    }
+}
+
+impl ShouldSkip for SpanOrQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.clauses.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::span_or([
+                Query::span_term("field", "value1"),
+                Query::span_term("field", None::<String>),
+                Query::span_term("field", "value2"),
+            ])
+            .boost(2),
+            json!({
+                "span_or": {
+                    "clauses": [
+                        { "span_term": { "field": { "value": "value1" } } },
+                        { "span_term": { "field": { "value": "value2" } } }
+                    ],
+                    "boost": 2
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::bool().must(Query::span_or(Vec::<SpanQuery>::new())),
+            json!({ "bool": {} }),
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use crate::search::*;
+use crate::util::*;
+
+macro_rules! span_query {
+    ($($variant:ident($query:ty)),+ $(,)?) => {
+        /// A container enum for span queries, the only clauses accepted by compound span queries
+        #[derive(Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum SpanQuery {
+            $(
+                $variant($query),
+            )*
+        }
+
+        impl ShouldSkip for SpanQuery {
+            fn should_skip(&self) -> bool {
+                match self {
+                    $(
+                        Self::$variant(q) => q.should_skip(),
+                    )+
+                }
+            }
+        }
+
+        impl std::fmt::Debug for SpanQuery {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(q) => q.fmt(f),
+                    )+
+                }
+            }
+        }
+
+        impl From<SpanQuery> for Query {
+            fn from(q: SpanQuery) -> Self {
+                match q {
+                    $(
+                        SpanQuery::$variant(q) => q.into(),
+                    )+
+                }
+            }
+        }
+
+        impl SpanQuery {
+            /// Converts a query back into a span query, failing for any other query
+            fn from_query(query: Query) -> Option<Self> {
+                match query {
+                    $(
+                        Query::$variant(q) => Some(Self::$variant(q)),
+                    )+
+                    _ => None,
+                }
+            }
+        }
+
+        $(
+            impl From<$query> for SpanQuery {
+                fn from(q: $query) -> Self {
+                    SpanQuery::$variant(q)
+                }
+            }
+        )+
+    };
+}
+
+span_query!(
+    SpanContaining(SpanContainingQuery),
+    SpanFieldMasking(SpanFieldMaskingQuery),
+    SpanFirst(SpanFirstQuery),
+    SpanMulti(SpanMultiQuery),
+    SpanNear(SpanNearQuery),
+    SpanNot(SpanNotQuery),
+    SpanOr(SpanOrQuery),
+    SpanTerm(SpanTermQuery),
+    SpanWithin(SpanWithinQuery),
+);
+
+/// A container enum for the multi-term queries that can be wrapped by a [`SpanMultiQuery`]
+#[derive(Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+#[allow(missing_docs)]
+pub enum MultiTermQuery {
+    Prefix(PrefixQuery),
+    Regexp(RegexpQuery),
+    Wildcard(WildcardQuery),
+    Fuzzy(FuzzyQuery),
+    Range(RangeQuery),
+}
+
+impl std::fmt::Debug for MultiTermQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prefix(q) => q.fmt(f),
+            Self::Regexp(q) => q.fmt(f),
+            Self::Wildcard(q) => q.fmt(f),
+            Self::Fuzzy(q) => q.fmt(f),
+            Self::Range(q) => q.fmt(f),
+        }
+    }
+}
+
+impl ShouldSkip for MultiTermQuery {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Prefix(q) => q.should_skip(),
+            Self::Regexp(q) => q.should_skip(),
+            Self::Wildcard(q) => q.should_skip(),
+            Self::Fuzzy(q) => q.should_skip(),
+            Self::Range(q) => q.should_skip(),
+        }
+    }
+}
+
+impl From<MultiTermQuery> for Query {
+    fn from(q: MultiTermQuery) -> Self {
+        match q {
+            MultiTermQuery::Prefix(q) => q.into(),
+            MultiTermQuery::Regexp(q) => q.into(),
+            MultiTermQuery::Wildcard(q) => q.into(),
+            MultiTermQuery::Fuzzy(q) => q.into(),
+            MultiTermQuery::Range(q) => q.into(),
+        }
+    }
+}
+
+impl MultiTermQuery {
+    /// Converts a query back into a multi-term query, failing for any other query
+    fn from_query(query: Query) -> Option<Self> {
+        match query {
+            Query::Prefix(q) => Some(Self::Prefix(q)),
+            Query::Regexp(q) => Some(Self::Regexp(q)),
+            Query::Wildcard(q) => Some(Self::Wildcard(q)),
+            Query::Fuzzy(q) => Some(Self::Fuzzy(q)),
+            Query::Range(q) => Some(Self::Range(q)),
+            _ => None,
+        }
+    }
+}
+
+impl From<PrefixQuery> for MultiTermQuery {
+    fn from(q: PrefixQuery) -> Self {
+        Self::Prefix(q)
+    }
+}
+
+impl From<RegexpQuery> for MultiTermQuery {
+    fn from(q: RegexpQuery) -> Self {
+        Self::Regexp(q)
+    }
+}
+
+impl From<WildcardQuery> for MultiTermQuery {
+    fn from(q: WildcardQuery) -> Self {
+        Self::Wildcard(q)
+    }
+}
+
+impl From<FuzzyQuery> for MultiTermQuery {
+    fn from(q: FuzzyQuery) -> Self {
+        Self::Fuzzy(q)
+    }
+}
+
+impl From<RangeQuery> for MultiTermQuery {
+    fn from(q: RangeQuery) -> Self {
+        Self::Range(q)
+    }
+}
+
+/// A clause of a compound span query, which only accepts span queries, or multi-term queries for
+/// [`SpanMultiQuery`]
+pub(crate) enum SpanClauseMut<'a> {
+    Span(&'a mut SpanQuery),
+    MultiTerm(&'a mut MultiTermQuery),
+}
+
+impl SpanClauseMut<'_> {
+    /// Replaces the clause with the query returned by `f`, unless that query isn't accepted in
+    /// the position of the clause, in which case the clause is kept as is
+    pub(crate) fn update<F>(self, f: F)
+    where
+        F: FnOnce(Query) -> Query,
+    {
+        match self {
+            Self::Span(clause) => {
+                if let Some(query) = SpanQuery::from_query(f(clause.clone().into())) {
+                    *clause = query;
+                }
+            }
+            Self::MultiTerm(clause) => {
+                if let Some(query) = MultiTermQuery::from_query(f(clause.clone().into())) {
+                    *clause = query;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! span_multi_term_query {
+    ($($query:ty),+ $(,)?) => {
+        $(
+            /// Wraps the multi-term query in a [`SpanMultiQuery`]
+            impl From<$query> for SpanQuery {
+                fn from(q: $query) -> Self {
+                    Self::SpanMulti(Query::span_multi(q))
+                }
+            }
+        )+
+    };
+}
+
+span_multi_term_query!(
+    PrefixQuery,
+    RegexpQuery,
+    WildcardQuery,
+    FuzzyQuery,
+    RangeQuery
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_multi_term_queries() {
+        assert_serialize(
+            Query::span_near([
+                SpanQuery::from(Query::span_term("text", "quick")),
+                SpanQuery::from(Query::wildcard("text", "br*n")),
+                SpanQuery::from(Query::fuzzy("text", "fox")),
+            ]),
+            json!({
+                "span_near": {
+                    "clauses": [
+                        { "span_term": { "text": { "value": "quick" } } },
+                        { "span_multi": { "match": { "wildcard": { "text": { "value": "br*n" } } } } },
+                        { "span_multi": { "match": { "fuzzy": { "text": { "value": "fox" } } } } }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Returns matches which are enclosed inside another span query. The span within query maps
+/// to Lucene `SpanWithinQuery`.
+///
+/// To create a span within query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::span_within(
+///     Query::span_near([
+///         Query::span_term("field1", "bar"),
+///         Query::span_term("field1", "baz"),
+///     ])
+///     .slop(5)
+///     .in_order(true),
+///     Query::span_term("field1", "foo"),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-within-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpanWithinQuery {
+    #[serde(rename = "span_within")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    big: Box<SpanQuery>,
+
+    little: Box<SpanQuery>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SpanWithinQuery`], returning the `little` matches
+    ///
+    /// - `big` - Span query whose matches must contain the `little` matches
+    /// - `little` - Span query whose matches must be contained in the `big` matches
+    pub fn span_within(big: impl Into<SpanQuery>, little: impl Into<SpanQuery>) -> SpanWithinQuery {
+        SpanWithinQuery {
+            inner: Inner {
+                big: Box::new(big.into()),
+                little: Box::new(little.into()),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SpanWithinQuery {
+    add_boost_and_name!();
+
+    pub(crate) fn span_clauses(&self) -> Vec<Query> {
+        vec![
+            self.inner.big.as_ref().clone().into(),
+            self.inner.little.as_ref().clone().into(),
+        ]
+    }
+
+    pub(crate) fn span_clauses_mut(&mut self) -> Vec<SpanClauseMut<'_>> {
+        vec![
+            SpanClauseMut::Span(self.inner.big.as_mut()),
+            SpanClauseMut::Span(self.inner.little.as_mut()),
+        ]
+    }
+}
+
+impl ShouldSkip for SpanWithinQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.big.should_skip() || self.inner.little.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        #[cfg(not(feature = "compact"))]
+        assert_serialize(
+            Query::span_within(
+                Query::span_near([
+                    Query::span_term("field1", "bar"),
+                    Query::span_term("field1", "baz"),
+                ])
+                .slop(5)
+                .in_order(true),
+                Query::span_term("field1", "foo"),
+            )
+            .boost(2),
+            json!({
+                "span_within": {
+                    "big": {
+                        "span_near": {
+                            "clauses": [
+                                { "span_term": { "field1": { "value": "bar" } } },
+                                { "span_term": { "field1": { "value": "baz" } } }
+                            ],
+                            "slop": 5,
+                            "in_order": true
+                        }
+                    },
+                    "little": { "span_term": { "field1": { "value": "foo" } } },
+                    "boost": 2
+                }
+            }),
+        );
+
+        #[cfg(feature = "compact")]
+        assert_serialize(
+            Query::span_within(
+                Query::span_near([
+                    Query::span_term("field1", "bar"),
+                    Query::span_term("field1", "baz"),
+                ])
+                .slop(5)
+                .in_order(true),
+                Query::span_term("field1", "foo"),
+            )
+            .boost(2),
+            json!({
+                "span_within": {
+                    "big": {
+                        "span_near": {
+                            "clauses": [
+                                { "span_term": { "field1": { "value": "bar" } } },
+                                { "span_term": { "field1": { "value": "baz" } } }
+                            ],
+                            "slop": 5,
+                        }
+                    },
+                    "little": { "span_term": { "field1": { "value": "foo" } } },
+                    "boost": 2
+                }
+            }),
+        );
+    }
+}
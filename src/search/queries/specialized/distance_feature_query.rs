@@ -17,6 +17,10 @@ impl Origin for GeoPoint {
     type Pivot = Distance;
 }
 
+impl Origin for DateMath {
+    type Pivot = Time;
+}
+
 /// Boosts the [relevance score](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-filter-context.html#relevance-scores)
 /// of documents closer to a provided `origin` date or point.
 /// For example, you can use this query to give more weight to documents
@@ -62,10 +66,26 @@ impl Origin for GeoPoint {
 /// # use elasticsearch_dsl::queries::params::*;
 /// # use chrono::prelude::*;
 /// # let query =
-/// Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Time::Days(7))
+/// Query::distance_feature("test", Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(), Time::Days(7))
 ///     .boost(1.5)
 ///     .name("test");
 /// ```
+/// To create distance feature query date query with a `chrono` duration pivot:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # use chrono::prelude::*;
+/// # let query =
+/// Query::distance_feature("test", Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(), chrono::Duration::hours(36).into());
+/// ```
+/// To create distance feature query with a date math origin:
+/// ```
+/// # use elasticsearch_dsl::{DateMath, Time};
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::distance_feature("test", DateMath::now(), Time::Days(7));
+/// ```
 /// To create distance feature query geo query:
 /// ```
 /// # use elasticsearch_dsl::{Distance, GeoPoint};
@@ -84,7 +104,7 @@ impl Origin for GeoPoint {
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # let query =
-/// Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Distance::Kilometers(15))
+/// Query::distance_feature("test", Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(), Distance::Kilometers(15))
 ///     .boost(1.5)
 ///     .name("test");
 /// ```
@@ -139,7 +159,7 @@ impl Query {
     /// [`date_nanos`](https://www.elastic.co/guide/en/elasticsearch/reference/current/date_nanos.html)
     /// field, the `pivot` value must be a
     /// [`time unit`](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#time-units)
-    /// , such as `1h` or `10d`.<br>
+    /// , such as `1h` or `10d`. `chrono` and `std` durations convert into a [`Time`].<br>
     /// If the `field` value is a
     /// [`geo_point`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
     /// field, the `pivot` value must be a
@@ -176,7 +196,11 @@ mod tests {
     #[test]
     fn serialization() {
         assert_serialize(
-            Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Time::Days(7)),
+            Query::distance_feature(
+                "test",
+                Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(),
+                Time::Days(7),
+            ),
             json!({
                 "distance_feature": {
                     "field": "test",
@@ -187,9 +211,13 @@ mod tests {
         );
 
         assert_serialize(
-            Query::distance_feature("test", Utc.ymd(2014, 7, 8).and_hms(9, 1, 0), Time::Days(7))
-                .boost(1.5)
-                .name("test"),
+            Query::distance_feature(
+                "test",
+                Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(),
+                Time::Days(7),
+            )
+            .boost(1.5)
+            .name("test"),
             json!({
                 "distance_feature": {
                     "field": "test",
@@ -233,5 +261,20 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::distance_feature(
+                "production_date",
+                DateMath::new("now-1d/d"),
+                Time::Hours(12),
+            ),
+            json!({
+                "distance_feature": {
+                    "field": "production_date",
+                    "origin": "now-1d/d",
+                    "pivot": "12h",
+                }
+            }),
+        );
     }
 }
@@ -0,0 +1,238 @@
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// Finds the k nearest vectors to a query vector, as measured by a similarity metric. Unlike
+/// the top-level [`Knn`] search, the `knn` query can be combined with other queries, e.g.
+/// within a `bool` query for hybrid search.
+///
+/// To create a kNN query with a raw query vector:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let query =
+/// Query::bool()
+///     .should(Query::knn("image-vector", [54.0, 10.0, -2.0]).num_candidates(50))
+///     .should(Query::r#match("title", "mountain lake"));
+/// ```
+/// To create a kNN query with a vector computed from text:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let query =
+/// Query::knn_query_vector_builder(
+///     "content-vector",
+///     QueryVectorBuilder::text_embedding("my-text-embedding-model", "The opposite of blue"),
+/// )
+/// .num_candidates(100);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-knn-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnnQuery {
+    #[serde(rename = "knn")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<Vec<f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector_builder: Option<QueryVectorBuilder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    num_candidates: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Queries,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`KnnQuery`] with a raw query vector
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector` - Query vector. Must have the same number of dimensions as the vector
+    ///   field you are searching against.
+    pub fn knn<T>(field: impl ToString, query_vector: T) -> KnnQuery
+    where
+        T: IntoIterator,
+        T::Item: Into<f32>,
+    {
+        KnnQuery {
+            inner: Inner {
+                field: field.to_string(),
+                query_vector: Some(query_vector.into_iter().map(Into::into).collect()),
+                query_vector_builder: None,
+                num_candidates: None,
+                filter: Default::default(),
+                similarity: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`KnnQuery`] with a query vector built by Elasticsearch
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector_builder` - A configuration object indicating how to build a query vector
+    ///   before executing the request
+    pub fn knn_query_vector_builder(
+        field: impl ToString,
+        query_vector_builder: impl Into<QueryVectorBuilder>,
+    ) -> KnnQuery {
+        KnnQuery {
+            inner: Inner {
+                field: field.to_string(),
+                query_vector: None,
+                query_vector_builder: Some(query_vector_builder.into()),
+                num_candidates: None,
+                filter: Default::default(),
+                similarity: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl KnnQuery {
+    /// The number of nearest neighbor candidates to consider per shard. Cannot exceed 10,000.
+    ///
+    /// Defaults to the search request `size`.
+    pub fn num_candidates(mut self, num_candidates: impl TryInto<u64>) -> Self {
+        if let Ok(num_candidates) = num_candidates.try_into() {
+            self.inner.num_candidates = Some(num_candidates);
+        }
+        self
+    }
+
+    /// Query to filter the documents that can match. The kNN query returns the nearest
+    /// documents that also match this filter.
+    pub fn filter(mut self, filter: impl Into<Queries>) -> Self {
+        self.inner.filter.extend(filter);
+        self
+    }
+
+    /// The minimum similarity required for a document to be considered a match
+    pub fn similarity(mut self, similarity: impl Into<f32>) -> Self {
+        self.inner.similarity = Some(similarity.into());
+        self
+    }
+
+    add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        self.inner.filter.iter().collect()
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        self.inner.filter.iter_mut().collect()
+    }
+}
+
+impl ShouldSkip for KnnQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+            || (self.inner.query_vector.should_skip()
+                && self.inner.query_vector_builder.should_skip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::knn("image-vector", [54.0, 10.0, -2.0]),
+            json!({
+                "knn": {
+                    "field": "image-vector",
+                    "query_vector": [54.0, 10.0, -2.0]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::knn("image-vector", [54.0, 10.0, -2.0])
+                .num_candidates(50)
+                .filter(Query::term("file-type", "png"))
+                .similarity(36.0)
+                .boost(2)
+                .name("test"),
+            json!({
+                "knn": {
+                    "field": "image-vector",
+                    "query_vector": [54.0, 10.0, -2.0],
+                    "num_candidates": 50,
+                    "filter": [
+                        { "term": { "file-type": { "value": "png" } } }
+                    ],
+                    "similarity": 36.0,
+                    "boost": 2,
+                    "_name": "test"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::knn_query_vector_builder(
+                "dense-vector-field",
+                QueryVectorBuilder::text_embedding("my-text-embedding-model", "rocky mountain"),
+            ),
+            json!({
+                "knn": {
+                    "field": "dense-vector-field",
+                    "query_vector_builder": {
+                        "text_embedding": {
+                            "model_id": "my-text-embedding-model",
+                            "model_text": "rocky mountain"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn hybrid_search() {
+        assert_serialize(
+            Query::bool()
+                .should(Query::knn("image-vector", [1.0, 2.0]).boost(2))
+                .should(Query::knn("", [1.0, 2.0]))
+                .should(Query::r#match("title", "mountain lake")),
+            json!({
+                "bool": {
+                    "should": [
+                        {
+                            "knn": {
+                                "field": "image-vector",
+                                "query_vector": [1.0, 2.0],
+                                "boost": 2
+                            }
+                        },
+                        {
+                            "match": {
+                                "title": {
+                                    "query": "mountain lake"
+                                }
+                            }
+                        }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -479,5 +479,36 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::more_like_this([
+                Like::from(Document::new("1").index("imdb")),
+                Like::from("Once upon a time"),
+            ])
+            .fields(["title", "description"])
+            .unlike([Document::new("2").index("imdb")])
+            .min_term_freq(1)
+            .min_doc_freq(5)
+            .max_query_terms(12)
+            .stop_words(["the", "a"])
+            .analyzer("english"),
+            json!({
+                "more_like_this": {
+                    "fields": ["title", "description"],
+                    "like": [
+                        { "_id": "1", "_index": "imdb" },
+                        "Once upon a time"
+                    ],
+                    "unlike": [
+                        { "_id": "2", "_index": "imdb" }
+                    ],
+                    "min_term_freq": 1,
+                    "min_doc_freq": 5,
+                    "max_query_terms": 12,
+                    "stop_words": ["the", "a"],
+                    "analyzer": "english"
+                }
+            }),
+        );
     }
 }
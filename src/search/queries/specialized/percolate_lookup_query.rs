@@ -40,6 +40,12 @@ struct Inner {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     name: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
 }
 
 impl Query {
@@ -63,6 +69,8 @@ impl Query {
                 preference: None,
                 version: None,
                 name: None,
+                boost: None,
+                _name: None,
             },
         }
     }
@@ -107,9 +115,37 @@ impl PercolateLookupQuery {
         self.inner.name = Some(name.to_string());
         self
     }
+
+    /// Floating point number used to decrease or increase the relevance scores of a query.
+    /// Boost values are relative to the default value of `1.0`.
+    pub fn boost<B>(mut self, boost: B) -> Self
+    where
+        B: TryInto<Boost>,
+    {
+        if let Ok(boost) = boost.try_into() {
+            self.inner.boost = non_default(boost, 1);
+        }
+        self
+    }
+
+    /// You can use named queries to track which queries matched returned documents. As `name`
+    /// sets the document slot suffix of the percolator, the query name is set with this method.
+    pub fn query_name<S>(mut self, name: S) -> Self
+    where
+        S: ToString,
+    {
+        self.inner._name = Some(name.to_string());
+        self
+    }
 }
 
-impl ShouldSkip for PercolateLookupQuery {}
+impl ShouldSkip for PercolateLookupQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+            || self.inner.index.should_skip()
+            || self.inner.id.should_skip()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -133,7 +169,9 @@ mod tests {
                 .name("toast")
                 .routing("routing_value")
                 .preference("preference_value")
-                .version(123),
+                .version(123)
+                .boost(2)
+                .query_name("test"),
             json!({
                 "percolate": {
                     "field": "field_name",
@@ -143,8 +181,17 @@ mod tests {
                     "routing": "routing_value",
                     "preference": "preference_value",
                     "version": 123,
+                    "boost": 2,
+                    "_name": "test",
                 }
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(!Query::percolate_lookup("field_name", "index_name", "document_id").should_skip());
+        assert!(Query::percolate_lookup("field_name", "index_name", "").should_skip());
+        assert!(Query::percolate_lookup("field_name", "", "document_id").should_skip());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::convert::TryInto;
 
 /// The `percolate` query can be used to match queries stored in an index. The percolate query
 /// itself contains the document that will be used as query to match with the stored queries.
@@ -35,6 +36,12 @@ struct Inner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     name: Option<String>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+
     #[serde(flatten)]
     source: PercolateSource,
 }
@@ -61,6 +68,8 @@ impl Query {
                 field: field.to_string(),
                 source,
                 name: None,
+                boost: None,
+                _name: None,
             },
         }
     }
@@ -76,6 +85,28 @@ impl PercolateQuery {
         self.inner.name = Some(name.to_string());
         self
     }
+
+    /// Floating point number used to decrease or increase the relevance scores of a query.
+    /// Boost values are relative to the default value of `1.0`.
+    pub fn boost<B>(mut self, boost: B) -> Self
+    where
+        B: TryInto<Boost>,
+    {
+        if let Ok(boost) = boost.try_into() {
+            self.inner.boost = non_default(boost, 1);
+        }
+        self
+    }
+
+    /// You can use named queries to track which queries matched returned documents. As `name`
+    /// sets the document slot suffix of the percolator, the query name is set with this method.
+    pub fn query_name<S>(mut self, name: S) -> Self
+    where
+        S: ToString,
+    {
+        self.inner._name = Some(name.to_string());
+        self
+    }
 }
 
 impl ShouldSkip for PercolateQuery {
@@ -161,6 +192,22 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Query::percolate("field_name", json!({"message": "lol"}))
+                .name("toast")
+                .query_name("test"),
+            json!({
+                "percolate": {
+                    "field": "field_name",
+                    "name": "toast",
+                    "_name": "test",
+                    "document": {
+                        "message": "lol"
+                    }
+                }
+            }),
+        );
+
         assert_serialize(
             Query::percolate("field_name", [json!({"message": "lol"})]),
             json!({
@@ -176,11 +223,14 @@ mod tests {
         );
 
         assert_serialize(
-            Query::percolate("field_name", [json!({"message": "lol"})]).name("toast"),
+            Query::percolate("field_name", [json!({"message": "lol"})])
+                .name("toast")
+                .boost(1.5),
             json!({
                 "percolate": {
                     "field": "field_name",
                     "name": "toast",
+                    "boost": 1.5,
                     "documents": [
                         {
                             "message": "lol"
@@ -1,19 +1,21 @@
 use crate::search::*;
 use crate::util::*;
 
-/// The most simple query, which matches all documents, giving them all a
-/// `_score` of `1.0`.
+/// Promotes selected documents to rank higher than those matching a given query. This feature is
+/// typically used to guide searchers to curated documents that are promoted over and above any
+/// "organic" matches for a search. The promoted or "pinned" documents are identified using the
+/// document IDs stored in the `_id` field, and are returned in the order they were listed.
 ///
-/// To create match_all query:
+/// To create pinned query:
 /// ```
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # let query =
 /// Query::pinned(PinnedQueryValues::ids([1]), Query::term("user_id", 2))
 ///     .boost(2)
-///     .name("matches_everything");
+///     .name("promoted");
 /// ```
-/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-all-query.html>
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-pinned-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PinnedQuery {
     #[serde(rename = "pinned")]
@@ -38,6 +40,10 @@ struct Inner {
 
 impl Query {
     /// Creates an instance of [`PinnedQuery`]
+    ///
+    /// - `values` - IDs or documents to pin, in the order they are to appear in results
+    /// - `organic` - Any choice of query used to rank documents which will be ranked below the
+    ///   pinned documents
     pub fn pinned<Q>(values: PinnedQueryValues, organic: Q) -> PinnedQuery
     where
         Q: Into<Query>,
@@ -55,6 +61,14 @@ impl Query {
 
 impl PinnedQuery {
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.organic.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.organic.as_mut()]
+    }
 }
 
 impl ShouldSkip for PinnedQuery {
@@ -104,6 +118,32 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Query::pinned(PinnedQueryValues::ids([3, 1, 2, 1]), Query::match_all()),
+            json!({
+                "pinned": {
+                    "ids": ["3", "1", "2"],
+                    "organic": { "match_all": {} }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::pinned(
+                PinnedQueryValues::docs([PinnedDocument::new("b", 1), PinnedDocument::new("a", 2)]),
+                Query::match_all(),
+            ),
+            json!({
+                "pinned": {
+                    "docs": [
+                        { "_index": "b", "_id": "1" },
+                        { "_index": "a", "_id": "2" }
+                    ],
+                    "organic": { "match_all": {} }
+                }
+            }),
+        );
+
         assert_serialize(
             Query::pinned(PinnedQueryValues::ids([1]), Query::term("user_id", 2))
                 .boost(2)
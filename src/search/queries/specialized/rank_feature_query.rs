@@ -258,6 +258,22 @@ impl Query {
             },
         }
     }
+
+    /// Creates an instance of [`RankFeatureQuery`] targeting a single feature of a
+    /// `rank_features` field, e.g. `topics.politics`
+    ///
+    /// - `field` - `rank_features` field holding the feature map
+    /// - `feature` - Name of the feature inside the map
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::queries::*;
+    /// # use elasticsearch_dsl::queries::params::*;
+    /// # let query =
+    /// Query::rank_features("topics", "politics").saturation();
+    /// ```
+    pub fn rank_features(field: impl AsRef<str>, feature: impl AsRef<str>) -> RankFeatureQuery {
+        Self::rank_feature(format!("{}.{}", field.as_ref(), feature.as_ref()))
+    }
 }
 
 impl RankFeatureQuery {
@@ -323,6 +339,10 @@ impl RankFeatureQuery {
     /// to preserve only 9 significant bits for the precision. If a rank feature field is indexed
     /// with `"positive_score_impact": false`, its indexed value is equal to `1/S` and rounded to
     /// preserve only 9 significant bits for the precision.
+    ///
+    /// > **Note**<br/>
+    /// > Since the indexed value of a negative score impact feature is already `1/S`, `linear`
+    /// > scores still decrease when `S` increases and no further inversion is needed.
     pub fn linear(self) -> RankFeatureLinearQuery {
         RankFeatureLinearQuery {
             inner: InnerLinear {
@@ -359,11 +379,35 @@ impl RankFeatureLinearQuery {
     add_boost_and_name!();
 }
 
-impl ShouldSkip for RankFeatureQuery {}
-impl ShouldSkip for RankFeatureSaturationQuery {}
-impl ShouldSkip for RankFeatureLogarithmQuery {}
-impl ShouldSkip for RankFeatureSigmoidQuery {}
-impl ShouldSkip for RankFeatureLinearQuery {}
+impl ShouldSkip for RankFeatureQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
+
+impl ShouldSkip for RankFeatureSaturationQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
+
+impl ShouldSkip for RankFeatureLogarithmQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
+
+impl ShouldSkip for RankFeatureSigmoidQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
+
+impl ShouldSkip for RankFeatureLinearQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -459,6 +503,16 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Query::rank_features("topics", "politics").linear(),
+            json!({
+                "rank_feature": {
+                    "field": "topics.politics",
+                    "linear": {},
+                }
+            }),
+        );
+
         assert_serialize(
             Query::rank_feature("test").linear().boost(2).name("query"),
             json!({
@@ -471,4 +525,27 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::rank_feature("").should_skip());
+        assert!(Query::rank_feature("").saturation().should_skip());
+        assert!(Query::rank_feature("").logarithm(1.0).should_skip());
+        assert!(Query::rank_feature("").sigmoid(1.0, 0.5).should_skip());
+        assert!(Query::rank_feature("").linear().should_skip());
+        assert!(!Query::rank_feature("pagerank").saturation().should_skip());
+
+        assert_serialize(
+            Query::bool()
+                .should(Query::rank_feature("").saturation())
+                .should(Query::rank_feature("pagerank").saturation()),
+            json!({
+                "bool": {
+                    "should": [
+                        { "rank_feature": { "field": "pagerank", "saturation": {} } }
+                    ]
+                }
+            }),
+        );
+    }
 }
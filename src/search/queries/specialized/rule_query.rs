@@ -0,0 +1,157 @@
+use crate::search::*;
+use crate::util::*;
+use serde_json::{Map, Value};
+
+/// Applies [query rules](crate::query_rules) to the query before returning results. Query rules
+/// pin or exclude documents when the `match_criteria` meet the criteria of a rule, while the
+/// remaining results are ranked by the "organic" query.
+///
+/// To create rule query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::rule(["my-ruleset"], Query::r#match("description", "puggles"))
+///     .match_criteria("user_query", "pugs")
+///     .match_criteria("user_country", "us")
+///     .boost(2)
+///     .name("curated");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rule-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleQuery {
+    #[serde(rename = "rule")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    organic: Box<Query>,
+
+    ruleset_ids: Vec<String>,
+
+    match_criteria: Map<String, Value>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`RuleQuery`]
+    ///
+    /// - `ruleset_ids` - IDs of the query rulesets to apply, see
+    ///   [`QueryRuleset`](crate::QueryRuleset)
+    /// - `organic` - Any choice of query used to rank the documents that are not affected by
+    ///   the rules
+    pub fn rule<I, Q>(ruleset_ids: I, organic: Q) -> RuleQuery
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+        Q: Into<Query>,
+    {
+        RuleQuery {
+            inner: Inner {
+                organic: Box::new(organic.into()),
+                ruleset_ids: ruleset_ids.into_iter().map(|id| id.to_string()).collect(),
+                match_criteria: Map::new(),
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl RuleQuery {
+    /// Criteria metadata matched against the criteria of the rules, such as the `user_query`.
+    /// Rules referencing metadata that is not provided do not apply.
+    pub fn match_criteria<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: serde::Serialize,
+    {
+        if let Ok(value) = serde_json::to_value(value) {
+            let _ = self.inner.match_criteria.insert(key.to_string(), value);
+        }
+        self
+    }
+
+    add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.organic.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.organic.as_mut()]
+    }
+}
+
+impl ShouldSkip for RuleQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.organic.should_skip() || self.inner.ruleset_ids.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::rule(["my-ruleset"], Query::r#match("description", "puggles")),
+            json!({
+                "rule": {
+                    "organic": {
+                        "match": {
+                            "description": {
+                                "query": "puggles"
+                            }
+                        }
+                    },
+                    "ruleset_ids": ["my-ruleset"],
+                    "match_criteria": {}
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::rule(
+                ["my-ruleset", "seasonal"],
+                Query::r#match("description", "puggles"),
+            )
+            .match_criteria("user_query", "pugs")
+            .match_criteria("user_age", 25)
+            .boost(2)
+            .name("curated"),
+            json!({
+                "rule": {
+                    "organic": {
+                        "match": {
+                            "description": {
+                                "query": "puggles"
+                            }
+                        }
+                    },
+                    "ruleset_ids": ["my-ruleset", "seasonal"],
+                    "match_criteria": {
+                        "user_query": "pugs",
+                        "user_age": 25
+                    },
+                    "boost": 2,
+                    "_name": "curated"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::rule(["my-ruleset"], Query::term("user", "")).should_skip());
+        assert!(Query::rule(Vec::<String>::new(), Query::term("user", "kimchy")).should_skip());
+        assert!(!Query::rule(["my-ruleset"], Query::term("user", "kimchy")).should_skip());
+    }
+}
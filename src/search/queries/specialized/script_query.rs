@@ -78,5 +78,38 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::script(
+                Script::source("doc['amount'].value < params.max")
+                    .lang(ScriptLang::Painless)
+                    .param("max", 10),
+            ),
+            json!({
+                "script": {
+                    "script": {
+                        "source": "doc['amount'].value < params.max",
+                        "lang": "painless",
+                        "params": {
+                            "max": 10
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::script(Script::id("amount-below").param("max", 10)),
+            json!({
+                "script": {
+                    "script": {
+                        "id": "amount-below",
+                        "params": {
+                            "max": 10
+                        }
+                    }
+                }
+            }),
+        );
     }
 }
@@ -62,7 +62,22 @@ impl Query {
 }
 
 impl ScriptScoreQuery {
+    /// Documents with a score lower than this floating point number are excluded from the search
+    /// results
+    pub fn min_score(mut self, min_score: impl Into<f32>) -> Self {
+        self.inner.min_score = Some(min_score.into());
+        self
+    }
+
     add_boost_and_name!();
+
+    pub(crate) fn children(&self) -> Vec<&Query> {
+        vec![self.inner.query.as_ref()]
+    }
+
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut Query> {
+        vec![self.inner.query.as_mut()]
+    }
 }
 
 impl ShouldSkip for ScriptScoreQuery {}
@@ -91,5 +106,30 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::script_score(
+                Query::r#match("message", "elasticsearch"),
+                Script::source(
+                    "decayNumericLinear(params.origin, params.scale, 0, 0.5, doc['n'].value)",
+                )
+                .param("origin", 20)
+                .param("scale", 10),
+            )
+            .min_score(0.5),
+            json!({
+                "script_score": {
+                    "query": { "match": { "message": { "query": "elasticsearch" } } },
+                    "script": {
+                        "source": "decayNumericLinear(params.origin, params.scale, 0, 0.5, doc['n'].value)",
+                        "params": {
+                            "origin": 20,
+                            "scale": 10
+                        }
+                    },
+                    "min_score": 0.5
+                }
+            }),
+        );
     }
 }
@@ -0,0 +1,217 @@
+use crate::search::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// The sparse vector query executes a query consisting of sparse vectors, such as built by a
+/// learned sparse retrieval model like ELSER. The query vector is either computed at query time
+/// by an inference endpoint or provided as pre-computed token-weight pairs.
+///
+/// To create a sparse vector query using an inference endpoint:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::sparse_vector("ml.tokens", "my-elser-endpoint", "How is the weather in Jamaica?")
+///     .prune(true)
+///     .boost(2)
+///     .name("test");
+/// ```
+/// To create a sparse vector query with pre-computed tokens:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::sparse_vector_query_vector("ml.tokens", [("weather", 1.5), ("jamaica", 2.1)]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-sparse-vector-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SparseVectorQuery {
+    #[serde(rename = "sparse_vector")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inference_id: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<BTreeMap<String, f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prune: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pruning_config: Option<PruningConfig>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SparseVectorQuery`] computing the query vector with an
+    /// inference endpoint
+    ///
+    /// - `field` - The name of the sparse vector field to search against
+    /// - `inference_id` - The inference endpoint used to convert the `query` into token-weight
+    ///   pairs. It must be the same model that was used to create the tokens from the indexed
+    ///   text.
+    /// - `query` - The query text you want to use for search
+    pub fn sparse_vector<T, U, V>(field: T, inference_id: U, query: V) -> SparseVectorQuery
+    where
+        T: ToString,
+        U: ToString,
+        V: ToString,
+    {
+        SparseVectorQuery {
+            inner: Inner {
+                field: field.to_string(),
+                inference_id: inference_id.to_string(),
+                query: query.to_string(),
+                query_vector: None,
+                prune: None,
+                pruning_config: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`SparseVectorQuery`] with a pre-computed query vector
+    ///
+    /// - `field` - The name of the sparse vector field to search against
+    /// - `query_vector` - Token-weight pairs to search for, as generated by the same model
+    ///   that was used to create the tokens from the indexed text
+    pub fn sparse_vector_query_vector<T, I, K, V>(field: T, query_vector: I) -> SparseVectorQuery
+    where
+        T: ToString,
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: Into<f32>,
+    {
+        SparseVectorQuery {
+            inner: Inner {
+                field: field.to_string(),
+                inference_id: String::new(),
+                query: String::new(),
+                query_vector: Some(
+                    query_vector
+                        .into_iter()
+                        .map(|(token, weight)| (token.to_string(), weight.into()))
+                        .collect(),
+                ),
+                prune: None,
+                pruning_config: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl SparseVectorQuery {
+    /// Whether to leave out tokens that are frequent across the corpus and carry little
+    /// weight, which speeds up the query significantly. Defaults to `false`.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.inner.prune = Some(prune);
+        self
+    }
+
+    /// Thresholds deciding which tokens are pruned when [`prune`](Self::prune) is enabled,
+    /// see [`PruningConfig`]
+    pub fn pruning_config(mut self, pruning_config: PruningConfig) -> Self {
+        self.inner.pruning_config = Some(pruning_config);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for SparseVectorQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+            || match &self.inner.query_vector {
+                Some(query_vector) => query_vector.is_empty(),
+                None => self.inner.inference_id.should_skip() || self.inner.query.should_skip(),
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::sparse_vector("ml.tokens", "my-elser-endpoint", "How is the weather?"),
+            json!({
+                "sparse_vector": {
+                    "field": "ml.tokens",
+                    "inference_id": "my-elser-endpoint",
+                    "query": "How is the weather?"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::sparse_vector("ml.tokens", "my-elser-endpoint", "How is the weather?")
+                .prune(true)
+                .pruning_config(
+                    PruningConfig::new()
+                        .tokens_freq_ratio_threshold(5)
+                        .tokens_weight_threshold(0.4)
+                        .only_score_pruned_tokens(false),
+                )
+                .boost(2)
+                .name("test"),
+            json!({
+                "sparse_vector": {
+                    "field": "ml.tokens",
+                    "inference_id": "my-elser-endpoint",
+                    "query": "How is the weather?",
+                    "prune": true,
+                    "pruning_config": {
+                        "tokens_freq_ratio_threshold": 5,
+                        "tokens_weight_threshold": 0.4,
+                        "only_score_pruned_tokens": false
+                    },
+                    "boost": 2,
+                    "_name": "test"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::sparse_vector_query_vector("ml.tokens", [("weather", 1.5), ("jamaica", 2.0)]),
+            json!({
+                "sparse_vector": {
+                    "field": "ml.tokens",
+                    "query_vector": {
+                        "jamaica": 2.0,
+                        "weather": 1.5
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::sparse_vector("ml.tokens", "my-elser-endpoint", "").should_skip());
+        assert!(Query::sparse_vector("", "my-elser-endpoint", "weather").should_skip());
+        assert!(
+            Query::sparse_vector_query_vector("ml.tokens", Vec::<(String, f32)>::new())
+                .should_skip()
+        );
+        assert!(!Query::sparse_vector_query_vector("ml.tokens", [("weather", 1.5)]).should_skip());
+    }
+}
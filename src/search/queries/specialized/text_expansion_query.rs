@@ -0,0 +1,166 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// The text expansion query uses a natural language processing model, such as ELSER, to convert
+/// the query text into a list of token-weight pairs which are then used in a query against a
+/// sparse vector or rank features field.
+///
+/// To create a text expansion query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::text_expansion("ml.tokens", ".elser_model_2", "How is the weather in Jamaica?")
+///     .pruning_config(
+///         PruningConfig::new()
+///             .tokens_freq_ratio_threshold(5)
+///             .tokens_weight_threshold(0.4),
+///     )
+///     .boost(2)
+///     .name("test");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-text-expansion-query.html>
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextExpansionQuery {
+    field: String,
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    model_id: String,
+
+    model_text: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pruning_config: Option<PruningConfig>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`TextExpansionQuery`]
+    ///
+    /// - `field` - The name of the sparse vector or rank features field containing the tokens
+    ///   generated by the model
+    /// - `model_id` - The ID of the model to use to convert the query text into token-weight
+    ///   pairs. It must be the same model ID that was used to create the tokens from the input
+    ///   text.
+    /// - `model_text` - The query text you want to use for search
+    pub fn text_expansion<T, U, V>(field: T, model_id: U, model_text: V) -> TextExpansionQuery
+    where
+        T: ToString,
+        U: ToString,
+        V: ToString,
+    {
+        TextExpansionQuery {
+            field: field.to_string(),
+            inner: Inner {
+                model_id: model_id.to_string(),
+                model_text: model_text.to_string(),
+                pruning_config: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl TextExpansionQuery {
+    /// Prunes frequent and low weight tokens from the expanded query, see [`PruningConfig`]
+    pub fn pruning_config(mut self, pruning_config: PruningConfig) -> Self {
+        self.inner.pruning_config = Some(pruning_config);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for TextExpansionQuery {
+    fn should_skip(&self) -> bool {
+        self.field.should_skip()
+            || self.inner.model_id.should_skip()
+            || self.inner.model_text.should_skip()
+    }
+}
+
+impl Serialize for TextExpansionQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut hash = std::collections::HashMap::new();
+        let _ = hash.insert(&self.field, &self.inner);
+
+        let mut map = serializer.serialize_struct("TextExpansionQuery", 1)?;
+        map.serialize_field("text_expansion", &hash)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::text_expansion(
+                "ml.tokens",
+                ".elser_model_2",
+                "How is the weather in Jamaica?",
+            ),
+            json!({
+                "text_expansion": {
+                    "ml.tokens": {
+                        "model_id": ".elser_model_2",
+                        "model_text": "How is the weather in Jamaica?"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::text_expansion(
+                "ml.tokens",
+                ".elser_model_2",
+                "How is the weather in Jamaica?",
+            )
+            .pruning_config(
+                PruningConfig::new()
+                    .tokens_freq_ratio_threshold(5)
+                    .tokens_weight_threshold(0.4)
+                    .only_score_pruned_tokens(false),
+            )
+            .boost(2)
+            .name("test"),
+            json!({
+                "text_expansion": {
+                    "ml.tokens": {
+                        "model_id": ".elser_model_2",
+                        "model_text": "How is the weather in Jamaica?",
+                        "pruning_config": {
+                            "tokens_freq_ratio_threshold": 5,
+                            "tokens_weight_threshold": 0.4,
+                            "only_score_pruned_tokens": false
+                        },
+                        "boost": 2,
+                        "_name": "test"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::text_expansion("ml.tokens", ".elser_model_2", "").should_skip());
+        assert!(Query::text_expansion("", ".elser_model_2", "weather").should_skip());
+        assert!(!Query::text_expansion("ml.tokens", ".elser_model_2", "weather").should_skip());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::collections::BTreeMap;
+
+/// The weighted tokens query requires a list of token-weight pairs that are sent in with a
+/// query rather than calculated using a natural language processing model. These token pairs
+/// are then used in a query against a sparse vector or rank features field.
+///
+/// Weighted tokens queries are useful when you want to use an external query expansion model,
+/// or quickly prototype changes without reindexing a new model.
+///
+/// To create a weighted tokens query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::weighted_tokens("query_expansion_field", [("2161", 0.4), ("jamaica", 2.1)])
+///     .pruning_config(
+///         PruningConfig::new()
+///             .tokens_freq_ratio_threshold(5)
+///             .tokens_weight_threshold(0.4),
+///     )
+///     .boost(2)
+///     .name("test");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-weighted-tokens-query.html>
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedTokensQuery {
+    field: String,
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    tokens: BTreeMap<String, f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pruning_config: Option<PruningConfig>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<Boost>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`WeightedTokensQuery`]
+    ///
+    /// - `field` - The name of the sparse vector or rank features field containing the tokens
+    /// - `tokens` - Token-weight pairs to search for, as generated by the same model that was
+    ///   used to create the tokens from the indexed text
+    pub fn weighted_tokens<T, I, K, V>(field: T, tokens: I) -> WeightedTokensQuery
+    where
+        T: ToString,
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: Into<f32>,
+    {
+        WeightedTokensQuery {
+            field: field.to_string(),
+            inner: Inner {
+                tokens: tokens
+                    .into_iter()
+                    .map(|(token, weight)| (token.to_string(), weight.into()))
+                    .collect(),
+                pruning_config: None,
+                boost: None,
+                _name: None,
+            },
+        }
+    }
+}
+
+impl WeightedTokensQuery {
+    /// Prunes frequent and low weight tokens from the query, see [`PruningConfig`]
+    pub fn pruning_config(mut self, pruning_config: PruningConfig) -> Self {
+        self.inner.pruning_config = Some(pruning_config);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for WeightedTokensQuery {
+    fn should_skip(&self) -> bool {
+        self.field.should_skip() || self.inner.tokens.is_empty()
+    }
+}
+
+impl Serialize for WeightedTokensQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut hash = std::collections::HashMap::new();
+        let _ = hash.insert(&self.field, &self.inner);
+
+        let mut map = serializer.serialize_struct("WeightedTokensQuery", 1)?;
+        map.serialize_field("weighted_tokens", &hash)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Query::weighted_tokens("ml.tokens", [("weather", 1.5), ("jamaica", 2.0)]),
+            json!({
+                "weighted_tokens": {
+                    "ml.tokens": {
+                        "tokens": {
+                            "jamaica": 2.0,
+                            "weather": 1.5
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::weighted_tokens("ml.tokens", [("weather", 1.5)])
+                .pruning_config(
+                    PruningConfig::new()
+                        .tokens_freq_ratio_threshold(5)
+                        .tokens_weight_threshold(0.4)
+                        .only_score_pruned_tokens(false),
+                )
+                .boost(2)
+                .name("test"),
+            json!({
+                "weighted_tokens": {
+                    "ml.tokens": {
+                        "tokens": {
+                            "weather": 1.5
+                        },
+                        "pruning_config": {
+                            "tokens_freq_ratio_threshold": 5,
+                            "tokens_weight_threshold": 0.4,
+                            "only_score_pruned_tokens": false
+                        },
+                        "boost": 2,
+                        "_name": "test"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::weighted_tokens("", [("weather", 1.5)]).should_skip());
+        assert!(Query::weighted_tokens("ml.tokens", Vec::<(String, f32)>::new()).should_skip());
+        assert!(!Query::weighted_tokens("ml.tokens", [("weather", 1.5)]).should_skip());
+    }
+}
@@ -1,7 +1,8 @@
 use crate::search::*;
 use crate::util::*;
 
-/// A query that accepts any other query as base64 encoded string.
+/// A query that accepts any other query as base64 encoded string. JSON values and DSL built
+/// queries are encoded on the fly, see [`WrappedQuery`].
 ///
 /// This query is more useful in the context of the Java high-level REST client
 /// or transport client to also accept queries as json formatted string. In
@@ -15,6 +16,25 @@ use crate::util::*;
 /// # let query =
 /// Query::wrapper("eyJ0ZXJtIiA6IHsgInVzZXIuaWQiIDogImtpbWNoeSIgfX0=");
 /// ```
+/// To wrap a hand-written query fragment:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # use serde_json::json;
+/// # let query =
+/// Query::wrapper(json!({ "term": { "user.id": "kimchy" } }));
+/// ```
+/// The `wrapper` query accepts no parameters besides the encoded query. To boost or name it,
+/// wrap it in a [`bool`](Query::bool) or [`constant_score`](Query::constant_score) query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::bool()
+///     .must(Query::wrapper("eyJ0ZXJtIiA6IHsgInVzZXIuaWQiIDogImtpbWNoeSIgfX0="))
+///     .boost(2)
+///     .name("test");
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-wrapper-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 pub struct WrapperQuery {
@@ -24,24 +44,30 @@ pub struct WrapperQuery {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 struct Inner {
-    query: String,
+    query: WrappedQuery,
 }
 
 impl Query {
     /// Creates an instance of [`WrapperQuery`]
-    pub fn wrapper<S>(query: S) -> WrapperQuery
+    ///
+    /// - `query` - Base64 encoded query, or a JSON value or query to encode
+    pub fn wrapper<Q>(query: Q) -> WrapperQuery
     where
-        S: ToString,
+        Q: Into<WrappedQuery>,
     {
         WrapperQuery {
             inner: Inner {
-                query: query.to_string(),
+                query: query.into(),
             },
         }
     }
 }
 
-impl ShouldSkip for WrapperQuery {}
+impl ShouldSkip for WrapperQuery {
+    fn should_skip(&self) -> bool {
+        self.inner.query.should_skip()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -53,5 +79,15 @@ mod tests {
             Query::wrapper("eyJ0ZXJtIiA6IHsgInVzZXIuaWQiIDogImtpbWNoeSIgfX0="),
             json!({ "wrapper": { "query": "eyJ0ZXJtIiA6IHsgInVzZXIuaWQiIDogImtpbWNoeSIgfX0=" } }),
         );
+
+        assert_serialize(
+            Query::wrapper(json!({ "term": { "user.id": "kimchy" } })),
+            json!({ "wrapper": { "query": "eyJ0ZXJtIjp7InVzZXIuaWQiOiJraW1jaHkifX0=" } }),
+        );
+
+        assert_serialize(
+            Query::wrapper(Query::from(Query::match_all())),
+            json!({ "wrapper": { "query": "eyJtYXRjaF9hbGwiOnt9fQ==" } }),
+        );
     }
 }
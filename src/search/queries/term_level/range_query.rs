@@ -37,7 +37,7 @@ struct Inner {
     lte: Term,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    format: Option<String>,
+    format: Option<DateFormat>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     relation: Option<RangeRelation>,
@@ -105,13 +105,12 @@ impl RangeQuery {
     /// [date `format`](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
     /// provided in the `<field>`'s mapping. This value overrides that mapping format.
     ///
-    /// For valid syntax, see
-    /// [`format`](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html).
+    /// For valid syntax, see [`DateFormat`].
     ///
     /// >If a format or date value is incomplete, the range query replaces
     /// any missing components with default values. See
     /// [Missing date components](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-range-query.html#missing-date-components).
-    pub fn format(mut self, format: impl Into<String>) -> Self {
+    pub fn format(mut self, format: impl Into<DateFormat>) -> Self {
         self.inner.format = Some(format.into());
         self
     }
@@ -218,10 +217,10 @@ mod tests {
 
         assert_serialize(
             Query::range("test_date_field")
-                .gt(Utc.ymd(2014, 11, 28).and_hms(12, 0, 1))
-                .gte(Utc.ymd(2014, 11, 28).and_hms(12, 0, 2))
-                .lt(Utc.ymd(2014, 11, 28).and_hms(12, 0, 3))
-                .lte(Utc.ymd(2014, 11, 28).and_hms(12, 0, 4))
+                .gt(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 1).unwrap())
+                .gte(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 2).unwrap())
+                .lt(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 3).unwrap())
+                .lte(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 4).unwrap())
                 .relation(RangeRelation::Contains)
                 .format("yyyy-MM-dd")
                 .time_zone("UTC")
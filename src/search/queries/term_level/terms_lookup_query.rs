@@ -107,7 +107,16 @@ impl TermsLookupQuery {
     }
 }
 
-impl ShouldSkip for TermsLookupQuery {}
+impl ShouldSkip for TermsLookupQuery {
+    fn should_skip(&self) -> bool {
+        let lookup = &self.inner.pair.value;
+
+        self.inner.pair.key.should_skip()
+            || lookup.index.should_skip()
+            || lookup.id.should_skip()
+            || lookup.path.should_skip()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -147,4 +156,13 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::terms_lookup("", "index", "id", "path").should_skip());
+        assert!(Query::terms_lookup("test", "", "id", "path").should_skip());
+        assert!(Query::terms_lookup("test", "index", " ", "path").should_skip());
+        assert!(Query::terms_lookup("test", "index", "id", "").should_skip());
+        assert!(!Query::terms_lookup("test", "index", "id", "path").should_skip());
+    }
 }
@@ -1,5 +1,11 @@
 use crate::search::*;
 use crate::util::*;
+use std::fmt;
+
+/// Default maximum number of terms a `terms` query may hold, see the
+/// [`index.max_terms_count`](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-max-terms-count)
+/// index setting
+pub const MAX_TERMS_COUNT: usize = 65_536;
 
 /// Returns documents that contain one or more **exact** terms in a provided field.
 /// The terms query is the same as the term query, except you can search for multiple values.
@@ -20,6 +26,18 @@ use crate::util::*;
 ///     .boost(2)
 ///     .name("test");
 /// ```
+/// To guard against exceeding the terms limit when building filters from large ID lists:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # use elasticsearch_dsl::MAX_TERMS_COUNT;
+/// let ids: Vec<u64> = (0..100_000).collect();
+///
+/// assert!(Query::terms("id", ids.clone()).check_terms_count(MAX_TERMS_COUNT).is_err());
+///
+/// # let query =
+/// Query::terms("id", ids).chunked(MAX_TERMS_COUNT);
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TermsQuery {
@@ -50,7 +68,7 @@ impl Query {
     /// 65,536 terms. You can change this limit using the
     /// [`index.max_terms_count setting`](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-max-terms-count).<br>
     /// > To use the field values of an existing document as search terms,
-    /// use the [terms lookup](crate::TermsLookup) parameters.
+    /// use the [terms lookup](Query::terms_lookup) query.
     pub fn terms<S, I>(field: S, values: I) -> TermsQuery
     where
         S: Into<String>,
@@ -66,8 +84,80 @@ impl Query {
     }
 }
 
+/// Error returned by [`TermsQuery::check_terms_count`] when a query holds more terms than allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyTermsError {
+    /// Number of terms in the query
+    pub count: usize,
+
+    /// Maximum number of terms allowed
+    pub limit: usize,
+}
+
+impl fmt::Display for TooManyTermsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Terms query holds {} terms, which exceeds the limit of {}",
+            self.count, self.limit
+        )
+    }
+}
+
+impl std::error::Error for TooManyTermsError {}
+
 impl TermsQuery {
     add_boost_and_name!();
+
+    /// Number of distinct terms in the query
+    pub fn len(&self) -> usize {
+        self.inner.pair.value.len()
+    }
+
+    /// Returns `true` when the query holds no terms
+    pub fn is_empty(&self) -> bool {
+        self.inner.pair.value.is_empty()
+    }
+
+    /// Fails when the query holds more than `limit` terms, which Elasticsearch would reject.
+    /// Use [`MAX_TERMS_COUNT`] unless the `index.max_terms_count` setting of the target indices
+    /// was changed.
+    pub fn check_terms_count(self, limit: usize) -> Result<Self, TooManyTermsError> {
+        let count = self.len();
+
+        if count > limit {
+            Err(TooManyTermsError { count, limit })
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Splits the query into a `bool` query with a `should` clause per `chunk_size` terms, so
+    /// that no clause exceeds the terms limit. Boost and name are moved to the `bool` query.
+    ///
+    /// Returns the query unchanged when it holds no more than `chunk_size` terms or when
+    /// `chunk_size` is `0`.
+    pub fn chunked(self, chunk_size: usize) -> Query {
+        if chunk_size == 0 || self.len() <= chunk_size {
+            return self.into();
+        }
+
+        let Inner { pair, boost, _name } = self.inner;
+        let mut query = Query::bool().minimum_should_match(1);
+
+        for chunk in pair.value.chunks(chunk_size) {
+            query = query.should(Query::terms(pair.key.clone(), chunk));
+        }
+
+        if let Some(boost) = boost {
+            query = query.boost(boost);
+        }
+        if let Some(name) = _name {
+            query = query.name(name);
+        }
+
+        query.into()
+    }
 }
 
 impl ShouldSkip for TermsQuery {
@@ -99,6 +189,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checks_terms_count() {
+        assert!(Query::terms("test", [1, 2, 3]).check_terms_count(3).is_ok());
+        assert_eq!(
+            Query::terms("test", [1, 2, 3, 3, 4]).check_terms_count(3),
+            Err(TooManyTermsError { count: 4, limit: 3 })
+        );
+    }
+
+    #[test]
+    fn chunks_terms() {
+        assert_serialize(
+            Query::terms("test", [1, 2, 3]).chunked(3),
+            json!({ "terms": { "test": [1, 2, 3] } }),
+        );
+
+        assert_serialize(
+            Query::terms("test", [5, 4, 3, 2, 1])
+                .boost(2)
+                .name("ids")
+                .chunked(2),
+            json!({
+                "bool": {
+                    "should": [
+                        { "terms": { "test": [1, 2] } },
+                        { "terms": { "test": [3, 4] } },
+                        { "terms": { "test": [5] } }
+                    ],
+                    "minimum_should_match": "1",
+                    "boost": 2,
+                    "_name": "ids"
+                }
+            }),
+        );
+    }
+
     #[test]
     fn should_skip_when_there_are_no_values() {
         let values: Vec<Term> = Vec::new();
@@ -14,6 +14,19 @@ use serde::ser::{Serialize, SerializeMap, Serializer};
 /// Query::terms_set("test", [123], "required_matches");
 /// ```
 ///
+/// To create a terms_set query with a typed script:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::terms_set(
+///     "skills",
+///     ["rust", "elasticsearch", "kafka"],
+///     Script::source("Math.min(params.num_terms, doc['required_matches'].value)")
+///         .param("num_terms", 2),
+/// );
+/// ```
+///
 /// To create a terms_set query with script:
 /// ```
 /// # use elasticsearch_dsl::queries::*;
@@ -77,7 +90,7 @@ impl TermsSetQuery {
 
 impl ShouldSkip for TermsSetQuery {
     fn should_skip(&self) -> bool {
-        self.inner.terms.should_skip()
+        self.field.should_skip() || self.inner.terms.should_skip()
     }
 }
 
@@ -140,5 +153,54 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Query::terms_set(
+                "skills",
+                ["rust", "kafka"],
+                Script::id("required_skills")
+                    .lang("painless")
+                    .param("num_terms", 2),
+            ),
+            json!({
+                "terms_set": {
+                    "skills": {
+                        "terms": ["kafka", "rust"],
+                        "minimum_should_match_script": {
+                            "id": "required_skills",
+                            "lang": "painless",
+                            "params": {
+                                "num_terms": 2
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Query::terms_set(
+                "skills",
+                ["rust"],
+                TermsSetScript::new("doc['required_matches'].value"),
+            ),
+            json!({
+                "terms_set": {
+                    "skills": {
+                        "terms": ["rust"],
+                        "minimum_should_match_script": {
+                            "source": "doc['required_matches'].value"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::terms_set("", ["rust"], "required_matches").should_skip());
+        assert!(Query::terms_set("skills", Vec::<String>::new(), "required_matches").should_skip());
+        assert!(!Query::terms_set("skills", ["rust"], "required_matches").should_skip());
     }
 }
@@ -0,0 +1,258 @@
+use crate::search::*;
+
+/// Visits the queries of a query tree traversed with [`Query::walk`] or [`Query::walk_mut`].
+///
+/// To collect every field queried with a `term` query:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// struct TermCounter(usize);
+///
+/// impl QueryVisitor for TermCounter {
+///     fn visit(&mut self, query: &Query) {
+///         if let Query::Term(_) = query {
+///             self.0 += 1;
+///         }
+///     }
+/// }
+///
+/// let query = Query::from(
+///     Query::bool()
+///         .must(Query::term("user", "kimchy"))
+///         .filter(Query::nested("comments", Query::term("comments.user", "kimchy"))),
+/// );
+///
+/// let mut counter = TermCounter(0);
+/// query.walk(&mut counter);
+///
+/// assert_eq!(counter.0, 2);
+/// ```
+///
+/// To restrict every leaf query to a tenant:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// struct TenantFilter(&'static str);
+///
+/// impl QueryVisitor for TenantFilter {
+///     fn visit_mut(&mut self, query: &mut Query) {
+///         if !query.is_compound() {
+///             let leaf = std::mem::replace(query, Query::match_all().into());
+///             *query = Query::bool()
+///                 .must(leaf)
+///                 .filter(Query::term("tenant", self.0))
+///                 .into();
+///         }
+///     }
+/// }
+///
+/// let mut query = Query::from(Query::term("user", "kimchy"));
+/// query.walk_mut(&mut TenantFilter("acme"));
+///
+/// assert_eq!(query.clause_count(), 3);
+/// ```
+pub trait QueryVisitor {
+    /// Called for every query of the tree, parents before their clauses
+    fn visit(&mut self, _query: &Query) {}
+
+    /// Called for every query of the tree, clauses before their parents, so that replaced
+    /// queries are not traversed again.
+    ///
+    /// Clauses of span queries only accept span queries, or multi-term queries for the clause
+    /// of a `span_multi` query, so replacing them with any other query has no effect.
+    fn visit_mut(&mut self, _query: &mut Query) {}
+}
+
+impl Query {
+    /// Traverses the query tree depth first, calling [`QueryVisitor::visit`] for every query
+    /// before its clauses
+    pub fn walk<V>(&self, visitor: &mut V)
+    where
+        V: QueryVisitor + ?Sized,
+    {
+        visitor.visit(self);
+
+        for child in self.children() {
+            child.walk(visitor);
+        }
+
+        for clause in self.span_clauses() {
+            clause.walk(visitor);
+        }
+    }
+
+    /// Traverses the query tree depth first, calling [`QueryVisitor::visit_mut`] for every
+    /// query after its clauses
+    pub fn walk_mut<V>(&mut self, visitor: &mut V)
+    where
+        V: QueryVisitor + ?Sized,
+    {
+        for child in self.children_mut() {
+            child.walk_mut(visitor);
+        }
+
+        for clause in self.span_clauses_mut() {
+            clause.update(|mut query| {
+                query.walk_mut(visitor);
+                query
+            });
+        }
+
+        visitor.visit_mut(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[derive(Default)]
+    struct Recorder(Vec<usize>);
+
+    impl QueryVisitor for Recorder {
+        fn visit(&mut self, query: &Query) {
+            self.0.push(query.clause_count());
+        }
+
+        fn visit_mut(&mut self, query: &mut Query) {
+            self.0.push(query.clause_count());
+
+            if let Query::Term(_) = query {
+                *query = Query::term("replaced", true).into();
+            }
+        }
+    }
+
+    #[test]
+    fn walks_in_order() {
+        let mut query = Query::from(
+            Query::bool()
+                .must(Query::boosting(
+                    Query::term("a", 1),
+                    Query::term("b", 2),
+                    0.5,
+                ))
+                .should(Query::term("c", 3)),
+        );
+
+        let mut recorder = Recorder::default();
+        query.walk(&mut recorder);
+
+        assert_eq!(recorder.0, vec![5, 3, 1, 1, 1]);
+
+        let mut recorder = Recorder::default();
+        query.walk_mut(&mut recorder);
+
+        assert_eq!(recorder.0, vec![1, 1, 3, 1, 5]);
+
+        assert_serialize(
+            query,
+            json!({
+                "bool": {
+                    "must": [
+                        {
+                            "boosting": {
+                                "positive": { "term": { "replaced": { "value": true } } },
+                                "negative": { "term": { "replaced": { "value": true } } },
+                                "negative_boost": 0.5
+                            }
+                        }
+                    ],
+                    "should": [
+                        { "term": { "replaced": { "value": true } } }
+                    ]
+                }
+            }),
+        );
+    }
+
+    struct SpanTermRenamer;
+
+    impl QueryVisitor for SpanTermRenamer {
+        fn visit_mut(&mut self, query: &mut Query) {
+            if let Query::SpanTerm(_) = query {
+                *query = Query::span_term("masked", "value").into();
+            }
+        }
+    }
+
+    #[test]
+    fn walks_span_and_knn_filter_clauses() {
+        let mut query = Query::from(Query::knn("vector", vec![1.0, 2.0]).filter(Query::span_not(
+            Query::span_near([
+                Query::span_term("text", "quick"),
+                Query::span_term("text", "fox"),
+            ]),
+            Query::span_first(Query::span_term("text", "lazy"), 3),
+        )));
+
+        let mut recorder = Recorder::default();
+        query.walk(&mut recorder);
+
+        assert_eq!(recorder.0, vec![7, 6, 3, 1, 1, 2, 1]);
+
+        query.walk_mut(&mut SpanTermRenamer);
+
+        assert_serialize(
+            query,
+            json!({
+                "knn": {
+                    "field": "vector",
+                    "query_vector": [1.0, 2.0],
+                    "filter": [
+                        {
+                            "span_not": {
+                                "include": {
+                                    "span_near": {
+                                        "clauses": [
+                                            { "span_term": { "masked": { "value": "value" } } },
+                                            { "span_term": { "masked": { "value": "value" } } }
+                                        ]
+                                    }
+                                },
+                                "exclude": {
+                                    "span_first": {
+                                        "match": { "span_term": { "masked": { "value": "value" } } },
+                                        "end": 3
+                                    }
+                                }
+                            }
+                        }
+                    ]
+                }
+            }),
+        );
+    }
+
+    struct LeafWrapper;
+
+    impl QueryVisitor for LeafWrapper {
+        fn visit_mut(&mut self, query: &mut Query) {
+            if !query.is_compound() {
+                let leaf = std::mem::replace(query, Query::match_all().into());
+                *query = Query::bool().must(leaf).into();
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_span_clauses_span_queries() {
+        let mut query = Query::from(Query::span_near([
+            SpanQuery::from(Query::span_term("text", "quick")),
+            SpanQuery::from(Query::prefix("text", "fo")),
+        ]));
+
+        query.walk_mut(&mut LeafWrapper);
+
+        assert_serialize(
+            query,
+            json!({
+                "span_near": {
+                    "clauses": [
+                        { "span_term": { "text": { "value": "quick" } } },
+                        { "span_multi": { "match": { "prefix": { "text": { "value": "fo" } } } } }
+                    ]
+                }
+            }),
+        );
+    }
+}
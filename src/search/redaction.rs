@@ -0,0 +1,330 @@
+use crate::search::*;
+use serde_json::Value;
+
+/// Placeholder substituted for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Parameters whose values describe the structure of a request rather than user data, and are
+/// therefore kept verbatim when redacting, as long as they hold plain values
+const STRUCTURAL_KEYS: &[&str] = &[
+    "_count",
+    "_key",
+    "_name",
+    "analyzer",
+    "boost",
+    "boost_mode",
+    "calendar_interval",
+    "default_field",
+    "default_operator",
+    "distance_type",
+    "explain",
+    "field",
+    "fields",
+    "fixed_interval",
+    "format",
+    "from",
+    "fuzziness",
+    "k",
+    "minimum_should_match",
+    "mode",
+    "negative_boost",
+    "num_candidates",
+    "operator",
+    "order",
+    "path",
+    "query_weight",
+    "relation",
+    "rescore_query_weight",
+    "rewrite",
+    "score_mode",
+    "size",
+    "slop",
+    "tie_breaker",
+    "time_zone",
+    "track_scores",
+    "track_total_hits",
+    "type",
+    "unmapped_type",
+    "validation_method",
+    "window_size",
+    "zero_terms_query",
+];
+
+/// Queries whose clause is keyed by the name of the queried field, which can clash with the
+/// name of any parameter, along with the parameters sitting next to the field name
+const FIELD_KEYED_QUERIES: &[(&str, &[&str])] = &[
+    ("fuzzy", &[]),
+    (
+        "geo_bounding_box",
+        &[
+            "_name",
+            "boost",
+            "ignore_unmapped",
+            "type",
+            "validation_method",
+        ],
+    ),
+    (
+        "geo_distance",
+        &[
+            "_name",
+            "boost",
+            "distance",
+            "distance_type",
+            "ignore_unmapped",
+            "validation_method",
+        ],
+    ),
+    ("geo_grid", &["_name", "boost"]),
+    ("geo_shape", &["_name", "boost", "ignore_unmapped"]),
+    ("intervals", &[]),
+    ("match", &[]),
+    ("match_bool_prefix", &[]),
+    ("match_phrase", &[]),
+    ("match_phrase_prefix", &[]),
+    ("prefix", &[]),
+    ("range", &[]),
+    ("regexp", &[]),
+    ("shape", &["_name", "boost", "ignore_unmapped"]),
+    ("span_term", &[]),
+    ("term", &[]),
+    ("terms", &["_name", "boost"]),
+    ("terms_set", &[]),
+    ("text_expansion", &[]),
+    ("weighted_tokens", &[]),
+    ("wildcard", &[]),
+];
+
+/// What the keys of the object being redacted stand for
+#[derive(Clone, Copy)]
+enum Keys {
+    /// Parameter names
+    Parameters,
+
+    /// Field names of a field keyed query clause, next to the given parameters
+    Fields(&'static [&'static str]),
+
+    /// Names of aggregations
+    Aggregations,
+
+    /// Types of an aggregation, plus its sub-aggregations
+    Aggregation,
+}
+
+impl Query {
+    /// Serializes the query with every user supplied value, such as `term` values, `match`
+    /// query text or `range` bounds, replaced by [`REDACTED`], while keeping the structure,
+    /// field names and structural parameters intact so that the query can be logged without
+    /// leaking personal data.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # use serde_json::json;
+    /// let query = Query::from(Query::bool().filter(Query::term("email", "jane@example.com")));
+    ///
+    /// assert_eq!(
+    ///     query.redacted().unwrap(),
+    ///     json!({ "bool": { "filter": [{ "term": { "email": { "value": "[REDACTED]" } } }] } })
+    /// );
+    /// ```
+    pub fn redacted(&self) -> Result<Value, serde_json::Error> {
+        Ok(redact(serde_json::to_value(self)?, Keys::Parameters))
+    }
+}
+
+impl Search {
+    /// Serializes the search request with every user supplied value replaced by [`REDACTED`],
+    /// see [`Query::redacted`]
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # use serde_json::json;
+    /// let search = Search::new()
+    ///     .size(10)
+    ///     .query(Query::r#match("name", "Jane Doe"));
+    ///
+    /// assert_eq!(
+    ///     search.redacted().unwrap(),
+    ///     json!({ "size": 10, "query": { "match": { "name": { "query": "[REDACTED]" } } } })
+    /// );
+    /// ```
+    pub fn redacted(&self) -> Result<Value, serde_json::Error> {
+        Ok(redact(serde_json::to_value(self)?, Keys::Parameters))
+    }
+}
+
+fn redact(value: Value, keys: Keys) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = redact_entry(&key, value, keys);
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| redact(value, keys))
+                .collect(),
+        ),
+        Value::Null => Value::Null,
+        _ => Value::String(REDACTED.to_string()),
+    }
+}
+
+fn redact_entry(key: &str, value: Value, keys: Keys) -> Value {
+    match keys {
+        Keys::Aggregations => redact(value, Keys::Aggregation),
+        Keys::Aggregation if key == "aggs" || key == "aggregations" => {
+            redact(value, Keys::Aggregations)
+        }
+        Keys::Aggregation => redact(value, Keys::Parameters),
+        Keys::Fields(parameters)
+            if parameters.contains(&key) && !value.is_object() && !value.is_array() =>
+        {
+            value
+        }
+        Keys::Fields(_) => redact(value, Keys::Parameters),
+        Keys::Parameters => {
+            let field_keyed = FIELD_KEYED_QUERIES
+                .iter()
+                .find(|(query, _)| *query == key)
+                .filter(|_| value.is_object());
+
+            match field_keyed {
+                Some((_, parameters)) => redact(value, Keys::Fields(parameters)),
+                None if key == "aggs" || key == "aggregations" => redact(value, Keys::Aggregations),
+                None if STRUCTURAL_KEYS.contains(&key) && is_plain(&value) => value,
+                None => redact(value, Keys::Parameters),
+            }
+        }
+    }
+}
+
+/// Whether the value is a scalar or a list of scalars, as opposed to a structure that can
+/// contain user data under keys of its own
+fn is_plain(value: &Value) -> bool {
+    match value {
+        Value::Object(_) => false,
+        Value::Array(values) => values
+            .iter()
+            .all(|value| !value.is_object() && !value.is_array()),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_values() {
+        let search = Search::new()
+            .from(20)
+            .size(10)
+            .query(
+                Query::bool()
+                    .must(Query::multi_match(["name", "bio"], "Jane Doe").boost(2))
+                    .filter(Query::terms("status", ["active", "pending"]))
+                    .filter(Query::range("age").gte(18).lt(65))
+                    .must_not(Query::ids(["42"]).name("blocked")),
+            )
+            .sort(Sort::new("created_at").order(SortOrder::Desc))
+            .aggregate("cities", Aggregation::terms("city").size(5));
+
+        assert_eq!(
+            search.redacted().unwrap(),
+            json!({
+                "from": 20,
+                "size": 10,
+                "query": {
+                    "bool": {
+                        "must": [
+                            {
+                                "multi_match": {
+                                    "fields": ["name", "bio"],
+                                    "query": "[REDACTED]",
+                                    "boost": 2
+                                }
+                            }
+                        ],
+                        "filter": [
+                            { "terms": { "status": ["[REDACTED]", "[REDACTED]"] } },
+                            { "range": { "age": { "gte": "[REDACTED]", "lt": "[REDACTED]" } } }
+                        ],
+                        "must_not": [
+                            { "ids": { "values": ["[REDACTED]"], "_name": "blocked" } }
+                        ]
+                    }
+                },
+                "sort": [
+                    { "created_at": { "order": "desc" } }
+                ],
+                "aggs": {
+                    "cities": { "terms": { "field": "city", "size": 5 } }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn redacts_fields_named_like_parameters() {
+        let query = Query::from(
+            Query::bool()
+                .filter(Query::term("type", "ssn-123"))
+                .filter(Query::terms("path", ["secret"]).boost(2))
+                .filter(Query::r#match("order", "jane"))
+                .filter(Query::range("size").gte(180))
+                .filter(Query::geo_distance(
+                    "from",
+                    GeoPoint::Coordinates {
+                        latitude: 40.7,
+                        longitude: -74.0,
+                    },
+                    Distance::Kilometers(1),
+                )),
+        );
+
+        assert_eq!(
+            query.redacted().unwrap(),
+            json!({
+                "bool": {
+                    "filter": [
+                        { "term": { "type": { "value": "[REDACTED]" } } },
+                        { "terms": { "path": ["[REDACTED]"], "boost": 2 } },
+                        { "match": { "order": { "query": "[REDACTED]" } } },
+                        { "range": { "size": { "gte": "[REDACTED]" } } },
+                        { "geo_distance": { "from": ["[REDACTED]", "[REDACTED]"], "distance": "1km" } }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn redacts_aggregations_named_like_parameters() {
+        let search = Search::new().size(0).aggregate(
+            "path",
+            Aggregation::filter(Query::terms("type", ["secret"]))
+                .aggregate("size", Aggregation::terms("city").size(5)),
+        );
+
+        assert_eq!(
+            search.redacted().unwrap(),
+            json!({
+                "size": 0,
+                "aggs": {
+                    "path": {
+                        "filter": { "terms": { "type": ["[REDACTED]"] } },
+                        "aggs": {
+                            "size": { "terms": { "field": "city", "size": 5 } }
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
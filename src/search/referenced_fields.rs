@@ -0,0 +1,577 @@
+use crate::search::*;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Queries serialized as `{ "<query>": { "<field>": ... } }`, alongside the parameters that may
+/// appear next to the field
+const FIELD_KEYED_QUERIES: &[(&str, &[&str])] = &[
+    ("term", &[]),
+    ("terms", &[]),
+    ("terms_set", &[]),
+    ("prefix", &[]),
+    ("regexp", &[]),
+    ("wildcard", &[]),
+    ("fuzzy", &[]),
+    ("range", &[]),
+    ("match", &[]),
+    ("match_phrase", &[]),
+    ("match_phrase_prefix", &[]),
+    ("match_bool_prefix", &[]),
+    ("intervals", &[]),
+    ("span_term", &[]),
+    ("text_expansion", &[]),
+    ("weighted_tokens", &[]),
+    (
+        "geo_distance",
+        &[
+            "distance",
+            "distance_type",
+            "validation_method",
+            "ignore_unmapped",
+        ],
+    ),
+    (
+        "geo_bounding_box",
+        &["validation_method", "type", "ignore_unmapped"],
+    ),
+    ("geo_grid", &[]),
+    ("geo_shape", &["ignore_unmapped"]),
+    ("shape", &["ignore_unmapped"]),
+];
+
+/// Parameters appearing next to the field of a `_geo_distance` sort
+const GEO_DISTANCE_SORT_PARAMETERS: &[&str] =
+    &["order", "unit", "mode", "distance_type", "ignore_unmapped"];
+
+/// Queries referencing a single field through a `field` parameter
+const FIELD_QUERIES: &[&str] = &[
+    "exists",
+    "distance_feature",
+    "percolate",
+    "rank_feature",
+    "sparse_vector",
+];
+
+/// Queries referencing multiple fields through a `fields` parameter
+const FIELDS_QUERIES: &[&str] = &[
+    "multi_match",
+    "combined_fields",
+    "query_string",
+    "simple_query_string",
+    "more_like_this",
+];
+
+impl Query {
+    /// Names of the document fields referenced anywhere in the query tree, e.g. to check field
+    /// level permissions or field capabilities before sending the query.
+    ///
+    /// Field patterns, such as `title*` or `content^2` in `multi_match`, are returned verbatim
+    /// without the boost suffix. Queries with opaque bodies, such as `wrapper` or `script`, do
+    /// not contribute any fields.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let query = Query::from(
+    ///     Query::bool()
+    ///         .must(Query::multi_match(["title^2", "body"], "rust"))
+    ///         .filter(Query::nested("comments", Query::term("comments.user", "kimchy"))),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     query.referenced_fields().into_iter().collect::<Vec<_>>(),
+    ///     ["body", "comments", "comments.user", "title"]
+    /// );
+    /// ```
+    pub fn referenced_fields(&self) -> BTreeSet<String> {
+        let mut fields = BTreeSet::new();
+
+        collect_query(self, &mut fields);
+
+        fields
+    }
+}
+
+impl Search {
+    /// Names of the document fields referenced anywhere in the search request, see
+    /// [`Query::referenced_fields`]. Besides the query, this covers kNN searches, retrievers,
+    /// sorts, aggregations, highlighting, rescorers, field collapsing, inner hits, runtime
+    /// mappings and source filtering includes.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let search = Search::new()
+    ///     .query(Query::term("user", "kimchy"))
+    ///     .sort(Sort::new("@timestamp"))
+    ///     .aggregate("tags", Aggregation::terms("tags"));
+    ///
+    /// assert_eq!(
+    ///     search.referenced_fields().into_iter().collect::<Vec<_>>(),
+    ///     ["@timestamp", "tags", "user"]
+    /// );
+    /// ```
+    pub fn referenced_fields(&self) -> BTreeSet<String> {
+        let mut fields = BTreeSet::new();
+
+        for query in self.embedded_queries() {
+            collect_query(query, &mut fields);
+        }
+
+        let search = match serde_json::to_value(self) {
+            Ok(search) => search,
+            Err(_) => return fields,
+        };
+
+        if let Some(runtime_mappings) = search["runtime_mappings"].as_object() {
+            fields.extend(runtime_mappings.keys().cloned());
+        }
+
+        collect_source(&search["_source"], &mut fields);
+        collect_sorts(&search["sort"], &mut fields);
+        collect_aggregations(&search["aggs"], &mut fields);
+        collect_highlight(&search["highlight"], &mut fields);
+        collect_collapse(&search["collapse"], &mut fields);
+        collect_retriever(&search["retriever"], &mut fields);
+
+        for knn in array(&search["knn"]) {
+            collect_field(&knn["field"], &mut fields);
+            collect_inner_hits(&knn["inner_hits"], &mut fields);
+        }
+
+        fields
+    }
+}
+
+/// Collects the fields of every query of the tree, including queries embedded in their
+/// parameters, such as highlight queries of inner hits
+struct FieldCollector<'a>(&'a mut BTreeSet<String>);
+
+impl QueryVisitor for FieldCollector<'_> {
+    fn visit(&mut self, query: &Query) {
+        let embedded_queries = match query {
+            Query::Annotated(_) => return,
+            Query::Nested(query) => query.embedded_queries(),
+            Query::HasChild(query) => query.embedded_queries(),
+            Query::HasParent(query) => query.embedded_queries(),
+            _ => vec![],
+        };
+
+        for query in embedded_queries {
+            collect_query(query, self.0);
+        }
+
+        // Clauses are visited on their own, so only compound queries with parameters of
+        // their own are worth serializing
+        let has_parameters = !query.is_compound()
+            || matches!(
+                query,
+                Query::Nested(_)
+                    | Query::HasChild(_)
+                    | Query::HasParent(_)
+                    | Query::Knn(_)
+                    | Query::SpanFieldMasking(_)
+                    | Query::FunctionScore(_)
+            );
+
+        if has_parameters {
+            if let Ok(query) = serde_json::to_value(query) {
+                collect_parameters(&query, self.0);
+            }
+        }
+    }
+}
+
+fn collect_query(query: &Query, fields: &mut BTreeSet<String>) {
+    query.walk(&mut FieldCollector(fields));
+}
+
+/// Elements of an array value, or the value itself when it is a single object
+fn array(value: &Value) -> &[Value] {
+    match value {
+        Value::Array(values) => values,
+        Value::Null => &[],
+        value => std::slice::from_ref(value),
+    }
+}
+
+fn collect_field(value: &Value, fields: &mut BTreeSet<String>) {
+    if let Some(field) = value.as_str() {
+        let field = field.split('^').next().unwrap_or(field);
+
+        if !field.is_empty() {
+            let _ = fields.insert(field.to_string());
+        }
+    }
+}
+
+fn collect_fields(value: &Value, fields: &mut BTreeSet<String>) {
+    for field in array(value) {
+        collect_field(field, fields);
+    }
+}
+
+/// Collects the fields referenced by the parameters of a single query, leaving out the fields of
+/// the queries it wraps
+fn collect_parameters(value: &Value, fields: &mut BTreeSet<String>) {
+    let (kind, body) = match value.as_object().and_then(|query| query.iter().next()) {
+        Some((kind, body)) => (kind.as_str(), body),
+        None => return,
+    };
+
+    match kind {
+        "nested" => {
+            collect_field(&body["path"], fields);
+            collect_inner_hits(&body["inner_hits"], fields);
+        }
+        "has_child" | "has_parent" => collect_inner_hits(&body["inner_hits"], fields),
+        "function_score" => {
+            for function in array(&body["functions"]) {
+                collect_function(function, fields);
+            }
+        }
+        "knn" | "span_field_masking" => collect_field(&body["field"], fields),
+        kind if FIELD_QUERIES.contains(&kind) => collect_field(&body["field"], fields),
+        kind if FIELDS_QUERIES.contains(&kind) => {
+            collect_field(&body["default_field"], fields);
+            collect_fields(&body["fields"], fields);
+        }
+        kind => {
+            let parameters = match FIELD_KEYED_QUERIES.iter().find(|(name, _)| *name == kind) {
+                Some((_, parameters)) => parameters,
+                None => return,
+            };
+
+            if let Some(body) = body.as_object() {
+                fields.extend(
+                    body.keys()
+                        .filter(|key| !matches!(key.as_str(), "boost" | "_name"))
+                        .filter(|key| !parameters.contains(&key.as_str()))
+                        .cloned(),
+                );
+            }
+        }
+    }
+}
+
+fn collect_function(function: &Value, fields: &mut BTreeSet<String>) {
+    collect_field(&function["field_value_factor"]["field"], fields);
+    collect_field(&function["random_score"]["field"], fields);
+
+    for decay in ["gauss", "exp", "linear"] {
+        if let Some(decay) = function[decay].as_object() {
+            fields.extend(
+                decay
+                    .keys()
+                    .filter(|key| key.as_str() != "multi_value_mode")
+                    .cloned(),
+            );
+        }
+    }
+}
+
+fn collect_sorts(value: &Value, fields: &mut BTreeSet<String>) {
+    for sort in array(value) {
+        let keys: Vec<&String> = match sort {
+            Value::String(field) => vec![field],
+            Value::Object(sort) => sort.keys().collect(),
+            _ => continue,
+        };
+
+        fields.extend(
+            keys.into_iter()
+                .filter(|field| !field.starts_with('_'))
+                .cloned(),
+        );
+
+        if let Some(geo_distance) = sort["_geo_distance"].as_object() {
+            fields.extend(
+                geo_distance
+                    .keys()
+                    .filter(|key| !GEO_DISTANCE_SORT_PARAMETERS.contains(&key.as_str()))
+                    .cloned(),
+            );
+        }
+    }
+}
+
+/// Fields included by [source filtering](SourceFilter), excludes don't expose any field
+fn collect_source(value: &Value, fields: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(source) => collect_fields(&source["includes"], fields),
+        value => collect_fields(value, fields),
+    }
+}
+
+fn collect_highlight(value: &Value, fields: &mut BTreeSet<String>) {
+    for field in array(&value["fields"]).iter().filter_map(Value::as_object) {
+        for (name, highlighter) in field {
+            collect_field(&Value::String(name.clone()), fields);
+            collect_fields(&highlighter["matched_fields"], fields);
+        }
+    }
+}
+
+fn collect_inner_hits(value: &Value, fields: &mut BTreeSet<String>) {
+    for inner_hits in array(value) {
+        collect_source(&inner_hits["_source"], fields);
+        collect_sorts(&inner_hits["sort"], fields);
+        collect_highlight(&inner_hits["highlight"], fields);
+        collect_fields(&inner_hits["docvalue_fields"], fields);
+        collect_collapse(&inner_hits["collapse"], fields);
+    }
+}
+
+fn collect_collapse(value: &Value, fields: &mut BTreeSet<String>) {
+    collect_field(&value["field"], fields);
+    collect_inner_hits(&value["inner_hits"], fields);
+}
+
+fn collect_retriever(value: &Value, fields: &mut BTreeSet<String>) {
+    collect_sorts(&value["standard"]["sort"], fields);
+    collect_collapse(&value["standard"]["collapse"], fields);
+    collect_field(&value["knn"]["field"], fields);
+
+    for retriever in array(&value["rrf"]["retrievers"]) {
+        collect_retriever(retriever, fields);
+    }
+}
+
+fn collect_aggregations(value: &Value, fields: &mut BTreeSet<String>) {
+    let aggregations = match value.as_object() {
+        Some(aggregations) => aggregations,
+        None => return,
+    };
+
+    for aggregation in aggregations.values().filter_map(Value::as_object) {
+        for (kind, body) in aggregation {
+            match kind.as_str() {
+                "aggs" | "aggregations" => collect_aggregations(body, fields),
+                "meta" | "filter" => {}
+                "nested" => collect_field(&body["path"], fields),
+                "top_hits" => {
+                    collect_sorts(&body["sort"], fields);
+                    collect_source(&body["_source"], fields);
+                }
+                _ => collect_field(&body["field"], fields),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(fields: BTreeSet<String>) -> Vec<String> {
+        fields.into_iter().collect()
+    }
+
+    #[test]
+    fn query_fields() {
+        let query = Query::from(
+            Query::bool()
+                .must(
+                    Query::function_score(Query::r#match("title", "rust"))
+                        .function(FieldValueFactor::new("likes")),
+                )
+                .should(Query::dis_max().query(Query::prefix("author", "ki")))
+                .should(Query::knn("embedding", [1.0, 2.0]).filter(Query::term("lang", "en")))
+                .filter(Query::range("date").gte("now-1d").boost(2))
+                .filter(Query::exists("tags"))
+                .filter(Query::geo_distance(
+                    "pin.location",
+                    GeoPoint::Geohash("drm3btev3e86".into()),
+                    Distance::Miles(200),
+                ))
+                .must_not(Query::ids(["1"]))
+                .must_not(Query::wrapper("eyJ0ZXJtIjp7fX0="))
+                .must_not(Query::span_near([
+                    SpanQuery::from(Query::span_term("body", "quick")),
+                    Query::span_field_masking(Query::span_term("body.stems", "fox"), "body").into(),
+                ])),
+        );
+
+        assert_eq!(
+            fields(query.referenced_fields()),
+            [
+                "author",
+                "body",
+                "body.stems",
+                "date",
+                "embedding",
+                "lang",
+                "likes",
+                "pin.location",
+                "tags",
+                "title"
+            ]
+        );
+    }
+
+    #[test]
+    fn search_fields() {
+        let search = Search::new()
+            .query(Query::term("user", "kimchy"))
+            .sort([Sort::new("@timestamp"), Sort::new(SortField::Score)])
+            .sort(
+                Sort::geo_distance("location", GeoPoint::coordinates(52.37, 4.89))
+                    .unit(DistanceUnit::Kilometers),
+            )
+            .aggregate(
+                "recent",
+                Aggregation::filter(Query::term("status", "published"))
+                    .aggregate("top", Aggregation::top_hits().sort(Sort::new("likes"))),
+            )
+            .knn(Knn::query_vector("vector", [1.0, 2.0]).filter(Query::term("lang", "en")))
+            .rescore(Rescore::new(Query::term("title", "rust")))
+            .collapse("author");
+
+        assert_eq!(
+            fields(search.referenced_fields()),
+            [
+                "@timestamp",
+                "author",
+                "lang",
+                "likes",
+                "location",
+                "status",
+                "title",
+                "user",
+                "vector"
+            ]
+        );
+    }
+
+    #[test]
+    fn retriever_fields() {
+        let search = Search::new().retriever(
+            Retriever::rrf([
+                Retriever::from(
+                    Retriever::standard(Query::term("user", "kimchy"))
+                        .filter(Query::term("lang", "en"))
+                        .sort(Sort::new("@timestamp"))
+                        .collapse("author"),
+                ),
+                Retriever::knn("vector", [1.0, 2.0], 10, 100)
+                    .filter(Query::exists("title"))
+                    .into(),
+            ])
+            .filter(Query::range("date").gte("now-1d")),
+        );
+
+        assert_eq!(
+            fields(search.referenced_fields()),
+            [
+                "@timestamp",
+                "author",
+                "date",
+                "lang",
+                "title",
+                "user",
+                "vector"
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_fields() {
+        let search = Search::new().highlight(
+            Highlight::new()
+                .highlighter(Highlighter::new().highlight_query(Query::term("summary", "rust")))
+                .field("title")
+                .field_highlighter(
+                    "body",
+                    Highlighter::fvh()
+                        .matched_fields(["body", "body.plain"])
+                        .highlight_query(Query::r#match("body.stems", "rust")),
+                ),
+        );
+
+        assert_eq!(
+            fields(search.referenced_fields()),
+            ["body", "body.plain", "body.stems", "summary", "title"]
+        );
+    }
+
+    #[test]
+    fn runtime_mapping_and_source_fields() {
+        let search = Search::new()
+            .runtime_mapping(
+                "day_of_week",
+                RuntimeMapping::new(RuntimeDataType::Keyword, "emit('Monday')"),
+            )
+            .source((["title", "user.*"], ["secret"]));
+
+        assert_eq!(
+            fields(search.referenced_fields()),
+            ["day_of_week", "title", "user.*"]
+        );
+
+        assert_eq!(
+            fields(Search::new().source("body").referenced_fields()),
+            ["body"]
+        );
+
+        assert!(Search::new().source(false).referenced_fields().is_empty());
+    }
+
+    #[test]
+    fn inner_hits_fields() {
+        let query = Query::from(
+            Query::nested("comments", Query::term("comments.user", "kimchy")).inner_hits(
+                InnerHits::new()
+                    .source(["comments.text"])
+                    .sort(Sort::new("comments.date"))
+                    .docvalue_fields(["comments.likes"])
+                    .highlight(Highlight::new().field_highlighter(
+                        "comments.text",
+                        Highlighter::new().highlight_query(Query::term("comments.lang", "en")),
+                    ))
+                    .collapse("comments.author"),
+            ),
+        );
+
+        assert_eq!(
+            fields(query.referenced_fields()),
+            [
+                "comments",
+                "comments.author",
+                "comments.date",
+                "comments.lang",
+                "comments.likes",
+                "comments.text",
+                "comments.user"
+            ]
+        );
+
+        let search = Search::new().collapse(
+            Collapse::new("author").inner_hits(InnerHits::new().docvalue_fields(["likes"])),
+        );
+
+        assert_eq!(fields(search.referenced_fields()), ["author", "likes"]);
+    }
+
+    #[test]
+    fn aggregation_fields() {
+        let search = Search::new()
+            .aggregate(
+                "keywords",
+                Aggregation::significant_terms("tags")
+                    .background_filter(Query::term("lang", "en"))
+                    .aggregate("phrases", Aggregation::significant_text("body")),
+            )
+            .aggregate(
+                "recent",
+                Aggregation::filter(Query::range("date").gte("now-1d")).aggregate(
+                    "top",
+                    Aggregation::top_hits()
+                        .sort(Sort::new("likes"))
+                        .source(["title"]),
+                ),
+            );
+
+        assert_eq!(
+            fields(search.referenced_fields()),
+            ["body", "date", "lang", "likes", "tags", "title"]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 //! Allows you to execute a search query and get back search hits that match the query.
+use crate::analyze::StringOrVecString;
 use crate::search::*;
 use crate::util::*;
 use std::{collections::BTreeMap, convert::TryInto};
@@ -29,23 +30,50 @@ pub struct Search {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     size: Option<u64>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    terminate_after: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     query: Option<Query>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    knn: Vec<Knn>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    retriever: Option<Retriever>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     sort: Vec<Sort>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_after: Vec<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pit: Option<PointInTime>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     aggs: Aggregations,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     track_total_hits: Option<TrackTotalHits>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    track_scores: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    explain: Option<bool>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight: Option<Highlight>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     rescore: Vec<Rescore>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collapse: Option<Collapse>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ext: BTreeMap<String, serde_json::Value>,
 }
 
 impl Search {
@@ -102,11 +130,18 @@ impl Search {
     }
 
     /// Specific `tag` of the request for logging and statistical purposes.
+    ///
+    /// Accepts a single statistics group or a collection of groups, which can be retrieved
+    /// through the index stats API.
     pub fn stats<S>(mut self, stats: S) -> Self
     where
-        S: ToString,
+        S: Into<StringOrVecString>,
     {
-        self.stats.push(stats.to_string());
+        match stats.into() {
+            StringOrVecString::String(group) => self.stats.push(group),
+            StringOrVecString::VecString(groups) => self.stats.extend(groups),
+        }
+        self.stats.retain(|group| !group.should_skip());
         self
     }
 
@@ -136,6 +171,23 @@ impl Search {
         self
     }
 
+    /// Sets `from` and `size` to retrieve the given [`Page`], which is validated against the
+    /// result window when created
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # use std::convert::TryFrom;
+    /// let search = Search::new().page(Page::try_from((3, 20))?);
+    ///
+    /// assert!(Page::try_from((2000, 10)).is_err());
+    /// # Ok::<(), PageError>(())
+    /// ```
+    pub fn page(mut self, page: Page) -> Self {
+        self.from = Some(page.from());
+        self.size = Some(page.size());
+        self
+    }
+
     /// Defines the search definition using the
     /// [Query DSL](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html).
     pub fn query<Q>(mut self, query: Q) -> Self
@@ -146,6 +198,68 @@ impl Search {
         self
     }
 
+    /// Adds queries in [filter context](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-filter-context.html),
+    /// which don't contribute to the score and can be cached. The queries are added as
+    /// [`filter`](BoolQuery::filter) clauses of the bool query set with [`query`](Self::query),
+    /// any other query is wrapped into a bool query as its `must` clause.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let search =
+    /// Search::new()
+    ///     .query(Query::r#match("title", "rust"))
+    ///     .filter(Query::term("status", "published"))
+    ///     .filter(Query::range("published_at").gte("now-1y"));
+    /// ```
+    pub fn filter<Q>(mut self, queries: Q) -> Self
+    where
+        Q: Into<Queries>,
+    {
+        let mut query = self.query.take().unwrap_or_else(|| Query::bool().into());
+
+        match query.unannotated_mut() {
+            Query::Bool(bool) if bool.accepts_filter() => {
+                *bool = std::mem::replace(bool, Query::bool()).filter(queries);
+            }
+            _ => query = Query::bool().must(query).filter(queries).into(),
+        }
+
+        self.query = Some(query);
+        self
+    }
+
+    /// Defines the approximate
+    /// [kNN search](https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html)
+    /// to run. Can be called multiple times to search several vector fields.
+    pub fn knn<K>(mut self, knn: K) -> Self
+    where
+        K: Into<Knn>,
+    {
+        let knn = knn.into();
+
+        if !knn.should_skip() {
+            self.knn.push(knn);
+        }
+
+        self
+    }
+
+    /// Defines a top-level
+    /// [retriever](https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html)
+    /// which returns the top documents instead of `query` and `knn`.
+    pub fn retriever<R>(mut self, retriever: R) -> Self
+    where
+        R: Into<Retriever>,
+    {
+        let retriever = retriever.into();
+
+        if !retriever.should_skip() {
+            self.retriever = Some(retriever);
+        }
+
+        self
+    }
+
     /// A collection of sorting fields
     pub fn sort<S>(mut self, sort: S) -> Self
     where
@@ -155,6 +269,98 @@ impl Search {
         self
     }
 
+    /// Sorts of the search request, in order
+    pub(crate) fn sorts(&self) -> &[Sort] {
+        &self.sort
+    }
+
+    /// Retrieves the next page of hits using the sort values of the last hit from the previous
+    /// page. Replaces previously set values.
+    pub fn search_after<I>(mut self, search_after: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: serde::Serialize,
+    {
+        self.search_after = search_after
+            .into_iter()
+            .filter_map(|value| serde_json::to_value(value).ok())
+            .collect();
+        self
+    }
+
+    /// Searches against a [`PointInTime`] to preserve the index state across successive
+    /// requests
+    pub fn pit(mut self, pit: PointInTime) -> Self {
+        if !pit.should_skip() {
+            self.pit = Some(pit);
+        }
+        self
+    }
+
+    /// Maximum number of documents to collect for each shard. If a query reaches this limit,
+    /// Elasticsearch terminates the query early and the response has `terminated_early` set.
+    /// Elasticsearch collects documents before sorting.
+    ///
+    /// Defaults to `0`, which does not terminate query execution early.
+    pub fn terminate_after(mut self, terminate_after: u64) -> Self {
+        self.terminate_after = Some(terminate_after);
+        self
+    }
+
+    /// Turns the search into a quick estimate over a huge index: no hits are returned and every
+    /// shard stops collecting after `n_per_shard` matching documents, so hit counts and
+    /// aggregations are computed over at most `n_per_shard` documents per shard.
+    ///
+    /// The query, or `match_all` when none is set, is wrapped in a
+    /// [`function_score`](Query::function_score) query replacing the relevance score with a
+    /// [`random_score`](crate::Function::random_score), so that [`sampler`](crate::Aggregation::sampler)
+    /// aggregations, which keep the top-scoring documents of each shard, aggregate a random
+    /// subset of the collected documents. Shards still collect documents in index order, so the
+    /// collected documents lean towards documents indexed early.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let search =
+    /// Search::new()
+    ///     .query(Query::term("status", "active"))
+    ///     .aggregate(
+    ///         "sample",
+    ///         Aggregation::sampler()
+    ///             .shard_size(1_000)
+    ///             .aggregate("tags", Aggregation::terms("tags")),
+    ///     )
+    ///     .sampled(10_000);
+    /// ```
+    pub fn sampled(mut self, n_per_shard: u64) -> Self {
+        let query = self
+            .query
+            .take()
+            .unwrap_or_else(|| Query::match_all().into());
+
+        self.query(
+            Query::function_score(query)
+                .function(Function::random_score())
+                .boost_mode(FunctionScoreBoostMode::Replace),
+        )
+        .size(0)
+        .terminate_after(n_per_shard)
+    }
+
+    /// Returns aggregations without any hits: sets `size` to `0` and disables `_source` and
+    /// `track_total_hits`, so shards skip fetching documents and counting every match
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let search =
+    /// Search::new()
+    ///     .query(Query::term("status", "active"))
+    ///     .aggregate("tags", Aggregation::terms("tags"))
+    ///     .aggregations_only();
+    /// ```
+    pub fn aggregations_only(self) -> Self {
+        self.size(0).source(false).track_total_hits(false)
+    }
+
     /// Track total hits
     pub fn track_total_hits<T>(mut self, track_total_hits: T) -> Self
     where
@@ -164,6 +370,24 @@ impl Search {
         self
     }
 
+    /// If `true`, calculate and return document scores, even if the scores are not used for
+    /// sorting.
+    ///
+    /// Defaults to `false`.
+    pub fn track_scores(mut self, track_scores: bool) -> Self {
+        self.track_scores = Some(track_scores);
+        self
+    }
+
+    /// If `true`, returns detailed information about score computation as part of each hit,
+    /// see [`Hit::explanation`].
+    ///
+    /// Defaults to `false`.
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.explain = Some(explain);
+        self
+    }
+
     /// Highlight
     pub fn highlight<H>(mut self, highlight: H) -> Self
     where
@@ -187,5 +411,287 @@ impl Search {
         self
     }
 
+    /// Collapse search results based on field values
+    pub fn collapse<C>(mut self, collapse: C) -> Self
+    where
+        C: Into<Collapse>,
+    {
+        let collapse = collapse.into();
+
+        if !collapse.should_skip() {
+            self.collapse = Some(collapse);
+        }
+
+        self
+    }
+
+    /// Adds a plugin specific section to the `ext` object of the search request body
+    pub fn ext<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: serde::Serialize,
+    {
+        if let Ok(value) = serde_json::to_value(value) {
+            let _ = self.ext.insert(key.to_string(), value);
+        }
+        self
+    }
+
+    /// Logs features with the Learning to Rank plugin
+    pub fn ltr_log(self, ltr_log: LtrLog) -> Self {
+        if ltr_log.should_skip() {
+            return self;
+        }
+
+        self.ext("ltr_log", ltr_log)
+    }
+
     add_aggregate!();
+
+    /// Queries of every section of the search request, such as kNN filters, retrievers,
+    /// aggregations, highlighting and rescoring, besides the `query` itself
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.query
+            .iter()
+            .chain(self.knn.iter().flat_map(Knn::embedded_queries))
+            .chain(self.retriever.iter().flat_map(Retriever::embedded_queries))
+            .chain(self.aggs.values().flat_map(Aggregation::embedded_queries))
+            .chain(self.highlight.iter().flat_map(Highlight::embedded_queries))
+            .chain(self.rescore.iter().flat_map(Rescore::embedded_queries))
+            .chain(self.collapse.iter().flat_map(Collapse::embedded_queries))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn serializes_stats() {
+        assert_serialize(
+            Search::new().stats("statistics"),
+            json!({ "stats": ["statistics"] }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .stats(["group1", "group2"])
+                .stats(vec![String::from("group3")])
+                .stats(""),
+            json!({ "stats": ["group1", "group2", "group3"] }),
+        );
+    }
+
+    #[test]
+    fn serializes_track_scores() {
+        assert_serialize(
+            Search::new()
+                .sort(Sort::new("post_date").order(SortOrder::Desc))
+                .track_scores(true),
+            json!({
+                "sort": [{ "post_date": { "order": "desc" } }],
+                "track_scores": true
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_explain() {
+        assert_serialize(
+            Search::new()
+                .query(Query::r#match("message", "elasticsearch"))
+                .explain(true),
+            json!({
+                "query": { "match": { "message": { "query": "elasticsearch" } } },
+                "explain": true
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_sampled() {
+        assert_serialize(
+            Search::new()
+                .size(10)
+                .query(Query::term("status", "active"))
+                .aggregate("tags", Aggregation::terms("tags"))
+                .sampled(1_000),
+            json!({
+                "size": 0,
+                "terminate_after": 1_000,
+                "query": {
+                    "function_score": {
+                        "query": { "term": { "status": { "value": "active" } } },
+                        "functions": [{ "random_score": {} }],
+                        "boost_mode": "replace"
+                    }
+                },
+                "aggs": { "tags": { "terms": { "field": "tags" } } }
+            }),
+        );
+
+        assert_serialize(
+            Search::new().sampled(1_000),
+            json!({
+                "size": 0,
+                "terminate_after": 1_000,
+                "query": {
+                    "function_score": {
+                        "query": { "match_all": {} },
+                        "functions": [{ "random_score": {} }],
+                        "boost_mode": "replace"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_page() {
+        assert_serialize(
+            Search::new().page(Page::new(3, 20).unwrap()),
+            json!({ "from": 40, "size": 20 }),
+        );
+
+        assert_serialize(
+            Search::new().page(Page::try_from((1, 10)).unwrap()),
+            json!({ "from": 0, "size": 10 }),
+        );
+    }
+
+    #[test]
+    fn serializes_aggregations_only() {
+        assert_serialize(
+            Search::new()
+                .aggregate("tags", Aggregation::terms("tags"))
+                .aggregations_only(),
+            json!({
+                "size": 0,
+                "_source": false,
+                "track_total_hits": false,
+                "aggs": { "tags": { "terms": { "field": "tags" } } }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_filter() {
+        assert_serialize(
+            Search::new().filter(Query::term("status", "active")),
+            json!({
+                "query": {
+                    "bool": { "filter": [{ "term": { "status": { "value": "active" } } }] }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::bool().must(Query::r#match("title", "rust")))
+                .filter(Query::term("status", "active"))
+                .filter([Query::term("lang", "en"), Query::term("lang", "")]),
+            json!({
+                "query": {
+                    "bool": {
+                        "must": [{ "match": { "title": { "query": "rust" } } }],
+                        "filter": [
+                            { "term": { "status": { "value": "active" } } },
+                            { "term": { "lang": { "value": "en" } } }
+                        ]
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::r#match("title", "rust"))
+                .filter(Query::term("status", "active")),
+            json!({
+                "query": {
+                    "bool": {
+                        "must": [{ "match": { "title": { "query": "rust" } } }],
+                        "filter": [{ "term": { "status": { "value": "active" } } }]
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::bool().should(Query::term("tag", "rust")))
+                .filter(Query::term("status", "active")),
+            json!({
+                "query": {
+                    "bool": {
+                        "must": [{ "bool": { "should": [{ "term": { "tag": { "value": "rust" } } }] } }],
+                        "filter": [{ "term": { "status": { "value": "active" } } }]
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn filters_annotated_query() {
+        let search = Search::new()
+            .query(
+                Query::annotated(Query::bool().must(Query::r#match("title", "rust")))
+                    .annotation("source", "search_box"),
+            )
+            .filter(Query::term("status", "active"));
+
+        assert_eq!(
+            search.query.as_ref().and_then(Query::annotations).unwrap()["source"],
+            "search_box"
+        );
+        assert_serialize(
+            search,
+            json!({
+                "query": {
+                    "bool": {
+                        "must": [{ "match": { "title": { "query": "rust" } } }],
+                        "filter": [{ "term": { "status": { "value": "active" } } }]
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::annotated(Query::term("tag", "rust")).annotation("source", "facet"))
+                .filter(Query::term("status", "active")),
+            json!({
+                "query": {
+                    "bool": {
+                        "must": [{ "term": { "tag": { "value": "rust" } } }],
+                        "filter": [{ "term": { "status": { "value": "active" } } }]
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_ext() {
+        assert_serialize(
+            Search::new()
+                .query(Query::term("user", "kimchy"))
+                .ltr_log(LtrLog::new().log_spec(LtrLogSpec::named_query("entry", "features")))
+                .ext("custom_plugin", json!({ "enabled": true })),
+            json!({
+                "query": { "term": { "user": { "value": "kimchy" } } },
+                "ext": {
+                    "custom_plugin": { "enabled": true },
+                    "ltr_log": {
+                        "log_specs": [
+                            { "name": "entry", "named_query": "features" }
+                        ]
+                    }
+                }
+            }),
+        );
+    }
 }
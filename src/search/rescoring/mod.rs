@@ -87,6 +87,10 @@ impl Rescore {
         self.query.query_weight = Some(query_weight.into());
         self
     }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.query.rescore_query.iter().collect()
+    }
 }
 
 impl ShouldSkip for Rescore {
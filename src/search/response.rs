@@ -1,3 +1,4 @@
+use crate::search::{AggregationsHandler, Explanation, Search, SortDistance};
 use crate::util::*;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -11,6 +12,15 @@ pub struct SearchResponse<H = Value, IH = Value> {
     /// Indicates whether there have been timed-out shards, if `true` - responses are partial
     pub timed_out: bool,
 
+    /// Number of times the coordinating node performed a partial reduce of shard results.
+    /// Only returned when the search was reduced in several phases
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub num_reduce_phases: Option<u32>,
+
+    /// Indicates whether the search was terminated early by `terminate_after` or a sorted index
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub terminated_early: Option<bool>,
+
     /// Number of shards touched with their states
     #[serde(rename = "_shards")]
     pub shards: Shards,
@@ -21,6 +31,27 @@ pub struct SearchResponse<H = Value, IH = Value> {
     /// Search aggregations
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     pub aggregations: Option<Value>,
+
+    /// Point in time ID to use in the next request, when searching against a point in time
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub pit_id: Option<String>,
+}
+
+impl<H, IH> SearchResponse<H, IH> {
+    /// Read access to the aggregation results
+    pub fn aggs(&self) -> AggregationsHandler<'_> {
+        AggregationsHandler::new(self.aggregations.as_ref())
+    }
+
+    /// Parses the result of the aggregation `name` into a typed response, such as
+    /// [`DateHistogramResponse`](crate::DateHistogramResponse). Returns `None` when the response
+    /// holds no such aggregation.
+    pub fn aggregation<T>(&self, name: &str) -> Option<Result<T, serde_json::Error>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.aggs().parse(name)
+    }
 }
 
 /// Number of shards touched with their states
@@ -99,13 +130,31 @@ pub struct Hit<H, IH> {
     /// Field values for the documents. Need to be specified in the request
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
     pub fields: std::collections::BTreeMap<String, Value>,
+
+    /// Score computation of the document, returned when searching with
+    /// [`explain`](crate::Search::explain) enabled
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "_explanation",
+        default
+    )]
+    pub explanation: Option<Explanation>,
 }
 
 /// Represents inner hits
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct InnerHitsResponse<IH> {
-    /// Inner hits items
-    pub items: InnerHitsItems<IH>,
+    /// Inner hits items, keyed by the [name](crate::InnerHits::name) of the inner hits
+    /// definition, which defaults to the query's `path`, `type` or `parent_type`
+    #[serde(flatten)]
+    pub items: HashMap<String, InnerHitsItems<IH>>,
+}
+
+impl<IH> InnerHitsResponse<IH> {
+    /// Matched inner hits of the inner hits definition `name`
+    pub fn get(&self, name: &str) -> Option<&InnerHitsItemsHits<IH>> {
+        self.items.get(name).map(|items| &items.hits)
+    }
 }
 
 /// Represents inner hits
@@ -175,6 +224,39 @@ pub struct Total {
     pub relation: Relation,
 }
 
+impl<H, IH> Hit<H, IH> {
+    /// Distance of the document computed by the first
+    /// [`GeoDistanceSort`](crate::GeoDistanceSort) of `search`, the request this hit was
+    /// returned for. [`None`] when the request has no geo distance sort.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # use serde_json::json;
+    /// let search = Search::new().sort(
+    ///     Sort::geo_distance("location", GeoPoint::coordinates(52.37, 4.89))
+    ///         .unit(DistanceUnit::Kilometers),
+    /// );
+    /// # let response: SearchResponse = serde_json::from_value(json!({
+    /// #     "took": 1,
+    /// #     "timed_out": false,
+    /// #     "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+    /// #     "hits": { "hits": [{ "_id": "1", "sort": [1.25] }] }
+    /// # })).unwrap();
+    /// let distance = response.hits.hits[0].geo_distance(&search).unwrap();
+    ///
+    /// assert_eq!(distance.value, 1.25);
+    /// assert_eq!(distance.unit, DistanceUnit::Kilometers);
+    /// ```
+    pub fn geo_distance(&self, search: &Search) -> Option<SortDistance> {
+        search
+            .sorts()
+            .iter()
+            .enumerate()
+            .find_map(|(position, sort)| Some((position, sort.distance_unit()?)))
+            .and_then(|(position, unit)| SortDistance::parse(self.sort.get(position)?, unit))
+    }
+}
+
 impl Total {
     /// Create default Total instance
     pub fn new(value: Option<u64>) -> Self {
@@ -212,12 +294,14 @@ pub enum Relation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::search::{DistanceUnit, GeoPoint, Sort};
 
     #[test]
     fn deserializes_successfully() {
         let json = serde_json::json!({
           "took": 6,
           "timed_out": false,
+          "num_reduce_phases": 2,
           "_shards": {
             "total": 10,
             "successful": 5,
@@ -246,6 +330,8 @@ mod tests {
         let expected = SearchResponse {
             took: 6,
             timed_out: false,
+            num_reduce_phases: Some(2),
+            terminated_early: None,
             shards: Shards {
                 total: 10,
                 successful: 5,
@@ -269,11 +355,82 @@ mod tests {
                     matched_queries: Default::default(),
                     sort: Default::default(),
                     fields: Default::default(),
+                    explanation: None,
                 }],
             },
             aggregations: None,
+            pit_id: None,
         };
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn deserializes_named_inner_hits() {
+        let hit: Hit<Value, Value> = serde_json::from_value(serde_json::json!({
+            "_id": "1",
+            "inner_hits": {
+                "comments": {
+                    "hits": {
+                        "total": { "value": 1, "relation": "eq" },
+                        "max_score": 1.5,
+                        "hits": [
+                            {
+                                "_id": "1",
+                                "_score": 1.5,
+                                "_nested": { "field": "comments", "offset": 2 },
+                                "_source": { "text": "rust" }
+                            }
+                        ]
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let inner_hits = hit.inner_hits.unwrap();
+        let comments = inner_hits.get("comments").unwrap();
+
+        assert_eq!(comments.hits.len(), 1);
+        assert_eq!(
+            comments.hits[0].nested,
+            Some(Nested {
+                field: "comments".into(),
+                offset: 2,
+            })
+        );
+        assert_eq!(
+            comments.hits[0].source,
+            Some(serde_json::json!({ "text": "rust" }))
+        );
+        assert!(inner_hits.get("answers").is_none());
+    }
+
+    #[test]
+    fn reads_geo_distance_sort_values() {
+        let hit: Hit<Value, Value> = serde_json::from_value(serde_json::json!({
+            "_id": "1",
+            "sort": ["open", 3.2]
+        }))
+        .unwrap();
+
+        let search = Search::new()
+            .sort(Sort::new("status"))
+            .sort(Sort::geo_distance(
+                "location",
+                GeoPoint::coordinates(52.37, 4.89),
+            ));
+
+        assert_eq!(
+            hit.geo_distance(&search),
+            Some(SortDistance {
+                value: 3.2,
+                unit: DistanceUnit::Meters,
+            })
+        );
+        assert_eq!(
+            hit.geo_distance(&Search::new().sort(Sort::new("status"))),
+            None
+        );
+    }
 }
@@ -0,0 +1,171 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A retriever that returns top documents from a
+/// [kNN search](https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html).
+///
+/// To create a kNN retriever:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let retriever =
+/// Retriever::knn("vector", [1.0, 2.0, 3.0], 10, 100)
+///     .filter(Query::term("in_stock", true));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#knn-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnnRetriever {
+    #[serde(rename = "knn")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<Vec<f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector_builder: Option<QueryVectorBuilder>,
+
+    k: u64,
+
+    num_candidates: u64,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Queries,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<f32>,
+}
+
+impl Retriever {
+    /// Creates an instance of [`KnnRetriever`] with a raw query vector
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector` - Query vector. Must have the same number of dimensions as the vector
+    ///   field you are searching against.
+    /// - `k` - Number of nearest neighbors to return as top hits
+    /// - `num_candidates` - The number of nearest neighbor candidates to consider per shard
+    pub fn knn<T>(
+        field: impl ToString,
+        query_vector: T,
+        k: u64,
+        num_candidates: u64,
+    ) -> KnnRetriever
+    where
+        T: IntoIterator,
+        T::Item: Into<f32>,
+    {
+        KnnRetriever {
+            inner: Inner {
+                field: field.to_string(),
+                query_vector: Some(query_vector.into_iter().map(Into::into).collect()),
+                query_vector_builder: None,
+                k,
+                num_candidates,
+                filter: Default::default(),
+                similarity: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`KnnRetriever`] with a query vector built by Elasticsearch
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector_builder` - A configuration object indicating how to build a query vector
+    ///   before executing the request
+    /// - `k` - Number of nearest neighbors to return as top hits
+    /// - `num_candidates` - The number of nearest neighbor candidates to consider per shard
+    pub fn knn_query_vector_builder(
+        field: impl ToString,
+        query_vector_builder: impl Into<QueryVectorBuilder>,
+        k: u64,
+        num_candidates: u64,
+    ) -> KnnRetriever {
+        KnnRetriever {
+            inner: Inner {
+                field: field.to_string(),
+                query_vector: None,
+                query_vector_builder: Some(query_vector_builder.into()),
+                k,
+                num_candidates,
+                filter: Default::default(),
+                similarity: None,
+            },
+        }
+    }
+}
+
+impl KnnRetriever {
+    /// Query to filter the documents that can match. The kNN search will return the top `k`
+    /// documents that also match this filter.
+    pub fn filter(mut self, filter: impl Into<Queries>) -> Self {
+        self.inner.filter.extend(filter);
+        self
+    }
+
+    /// The minimum similarity required for a document to be considered a match
+    pub fn similarity(mut self, similarity: impl Into<f32>) -> Self {
+        self.inner.similarity = Some(similarity.into());
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner.filter.iter().collect()
+    }
+}
+
+impl ShouldSkip for KnnRetriever {
+    fn should_skip(&self) -> bool {
+        self.inner.field.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::knn("vector", [1.0, 2.0, 3.0], 10, 100),
+            json!({
+                "knn": {
+                    "field": "vector",
+                    "query_vector": [1.0, 2.0, 3.0],
+                    "k": 10,
+                    "num_candidates": 100
+                }
+            }),
+        );
+
+        assert_serialize(
+            Retriever::knn_query_vector_builder(
+                "vector",
+                QueryVectorBuilder::text_embedding("my-model", "red shoes"),
+                5,
+                50,
+            )
+            .filter(Query::term("in_stock", true))
+            .similarity(0.5),
+            json!({
+                "knn": {
+                    "field": "vector",
+                    "query_vector_builder": {
+                        "text_embedding": {
+                            "model_id": "my-model",
+                            "model_text": "red shoes"
+                        }
+                    },
+                    "k": 5,
+                    "num_candidates": 50,
+                    "filter": [
+                        { "term": { "in_stock": { "value": true } } }
+                    ],
+                    "similarity": 0.5
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! A retriever is a specification to describe top documents returned from a search. Retrievers
+//! can be nested, which allows combining lexical and vector results with
+//! [reciprocal rank fusion](https://www.elastic.co/guide/en/elasticsearch/reference/current/rrf.html).
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html>
+
+mod knn_retriever;
+mod rrf_retriever;
+mod standard_retriever;
+
+pub use self::knn_retriever::*;
+pub use self::rrf_retriever::*;
+pub use self::standard_retriever::*;
+
+use crate::search::*;
+use crate::util::*;
+
+/// A container enum for supported Elasticsearch retriever types
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+#[allow(missing_docs, clippy::large_enum_variant)]
+pub enum Retriever {
+    Standard(StandardRetriever),
+    Knn(KnnRetriever),
+    Rrf(RrfRetriever),
+}
+
+impl Retriever {
+    /// Queries of the retriever tree, such as standard retriever queries and kNN filters
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        match self {
+            Self::Standard(retriever) => retriever.embedded_queries(),
+            Self::Knn(retriever) => retriever.embedded_queries(),
+            Self::Rrf(retriever) => retriever.embedded_queries(),
+        }
+    }
+}
+
+impl ShouldSkip for Retriever {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Standard(retriever) => retriever.should_skip(),
+            Self::Knn(retriever) => retriever.should_skip(),
+            Self::Rrf(retriever) => retriever.should_skip(),
+        }
+    }
+}
+
+impl From<StandardRetriever> for Retriever {
+    fn from(retriever: StandardRetriever) -> Self {
+        Self::Standard(retriever)
+    }
+}
+
+impl From<KnnRetriever> for Retriever {
+    fn from(retriever: KnnRetriever) -> Self {
+        Self::Knn(retriever)
+    }
+}
+
+impl From<RrfRetriever> for Retriever {
+    fn from(retriever: RrfRetriever) -> Self {
+        Self::Rrf(retriever)
+    }
+}
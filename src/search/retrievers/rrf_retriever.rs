@@ -0,0 +1,174 @@
+use crate::search::*;
+use crate::util::*;
+use std::convert::TryInto;
+
+/// A retriever that produces top documents from
+/// [reciprocal rank fusion](https://www.elastic.co/guide/en/elasticsearch/reference/current/rrf.html)
+/// of the result sets of its child retrievers.
+///
+/// To combine lexical and vector search:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let retriever =
+/// Retriever::rrf([
+///     Retriever::from(Retriever::standard(Query::r#match("text", "shoes"))),
+///     Retriever::from(Retriever::knn("vector", [1.0, 2.0, 3.0], 10, 100)),
+/// ])
+/// .rank_window_size(50)
+/// .rank_constant(20);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#rrf-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RrfRetriever {
+    #[serde(rename = "rrf")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    retrievers: Vec<Retriever>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_window_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_constant: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Queries,
+}
+
+impl Retriever {
+    /// Creates an instance of [`RrfRetriever`]
+    ///
+    /// - `retrievers` - A list of child retrievers to specify which sets of returned top
+    ///   documents will have the RRF formula applied to them
+    pub fn rrf<I>(retrievers: I) -> RrfRetriever
+    where
+        I: IntoIterator,
+        I::Item: Into<Retriever>,
+    {
+        RrfRetriever {
+            inner: Inner {
+                retrievers: retrievers
+                    .into_iter()
+                    .map(Into::into)
+                    .filter(|retriever: &Retriever| !retriever.should_skip())
+                    .collect(),
+                rank_window_size: None,
+                rank_constant: None,
+                filter: Default::default(),
+            },
+        }
+    }
+}
+
+impl RrfRetriever {
+    /// Adds a child retriever
+    pub fn retriever(mut self, retriever: impl Into<Retriever>) -> Self {
+        let retriever = retriever.into();
+
+        if !retriever.should_skip() {
+            self.inner.retrievers.push(retriever);
+        }
+
+        self
+    }
+
+    /// The size of the individual result sets per query. A higher value improves result
+    /// relevance at the cost of performance.
+    ///
+    /// Defaults to `size`.
+    pub fn rank_window_size(mut self, rank_window_size: impl TryInto<u64>) -> Self {
+        if let Ok(rank_window_size) = rank_window_size.try_into() {
+            self.inner.rank_window_size = Some(rank_window_size);
+        }
+        self
+    }
+
+    /// Determines how much influence documents in individual result sets per query have over
+    /// the final ranked result set. A higher value indicates that lower ranked documents have
+    /// more influence.
+    ///
+    /// Defaults to `60`.
+    pub fn rank_constant(mut self, rank_constant: impl TryInto<u64>) -> Self {
+        if let Ok(rank_constant) = rank_constant.try_into() {
+            self.inner.rank_constant = Some(rank_constant);
+        }
+        self
+    }
+
+    /// Applies the specified boolean query filter to all of the specified child retrievers
+    pub fn filter(mut self, filter: impl Into<Queries>) -> Self {
+        self.inner.filter.extend(filter);
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner
+            .retrievers
+            .iter()
+            .flat_map(Retriever::embedded_queries)
+            .chain(self.inner.filter.iter())
+            .collect()
+    }
+}
+
+impl ShouldSkip for RrfRetriever {
+    fn should_skip(&self) -> bool {
+        self.inner.retrievers.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::rrf([Retriever::standard(Query::term("user", "kimchy"))]),
+            json!({
+                "rrf": {
+                    "retrievers": [
+                        { "standard": { "query": { "term": { "user": { "value": "kimchy" } } } } }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Retriever::rrf([Retriever::standard(Query::r#match("text", "shoes"))])
+                .retriever(
+                    Retriever::knn("vector", [1.0, 2.0], 10, 100)
+                        .filter(Query::term("color", "red")),
+                )
+                .rank_window_size(50)
+                .rank_constant(20)
+                .filter(Query::term("in_stock", true)),
+            json!({
+                "rrf": {
+                    "retrievers": [
+                        { "standard": { "query": { "match": { "text": { "query": "shoes" } } } } },
+                        {
+                            "knn": {
+                                "field": "vector",
+                                "query_vector": [1.0, 2.0],
+                                "k": 10,
+                                "num_candidates": 100,
+                                "filter": [
+                                    { "term": { "color": { "value": "red" } } }
+                                ]
+                            }
+                        }
+                    ],
+                    "rank_window_size": 50,
+                    "rank_constant": 20,
+                    "filter": [
+                        { "term": { "in_stock": { "value": true } } }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A retriever that replaces the functionality of a traditional query.
+///
+/// To create a standard retriever:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let retriever =
+/// Retriever::standard(Query::r#match("text", "shoes"))
+///     .filter(Query::term("in_stock", true));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#standard-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StandardRetriever {
+    #[serde(rename = "standard")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Queries,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_score: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: Vec<Sort>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collapse: Option<Collapse>,
+}
+
+impl Retriever {
+    /// Creates an instance of [`StandardRetriever`]
+    ///
+    /// - `query` - Defines a query to retrieve a set of top documents
+    pub fn standard(query: impl Into<Option<Query>>) -> StandardRetriever {
+        StandardRetriever {
+            inner: Inner {
+                query: query.into().filter(|query| !query.should_skip()),
+                filter: Default::default(),
+                min_score: None,
+                sort: vec![],
+                collapse: None,
+            },
+        }
+    }
+}
+
+impl StandardRetriever {
+    /// Applies a boolean query filter to this retriever, where all documents must match this
+    /// query but do not contribute to the score.
+    pub fn filter(mut self, filter: impl Into<Queries>) -> Self {
+        self.inner.filter.extend(filter);
+        self
+    }
+
+    /// Minimum score for matching documents. Documents with a lower score are not included in
+    /// the top documents.
+    pub fn min_score(mut self, min_score: impl Into<f32>) -> Self {
+        self.inner.min_score = Some(min_score.into());
+        self
+    }
+
+    /// A collection of sorting fields
+    pub fn sort(mut self, sort: impl Into<Vec<Sort>>) -> Self {
+        self.inner.sort.extend(sort.into());
+        self
+    }
+
+    /// Collapses the top documents by a specified key into a single top document per key
+    pub fn collapse(mut self, collapse: impl Into<Collapse>) -> Self {
+        let collapse = collapse.into();
+
+        if !collapse.should_skip() {
+            self.inner.collapse = Some(collapse);
+        }
+
+        self
+    }
+
+    pub(crate) fn embedded_queries(&self) -> Vec<&Query> {
+        self.inner
+            .query
+            .iter()
+            .chain(self.inner.filter.iter())
+            .chain(
+                self.inner
+                    .collapse
+                    .iter()
+                    .flat_map(Collapse::embedded_queries),
+            )
+            .collect()
+    }
+}
+
+impl ShouldSkip for StandardRetriever {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::standard(Query::r#match("text", "shoes")),
+            json!({
+                "standard": {
+                    "query": { "match": { "text": { "query": "shoes" } } }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Retriever::standard(Query::r#match("text", "shoes"))
+                .filter(Query::term("in_stock", true))
+                .min_score(1.5)
+                .sort(Sort::new("price").order(SortOrder::Asc))
+                .collapse("brand"),
+            json!({
+                "standard": {
+                    "query": { "match": { "text": { "query": "shoes" } } },
+                    "filter": [
+                        { "term": { "in_stock": { "value": true } } }
+                    ],
+                    "min_score": 1.5,
+                    "sort": [
+                        { "price": { "order": "asc" } }
+                    ],
+                    "collapse": { "field": "brand" }
+                }
+            }),
+        );
+    }
+}
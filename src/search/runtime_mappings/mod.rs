@@ -10,6 +10,8 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/master/runtime-search-request.html>
 
+use crate::search::*;
+use crate::util::*;
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// A runtime data type that is used in a search request.
@@ -38,6 +40,22 @@ pub enum RuntimeDataType {
 
     /// Long
     Long,
+
+    /// Retrieves fields from a related index at query time. Lookup runtime fields don't
+    /// take a script.
+    Lookup {
+        /// The index to fetch the fields from
+        target_index: String,
+
+        /// The field of the current index whose value is looked up in `target_field`
+        input_field: String,
+
+        /// The field of the target index to match `input_field` values against
+        target_field: String,
+
+        /// Fields of the matching target documents to return
+        fetch_fields: Vec<String>,
+    },
 }
 
 impl Serialize for RuntimeDataType {
@@ -94,21 +112,32 @@ impl Serialize for RuntimeDataType {
                 state.serialize_field("type", "long")?;
                 state.end()
             }
+            Self::Lookup {
+                target_index,
+                input_field,
+                target_field,
+                fetch_fields,
+            } => {
+                let mut state = serializer.serialize_struct("RuntimeDataType_Lookup", 5)?;
+                state.serialize_field("type", "lookup")?;
+                state.serialize_field("target_index", target_index)?;
+                state.serialize_field("input_field", input_field)?;
+                state.serialize_field("target_field", target_field)?;
+                state.serialize_field("fetch_fields", fetch_fields)?;
+                state.end()
+            }
         }
     }
 }
 
 /// A runtime field that is used in a search request.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RuntimeMapping {
     #[serde(flatten)]
     r#type: RuntimeDataType,
-    script: RuntimeScript,
-}
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
-struct RuntimeScript {
-    source: String,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
 }
 
 impl RuntimeMapping {
@@ -117,11 +146,72 @@ impl RuntimeMapping {
     where
         T: ToString,
     {
+        Self::with_script(r#type, Script::source(source))
+    }
+
+    /// Creates a new instance of [RuntimeMapping] with a full [Script], e.g. to pass `params`
+    /// or to reference a stored script
+    pub fn with_script(r#type: RuntimeDataType, script: Script) -> Self {
         RuntimeMapping {
             r#type,
-            script: RuntimeScript {
-                source: source.to_string(),
+            script: Some(script),
+        }
+    }
+
+    /// Creates a new instance of [RuntimeDataType::Keyword] [RuntimeMapping] with a full
+    /// [Script]
+    pub fn keyword_script(script: Script) -> Self {
+        Self::with_script(RuntimeDataType::Keyword, script)
+    }
+
+    /// Creates a new instance of [RuntimeDataType::Long] [RuntimeMapping] with a full [Script]
+    pub fn long_script(script: Script) -> Self {
+        Self::with_script(RuntimeDataType::Long, script)
+    }
+
+    /// Creates a new instance of [RuntimeDataType::Date] [RuntimeMapping] with format and a
+    /// full [Script]
+    pub fn date_script<F>(format: F, script: Script) -> Self
+    where
+        F: ToString,
+    {
+        Self::with_script(RuntimeDataType::Date(Some(format.to_string())), script)
+    }
+
+    /// Creates a new instance of [RuntimeDataType::Lookup] [RuntimeMapping]
+    ///
+    /// - `target_index` - The index to fetch the fields from
+    /// - `input_field` - The field of the current index whose value is looked up
+    /// - `target_field` - The field of the target index to match `input_field` values against
+    /// - `fetch_fields` - Fields of the matching target documents to return
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let mapping =
+    /// RuntimeMapping::lookup("ip_location", "host", "ip", ["country", "city"]);
+    /// ```
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime-retrieving-fields.html#lookup-runtime-fields>
+    pub fn lookup<T, I, F, S>(
+        target_index: T,
+        input_field: I,
+        target_field: F,
+        fetch_fields: S,
+    ) -> Self
+    where
+        T: ToString,
+        I: ToString,
+        F: ToString,
+        S: IntoIterator,
+        S::Item: ToString,
+    {
+        RuntimeMapping {
+            r#type: RuntimeDataType::Lookup {
+                target_index: target_index.to_string(),
+                input_field: input_field.to_string(),
+                target_field: target_field.to_string(),
+                fetch_fields: fetch_fields.into_iter().map(|x| x.to_string()).collect(),
             },
+            script: None,
         }
     }
 
@@ -202,7 +292,6 @@ impl RuntimeMapping {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::util::*;
 
     #[test]
     fn serialization() {
@@ -279,4 +368,54 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serialization_with_script() {
+        assert_serialize(
+            RuntimeMapping::keyword_script(
+                Script::source("emit(doc[params.field].value)").param("field", "name"),
+            ),
+            json!({
+                "type": "keyword",
+                "script": {
+                    "source": "emit(doc[params.field].value)",
+                    "params": { "field": "name" }
+                }
+            }),
+        );
+
+        assert_serialize(
+            RuntimeMapping::long_script(Script::id("stored-script")),
+            json!({
+                "type": "long",
+                "script": { "id": "stored-script" }
+            }),
+        );
+
+        assert_serialize(
+            RuntimeMapping::date_script(
+                "yyyy-MM-dd",
+                Script::source("emit(doc['timestamp'].value.toEpochMilli())").lang("painless"),
+            ),
+            json!({
+                "type": "date",
+                "format": "yyyy-MM-dd",
+                "script": {
+                    "source": "emit(doc['timestamp'].value.toEpochMilli())",
+                    "lang": "painless"
+                }
+            }),
+        );
+
+        assert_serialize(
+            RuntimeMapping::lookup("ip_location", "host", "ip", ["country", "city"]),
+            json!({
+                "type": "lookup",
+                "target_index": "ip_location",
+                "input_field": "host",
+                "target_field": "ip",
+                "fetch_fields": ["country", "city"]
+            }),
+        );
+    }
 }
@@ -0,0 +1,135 @@
+use crate::search::*;
+use serde_json::{Map, Value};
+
+/// Sections of a search request body that differ between two requests, see [`Search::diff`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchDiff {
+    changes: Vec<SectionChange>,
+}
+
+/// Single top level section, such as `query`, `aggs` or `size`, that differs between two search
+/// requests
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionChange {
+    /// Name of the section, as it appears in the request body
+    pub section: String,
+
+    /// Serialized section of the original request, [`None`] when it was added
+    pub before: Option<Value>,
+
+    /// Serialized section of the other request, [`None`] when it was removed
+    pub after: Option<Value>,
+}
+
+impl Search {
+    /// Compares the serialized request bodies section by section, e.g. to audit log what a
+    /// user changed in a saved search or to assert in tests that only the intended parts of a
+    /// request template changed.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// let template = Search::new()
+    ///     .size(10)
+    ///     .query(Query::term("status", "published"));
+    /// let search = template.clone().size(20).sort(Sort::new("@timestamp"));
+    ///
+    /// let diff = template.diff(&search)?;
+    ///
+    /// assert_eq!(diff.sections().collect::<Vec<_>>(), ["size", "sort"]);
+    /// assert!(!diff.contains("query"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn diff(&self, other: &Self) -> Result<SearchDiff, serde_json::Error> {
+        let before = sections(self)?;
+        let after = sections(other)?;
+
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let changes = names
+            .into_iter()
+            .filter(|name| before.get(*name) != after.get(*name))
+            .map(|name| SectionChange {
+                section: name.clone(),
+                before: before.get(name).cloned(),
+                after: after.get(name).cloned(),
+            })
+            .collect();
+
+        Ok(SearchDiff { changes })
+    }
+}
+
+impl SearchDiff {
+    /// Returns `true` when both requests serialize to the same body
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Changed sections, ordered by name
+    pub fn changes(&self) -> &[SectionChange] {
+        &self.changes
+    }
+
+    /// Names of the changed sections, ordered by name
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.changes.iter().map(|change| change.section.as_str())
+    }
+
+    /// Returns `true` when the `section` changed
+    pub fn contains(&self, section: &str) -> bool {
+        self.get(section).is_some()
+    }
+
+    /// Change of the `section`, if it changed
+    pub fn get(&self, section: &str) -> Option<&SectionChange> {
+        self.changes.iter().find(|change| change.section == section)
+    }
+}
+
+fn sections(search: &Search) -> Result<Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(search)? {
+        Value::Object(sections) => Ok(sections),
+        _ => Ok(Map::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diffs_sections() {
+        let template = Search::new()
+            .size(10)
+            .query(Query::term("status", "published"))
+            .aggregate("tags", Aggregation::terms("tags"));
+
+        assert!(template.diff(&template.clone()).unwrap().is_empty());
+
+        let search = Search::new()
+            .size(10)
+            .query(Query::term("status", "draft"))
+            .sort(Sort::new("@timestamp"));
+
+        let diff = template.diff(&search).unwrap();
+
+        assert_eq!(
+            diff.sections().collect::<Vec<_>>(),
+            ["aggs", "query", "sort"]
+        );
+        assert_eq!(
+            diff.get("query"),
+            Some(&SectionChange {
+                section: "query".to_string(),
+                before: Some(json!({ "term": { "status": { "value": "published" } } })),
+                after: Some(json!({ "term": { "status": { "value": "draft" } } })),
+            })
+        );
+        assert_eq!(diff.get("aggs").unwrap().after, None);
+        assert_eq!(diff.get("sort").unwrap().before, None);
+        assert!(!diff.contains("size"));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::util::*;
+use std::fmt::Write;
+use std::iter::FromIterator;
+
+/// Data streams, indices and aliases targeted by a search request, expressed in the
+/// [multi-target syntax](https://www.elastic.co/guide/en/elasticsearch/reference/current/api-conventions.html#api-multi-index).
+///
+/// Supports plain names, wildcard expressions, exclusions,
+/// [date math index names](https://www.elastic.co/guide/en/elasticsearch/reference/current/api-conventions.html#api-date-math-index-names)
+/// and [cross-cluster](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-cross-cluster-search.html)
+/// `cluster:index` references. Serializes as a list of names, as expected by the `index` key of
+/// a multi search header, while [`SearchTarget::to_path`] renders the URL encoded path segment.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// let target = SearchTarget::new()
+///     .index("logs-*")
+///     .exclude("logs-debug")
+///     .index("<logs-{now/d}>")
+///     .remote("europe", "logs-*");
+///
+/// assert_eq!(
+///     target.to_path(),
+///     "logs-*,-logs-debug,%3Clogs-%7Bnow%2Fd%7D%3E,europe:logs-*"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SearchTarget(Vec<String>);
+
+impl SearchTarget {
+    /// Creates an empty [`SearchTarget`], which targets all data streams and indices
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a data stream, index or alias name. Accepts wildcard expressions such as `logs-*`
+    /// and date math names such as `<logs-{now/d}>`.
+    pub fn index(mut self, index: impl ToString) -> Self {
+        let index = index.to_string();
+
+        if !index.should_skip() {
+            self.0.push(index);
+        }
+        self
+    }
+
+    /// Excludes indices matched by a previously added wildcard expression
+    pub fn exclude(self, index: impl ToString) -> Self {
+        self.index(format!("-{}", index.to_string()))
+    }
+
+    /// Adds an index of a remote cluster for cross-cluster search. Both `cluster` and `index`
+    /// accept wildcard expressions.
+    pub fn remote(self, cluster: impl ToString, index: impl ToString) -> Self {
+        let cluster = cluster.to_string();
+        let index = index.to_string();
+
+        if cluster.should_skip() || index.should_skip() {
+            return self;
+        }
+
+        self.index(format!("{}:{}", cluster, index))
+    }
+
+    /// Returns the targeted names
+    pub fn names(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns `true` when no names were added, meaning all data streams and indices are
+    /// targeted
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Renders the target as a URL path segment. Names are comma separated, date math
+    /// expressions are percent encoded and an empty target is rendered as `_all`.
+    pub fn to_path(&self) -> String {
+        if self.0.is_empty() {
+            return String::from("_all");
+        }
+
+        self.0
+            .iter()
+            .map(|name| encode_name(name))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl ShouldSkip for SearchTarget {
+    fn should_skip(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&str> for SearchTarget {
+    fn from(value: &str) -> Self {
+        Self::new().index(value)
+    }
+}
+
+impl From<String> for SearchTarget {
+    fn from(value: String) -> Self {
+        Self::new().index(value)
+    }
+}
+
+impl<T> From<Vec<T>> for SearchTarget
+where
+    T: ToString,
+{
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SearchTarget
+where
+    T: ToString,
+{
+    fn from(values: [T; N]) -> Self {
+        IntoIterator::into_iter(values).collect()
+    }
+}
+
+impl<T> FromIterator<T> for SearchTarget
+where
+    T: ToString,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().fold(Self::new(), Self::index)
+    }
+}
+
+/// Percent encodes a single name. Cluster prefixes, exclusions and wildcards are kept as is,
+/// while everything from the opening `<` of a date math expression onwards is fully encoded.
+fn encode_name(name: &str) -> String {
+    let (plain, date_math) = match name.find('<') {
+        Some(position) => name.split_at(position),
+        None => (name, ""),
+    };
+
+    let mut encoded = String::with_capacity(name.len());
+
+    for character in plain.chars() {
+        if matches!(character, '*' | ':') {
+            encoded.push(character);
+        } else {
+            encode_char(character, &mut encoded);
+        }
+    }
+
+    for character in date_math.chars() {
+        encode_char(character, &mut encoded);
+    }
+
+    encoded
+}
+
+fn encode_char(character: char, encoded: &mut String) {
+    if character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.' | '~') {
+        encoded.push(character);
+        return;
+    }
+
+    let mut buffer = [0; 4];
+
+    for byte in character.encode_utf8(&mut buffer).bytes() {
+        let _ = write!(encoded, "%{:02X}", byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(SearchTarget::new(), json!([]));
+
+        assert_serialize(
+            SearchTarget::from(["index-1", "index-2"]).remote("cluster", "index-3"),
+            json!(["index-1", "index-2", "cluster:index-3"]),
+        );
+    }
+
+    #[test]
+    fn to_path() {
+        assert_eq!(SearchTarget::new().to_path(), "_all");
+        assert_eq!(SearchTarget::from("").to_path(), "_all");
+        assert_eq!(SearchTarget::from("my-index").to_path(), "my-index");
+
+        assert_eq!(
+            SearchTarget::from(vec!["logs-*", "metrics"])
+                .exclude("logs-debug")
+                .to_path(),
+            "logs-*,metrics,-logs-debug"
+        );
+
+        assert_eq!(
+            SearchTarget::from("<logstash-{now/d{yyyy.MM.dd|+12:00}}>").to_path(),
+            "%3Clogstash-%7Bnow%2Fd%7Byyyy.MM.dd%7C%2B12%3A00%7D%7D%3E"
+        );
+
+        assert_eq!(
+            SearchTarget::new()
+                .remote("cluster_*", "<logs-{now/M}>")
+                .remote("", "ignored")
+                .to_path(),
+            "cluster_*:%3Clogs-%7Bnow%2FM%7D%3E"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Sorts search hits by their distance to one or more geo points, e.g. to list the nearest
+/// stores first.
+///
+/// The computed distance of each hit is returned in its `sort` values, in the requested
+/// [`unit`](GeoDistanceSort::unit), and can be read with [`Hit::geo_distance`].
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let search =
+/// Search::new().sort(
+///     Sort::geo_distance("pin.location", GeoPoint::coordinates(40.0, -70.0))
+///         .order(SortOrder::Asc)
+///         .unit(DistanceUnit::Kilometers),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#geo-sorting>
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoDistanceSort {
+    field: String,
+    points: Vec<GeoPoint>,
+    order: Option<SortOrder>,
+    unit: Option<DistanceUnit>,
+    mode: Option<SortMode>,
+    distance_type: Option<DistanceType>,
+    ignore_unmapped: Option<bool>,
+}
+
+/// Distance of a hit to the geo points of a [`GeoDistanceSort`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SortDistance {
+    /// Computed distance, infinite when the document has no value for the sorted field
+    pub value: f64,
+
+    /// Unit of the computed distance
+    pub unit: DistanceUnit,
+}
+
+impl Sort {
+    /// Creates an instance of [`GeoDistanceSort`]
+    ///
+    /// - `field` - Field containing the geo points of the documents
+    /// - `points` - One or more points to compute the distance to. With multiple points, the
+    ///   distance to the closest one is used unless a different [`mode`](GeoDistanceSort::mode)
+    ///   is set.
+    pub fn geo_distance<T, U>(field: T, points: U) -> GeoDistanceSort
+    where
+        T: ToString,
+        U: Into<GeoPoints>,
+    {
+        GeoDistanceSort {
+            field: field.to_string(),
+            points: points.into().0,
+            order: None,
+            unit: None,
+            mode: None,
+            distance_type: None,
+            ignore_unmapped: None,
+        }
+    }
+}
+
+/// Geo points passed to [`Sort::geo_distance`], either a single point or a collection of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoPoints(Vec<GeoPoint>);
+
+impl From<GeoPoint> for GeoPoints {
+    fn from(value: GeoPoint) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl From<Vec<GeoPoint>> for GeoPoints {
+    fn from(value: Vec<GeoPoint>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> From<[GeoPoint; N]> for GeoPoints {
+    fn from(value: [GeoPoint; N]) -> Self {
+        Self(IntoIterator::into_iter(value).collect())
+    }
+}
+
+impl GeoDistanceSort {
+    /// Explicit order, distances are sorted ascending by default
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Unit of the computed distances returned in the `sort` values of each hit. Defaults to
+    /// meters.
+    pub fn unit(mut self, unit: DistanceUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// What to do in case a field has several geo points. By default, the shortest distance is
+    /// taken into account when sorting in ascending order and the longest distance when sorting
+    /// in descending order. Supported values are `min`, `max`, `median` and `avg`.
+    pub fn mode(mut self, mode: SortMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// How to compute the distance, `arc` by default
+    pub fn distance_type(mut self, distance_type: DistanceType) -> Self {
+        self.distance_type = Some(distance_type);
+        self
+    }
+
+    /// Indicates if the unmapped field should be treated as a missing value. Setting it to
+    /// `true` is equivalent to specifying an `unmapped_type` in the field sort.
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = non_default(ignore_unmapped, false);
+        self
+    }
+
+    /// Unit the computed distances are returned in
+    pub(crate) fn distance_unit(&self) -> DistanceUnit {
+        self.unit.unwrap_or_default()
+    }
+}
+
+impl Serialize for GeoDistanceSort {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Inner<'a>(&'a GeoDistanceSort);
+
+        impl Serialize for Inner<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let sort = self.0;
+                let mut map = serializer.serialize_map(None)?;
+
+                match sort.points.as_slice() {
+                    [point] => map.serialize_entry(&sort.field, point)?,
+                    points => map.serialize_entry(&sort.field, points)?,
+                }
+
+                if !sort.order.should_skip() {
+                    map.serialize_entry("order", &sort.order)?;
+                }
+                if !sort.unit.should_skip() {
+                    map.serialize_entry("unit", &sort.unit)?;
+                }
+                if !sort.mode.should_skip() {
+                    map.serialize_entry("mode", &sort.mode)?;
+                }
+                if !sort.distance_type.should_skip() {
+                    map.serialize_entry("distance_type", &sort.distance_type)?;
+                }
+                if !sort.ignore_unmapped.should_skip() {
+                    map.serialize_entry("ignore_unmapped", &sort.ignore_unmapped)?;
+                }
+
+                map.end()
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("_geo_distance", &Inner(self))?;
+        map.end()
+    }
+}
+
+impl From<GeoDistanceSort> for Vec<Sort> {
+    fn from(sort: GeoDistanceSort) -> Self {
+        vec![sort.into()]
+    }
+}
+
+impl SortDistance {
+    /// Reads a distance from a `sort` value, which is either a number or, for documents without a
+    /// value in the sorted field, the string `"Infinity"`
+    pub(crate) fn parse(value: &serde_json::Value, unit: DistanceUnit) -> Option<Self> {
+        let value = match value {
+            serde_json::Value::Number(value) => value.as_f64()?,
+            serde_json::Value::String(value) => value.parse().ok()?,
+            _ => return None,
+        };
+
+        Some(Self { value, unit })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Sort::geo_distance("pin.location", GeoPoint::coordinates(40.0, -70.0)),
+            json!({
+                "_geo_distance": {
+                    "pin.location": [-70.0, 40.0]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Sort::geo_distance(
+                "pin.location",
+                [
+                    GeoPoint::coordinates(40.0, -70.0),
+                    GeoPoint::geohash("drm3btev3e86"),
+                ],
+            )
+            .order(SortOrder::Asc)
+            .unit(DistanceUnit::Kilometers)
+            .mode(SortMode::Min)
+            .distance_type(DistanceType::Arc)
+            .ignore_unmapped(true),
+            json!({
+                "_geo_distance": {
+                    "pin.location": [[-70.0, 40.0], "drm3btev3e86"],
+                    "order": "asc",
+                    "unit": "km",
+                    "mode": "min",
+                    "distance_type": "arc",
+                    "ignore_unmapped": true
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn parses_sort_distances() {
+        assert_eq!(
+            SortDistance::parse(&json!(1.5), DistanceUnit::Miles),
+            Some(SortDistance {
+                value: 1.5,
+                unit: DistanceUnit::Miles
+            })
+        );
+        assert_eq!(
+            SortDistance::parse(&json!("Infinity"), DistanceUnit::Meters).map(|d| d.value),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(
+            SortDistance::parse(&json!(null), DistanceUnit::Meters),
+            None
+        );
+    }
+}
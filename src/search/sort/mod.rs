@@ -7,6 +7,10 @@ use crate::search::*;
 use crate::util::*;
 use serde::ser::{Serialize, Serializer};
 
+mod geo_distance_sort;
+
+pub use self::geo_distance_sort::*;
+
 /// The order defaults to `desc` when sorting on the `_score`, and defaults to `asc` when sorting on anything else.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sort_order>
@@ -138,8 +142,15 @@ where
 /// Sorts search hits by other field values
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#sort-search-results>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct Sort(KeyValuePair<SortField, SortInner>);
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Sort(SortKind);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum SortKind {
+    Field(KeyValuePair<SortField, SortInner>),
+    GeoDistance(GeoDistanceSort),
+}
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 struct SortInner {
@@ -154,19 +165,56 @@ struct SortInner {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<SortMissing>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<DateFormat>,
 }
 
 impl Sort {
     /// Creates an instance of [`Sort`]
     pub fn new(field: impl Into<SortField>) -> Self {
-        Self(KeyValuePair::new(field.into(), Default::default()))
+        Self(SortKind::Field(KeyValuePair::new(
+            field.into(),
+            Default::default(),
+        )))
+    }
+
+    /// Sorts by relevance `_score`, highest scores first unless an [`order`](Self::order) is
+    /// given
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let sort =
+    /// Sort::score().order(SortOrder::Asc);
+    /// ```
+    pub fn score() -> Self {
+        Self::new(SortField::Score)
+    }
+
+    /// Sorts by index order, `_doc`. This is the most efficient sort order and is recommended
+    /// when iterating over all documents with `scroll` or a
+    /// [point in time](crate::PointInTime), regardless of their order.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let sort =
+    /// Sort::doc();
+    /// ```
+    pub fn doc() -> Self {
+        Self::new(SortField::Doc)
     }
 
     /// Explicit order
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sort_order>
     pub fn order(mut self, order: SortOrder) -> Self {
-        self.0.value.order = Some(order);
+        self.0 = match self.0 {
+            SortKind::Field(mut pair) => {
+                pair.value.order = Some(order);
+                SortKind::Field(pair)
+            }
+            SortKind::GeoDistance(sort) => SortKind::GeoDistance(sort.order(order)),
+        };
         self
     }
 
@@ -174,25 +222,65 @@ impl Sort {
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sort_mode_option>
     pub fn mode(mut self, mode: SortMode) -> Self {
-        self.0.value.mode = Some(mode);
+        self.0 = match self.0 {
+            SortKind::Field(mut pair) => {
+                pair.value.mode = Some(mode);
+                SortKind::Field(pair)
+            }
+            SortKind::GeoDistance(sort) => SortKind::GeoDistance(sort.mode(mode)),
+        };
         self
     }
 
-    /// Fallback type if mapping is not defined
+    /// Fallback type if mapping is not defined. Not applicable to geo distance sorts, which use
+    /// [`GeoDistanceSort::ignore_unmapped`] instead.
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_ignoring_unmapped_fields>
     pub fn unmapped_type(mut self, unmapped_type: impl Into<String>) -> Self {
-        self.0.value.unmapped_type = Some(unmapped_type.into());
+        if let SortKind::Field(pair) = &mut self.0 {
+            pair.value.unmapped_type = Some(unmapped_type.into());
+        }
         self
     }
 
-    /// The missing parameter specifies how docs which are missing the sort field should be treated
+    /// The missing parameter specifies how docs which are missing the sort field should be
+    /// treated. Not applicable to geo distance sorts, which always sort such docs last.
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_missing_values>
     pub fn missing(mut self, missing: impl Into<SortMissing>) -> Self {
-        self.0.value.missing = Some(missing.into());
+        if let SortKind::Field(pair) = &mut self.0 {
+            pair.value.missing = Some(missing.into());
+        }
         self
     }
+
+    /// Format of the sort values returned for date fields. Not applicable to geo distance sorts.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::*;
+    /// # let sort =
+    /// Sort::new("post_date").format(DateFormat::StrictDateOptionalTimeNanos);
+    /// ```
+    pub fn format(mut self, format: impl Into<DateFormat>) -> Self {
+        if let SortKind::Field(pair) = &mut self.0 {
+            pair.value.format = Some(format.into());
+        }
+        self
+    }
+
+    /// Unit of the distances returned by a geo distance sort, [`None`] for other sorts
+    pub(crate) fn distance_unit(&self) -> Option<DistanceUnit> {
+        match &self.0 {
+            SortKind::Field(_) => None,
+            SortKind::GeoDistance(sort) => Some(sort.distance_unit()),
+        }
+    }
+}
+
+impl From<GeoDistanceSort> for Sort {
+    fn from(sort: GeoDistanceSort) -> Self {
+        Self(SortKind::GeoDistance(sort))
+    }
 }
 
 impl From<Sort> for Vec<Sort> {
@@ -209,6 +297,16 @@ mod tests {
     fn serialization() {
         assert_serialize(Sort::new("test"), json!({"test": {}}));
         assert_serialize(Sort::new(SortField::Id), json!({"_id": {}}));
+        assert_serialize(Sort::score(), json!({"_score": {}}));
+        assert_serialize(
+            Sort::score().order(SortOrder::Asc),
+            json!({"_score": {"order": "asc"}}),
+        );
+        assert_serialize(Sort::doc(), json!({"_doc": {}}));
+        assert_serialize(
+            Sort::doc().order(SortOrder::Desc),
+            json!({"_doc": {"order": "desc"}}),
+        );
         assert_serialize(
             Sort::new("test")
                 .order(SortOrder::Asc)
@@ -224,5 +322,16 @@ mod tests {
                 }
             }),
         );
+        assert_serialize(
+            Sort::new("post_date")
+                .order(SortOrder::Desc)
+                .format(DateFormat::StrictDateOptionalTimeNanos),
+            json!({
+                "post_date": {
+                    "order": "desc",
+                    "format": "strict_date_optional_time_nanos"
+                }
+            }),
+        );
     }
 }
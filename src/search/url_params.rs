@@ -0,0 +1,206 @@
+use crate::util::*;
+use serde::Serializer;
+use std::convert::TryInto;
+
+/// URL query parameters of the search API that are not part of the request body.
+///
+/// Serializes into a flat map of query string parameters, so it can be handed to any URL encoder
+/// (e.g. `serde_urlencoded` or an HTTP client's `query` method) alongside the
+/// [`Search`](crate::Search) body.
+///
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let params =
+/// SearchUrlParams::new()
+///     .routing("user-1")
+///     .preference("_local")
+///     .request_cache(true)
+///     .allow_partial_search_results(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-search-api-query-params>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SearchUrlParams {
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        serialize_with = "join_with_comma"
+    )]
+    routing: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preference: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    request_cache: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    allow_partial_search_results: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_concurrent_shard_requests: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ccs_minimize_roundtrips: Option<bool>,
+}
+
+impl SearchUrlParams {
+    /// Creates an instance of [`SearchUrlParams`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Custom value used to route operations to a specific shard. Can be called multiple times
+    /// to route to several shards.
+    pub fn routing(mut self, routing: impl ToString) -> Self {
+        let routing = routing.to_string();
+
+        if !routing.should_skip() {
+            self.routing.push(routing);
+        }
+        self
+    }
+
+    /// Nodes and shards used for the search, e.g. `_local`, `_only_nodes:<node-id>` or a custom
+    /// string that keeps the same shard copies across requests of a user session.
+    pub fn preference(mut self, preference: impl ToString) -> Self {
+        self.preference = Some(preference.to_string());
+        self
+    }
+
+    /// If `true`, the request cache is used for this request. Defaults to the index-level
+    /// setting.
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.request_cache = Some(request_cache);
+        self
+    }
+
+    /// If `false`, the request returns an error if there are shard failures instead of partial
+    /// results. Defaults to `true`.
+    pub fn allow_partial_search_results(mut self, allow_partial_search_results: bool) -> Self {
+        self.allow_partial_search_results = Some(allow_partial_search_results);
+        self
+    }
+
+    /// The number of concurrent shard requests per node this search executes concurrently.
+    /// Defaults to `5`.
+    pub fn max_concurrent_shard_requests(
+        mut self,
+        max_concurrent_shard_requests: impl TryInto<u64>,
+    ) -> Self {
+        if let Ok(max_concurrent_shard_requests) = max_concurrent_shard_requests.try_into() {
+            if max_concurrent_shard_requests > 0 {
+                self.max_concurrent_shard_requests = Some(max_concurrent_shard_requests);
+            }
+        }
+        self
+    }
+
+    /// If `true`, network round-trips between the coordinating node and the remote clusters are
+    /// minimized when executing cross-cluster search requests. Defaults to `true`.
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.ccs_minimize_roundtrips = Some(ccs_minimize_roundtrips);
+        self
+    }
+
+    /// Returns the parameters as query string key-value pairs, in declaration order
+    pub fn pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+
+        if !self.routing.is_empty() {
+            pairs.push(("routing", self.routing.join(",")));
+        }
+        if let Some(preference) = &self.preference {
+            pairs.push(("preference", preference.clone()));
+        }
+        if let Some(request_cache) = self.request_cache {
+            pairs.push(("request_cache", request_cache.to_string()));
+        }
+        if let Some(allow_partial_search_results) = self.allow_partial_search_results {
+            pairs.push((
+                "allow_partial_search_results",
+                allow_partial_search_results.to_string(),
+            ));
+        }
+        if let Some(max_concurrent_shard_requests) = self.max_concurrent_shard_requests {
+            pairs.push((
+                "max_concurrent_shard_requests",
+                max_concurrent_shard_requests.to_string(),
+            ));
+        }
+        if let Some(ccs_minimize_roundtrips) = self.ccs_minimize_roundtrips {
+            pairs.push((
+                "ccs_minimize_roundtrips",
+                ccs_minimize_roundtrips.to_string(),
+            ));
+        }
+
+        pairs
+    }
+}
+
+impl ShouldSkip for SearchUrlParams {
+    fn should_skip(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+fn join_with_comma<S>(values: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(SearchUrlParams::new(), json!({}));
+
+        assert_serialize(
+            SearchUrlParams::new()
+                .routing("user-1")
+                .routing("user-2")
+                .preference("_local")
+                .request_cache(true)
+                .allow_partial_search_results(false)
+                .max_concurrent_shard_requests(3)
+                .ccs_minimize_roundtrips(false),
+            json!({
+                "routing": "user-1,user-2",
+                "preference": "_local",
+                "request_cache": true,
+                "allow_partial_search_results": false,
+                "max_concurrent_shard_requests": 3,
+                "ccs_minimize_roundtrips": false
+            }),
+        );
+
+        assert_serialize(
+            SearchUrlParams::new()
+                .routing("")
+                .max_concurrent_shard_requests(0),
+            json!({}),
+        );
+    }
+
+    #[test]
+    fn pairs() {
+        assert!(SearchUrlParams::new().pairs().is_empty());
+        assert!(SearchUrlParams::new().should_skip());
+
+        assert_eq!(
+            SearchUrlParams::new()
+                .routing("user-1")
+                .preference("_local")
+                .max_concurrent_shard_requests(3)
+                .pairs(),
+            vec![
+                ("routing", "user-1".to_string()),
+                ("preference", "_local".to_string()),
+                ("max_concurrent_shard_requests", "3".to_string()),
+            ]
+        );
+    }
+}
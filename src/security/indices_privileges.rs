@@ -0,0 +1,158 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Grants privileges on a set of indices, optionally restricting access to documents with a
+/// document level security query and to fields with field level security.
+///
+/// To grant read access to public documents only, without the `ssn` field:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let privileges =
+/// IndicesPrivileges::new(["customers"], ["read"])
+///     .query(Query::term("visibility", "public"))
+///     .grant(["*"])
+///     .except(["ssn"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/defining-roles.html#roles-indices-priv>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndicesPrivileges {
+    names: Vec<String>,
+
+    privileges: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field_security: FieldSecurity,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    allow_restricted_indices: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+struct FieldSecurity {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    grant: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    except: Vec<String>,
+}
+
+impl ShouldSkip for FieldSecurity {
+    fn should_skip(&self) -> bool {
+        self.grant.should_skip() && self.except.should_skip()
+    }
+}
+
+impl IndicesPrivileges {
+    /// Creates an instance of [`IndicesPrivileges`]
+    ///
+    /// - `names` - Indices (or index name patterns) to which the permissions apply
+    /// - `privileges` - Index level privileges the owners of the role have on the indices, such
+    ///   as `read`, `write` or `manage`
+    pub fn new<N, P>(names: N, privileges: P) -> Self
+    where
+        N: IntoIterator,
+        N::Item: Into<String>,
+        P: IntoIterator,
+        P::Item: Into<String>,
+    {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            privileges: privileges.into_iter().map(Into::into).collect(),
+            field_security: Default::default(),
+            query: None,
+            allow_restricted_indices: None,
+        }
+    }
+
+    /// Document level security query, restricting read access to documents that match the
+    /// query
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/document-level-security.html>
+    pub fn query(mut self, query: impl Into<Query>) -> Self {
+        let query = query.into();
+
+        if !query.should_skip() {
+            self.query = Some(query);
+        }
+
+        self
+    }
+
+    /// Fields the owners of the role have read access to, wildcards are supported
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/field-level-security.html>
+    pub fn grant<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.field_security
+            .grant
+            .extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Fields excluded from the granted fields, wildcards are supported
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/field-level-security.html>
+    pub fn except<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.field_security
+            .except
+            .extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// If `true`, `names` may match restricted indices such as `.security`. Defaults to
+    /// `false`.
+    pub fn allow_restricted_indices(mut self, allow_restricted_indices: bool) -> Self {
+        self.allow_restricted_indices = Some(allow_restricted_indices);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            IndicesPrivileges::new(["logs-*"], ["read", "view_index_metadata"]),
+            json!({
+                "names": ["logs-*"],
+                "privileges": ["read", "view_index_metadata"]
+            }),
+        );
+
+        assert_serialize(
+            IndicesPrivileges::new(["customers"], ["read"])
+                .query(Query::term("visibility", "public"))
+                .grant(["*"])
+                .except(["ssn", "salary"])
+                .allow_restricted_indices(false),
+            json!({
+                "names": ["customers"],
+                "privileges": ["read"],
+                "field_security": {
+                    "grant": ["*"],
+                    "except": ["ssn", "salary"]
+                },
+                "query": {
+                    "term": {
+                        "visibility": {
+                            "value": "public"
+                        }
+                    }
+                },
+                "allow_restricted_indices": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! The Elastic Stack security features grant users access to resources through roles. Roles
+//! can restrict which documents and fields of an index are visible using document and field
+//! level security.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/authorization.html>
+
+mod indices_privileges;
+mod role;
+
+pub use self::indices_privileges::*;
+pub use self::role::*;
@@ -0,0 +1,138 @@
+use crate::security::*;
+use crate::util::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Role body for the create or update roles API.
+///
+/// To create a role that can monitor the cluster and read its own department's documents:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let role =
+/// Role::new()
+///     .cluster(["monitor"])
+///     .indices(
+///         IndicesPrivileges::new(["employees"], ["read"])
+///             .query(Query::term("department", "engineering")),
+///     );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/security-api-put-role.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Role {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    cluster: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    indices: Vec<IndicesPrivileges>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    run_as: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    metadata: BTreeMap<String, Value>,
+}
+
+impl Role {
+    /// Creates an instance of [`Role`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Description of the role
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Cluster privileges, such as `monitor` or `manage_ilm`
+    pub fn cluster<I>(mut self, privileges: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.cluster.extend(privileges.into_iter().map(Into::into));
+        self
+    }
+
+    /// Pushes an indices permissions entry
+    pub fn indices(mut self, indices: IndicesPrivileges) -> Self {
+        self.indices.push(indices);
+        self
+    }
+
+    /// Users that the owners of this role can impersonate
+    pub fn run_as<I>(mut self, users: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.run_as.extend(users.into_iter().map(Into::into));
+        self
+    }
+
+    /// Optional metadata, keys that begin with `_` are reserved for system use
+    pub fn metadata<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.metadata.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Role::new(), json!({}));
+
+        assert_serialize(
+            Role::new()
+                .description("Engineering readers")
+                .cluster(["monitor"])
+                .indices(
+                    IndicesPrivileges::new(["employees"], ["read"])
+                        .query(Query::term("department", "engineering"))
+                        .grant(["name", "department"]),
+                )
+                .indices(IndicesPrivileges::new(["public-*"], ["read"]))
+                .run_as(["other_user"])
+                .metadata("version", 1),
+            json!({
+                "description": "Engineering readers",
+                "cluster": ["monitor"],
+                "indices": [
+                    {
+                        "names": ["employees"],
+                        "privileges": ["read"],
+                        "field_security": {
+                            "grant": ["name", "department"]
+                        },
+                        "query": {
+                            "term": {
+                                "department": {
+                                    "value": "engineering"
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "names": ["public-*"],
+                        "privileges": ["read"]
+                    }
+                ],
+                "run_as": ["other_user"],
+                "metadata": {
+                    "version": 1
+                }
+            }),
+        );
+    }
+}
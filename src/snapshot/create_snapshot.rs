@@ -0,0 +1,128 @@
+use crate::util::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Create snapshot request body, takes a snapshot of a cluster or specified data streams and
+/// indices.
+///
+/// To snapshot a couple of indices without the cluster state:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// CreateSnapshot::new()
+///     .indices(["index_1", "index_2"])
+///     .ignore_unavailable(true)
+///     .include_global_state(false);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/create-snapshot-api.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct CreateSnapshot {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    indices: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_unavailable: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_global_state: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    partial: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    feature_states: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    metadata: BTreeMap<String, Value>,
+}
+
+impl CreateSnapshot {
+    /// Creates an instance of [`CreateSnapshot`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data streams and indices to include in the snapshot. Supports multi-target syntax.
+    /// Defaults to all data streams and indices.
+    pub fn indices<I>(mut self, indices: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.indices.extend(indices.into_iter().map(Into::into));
+        self
+    }
+
+    /// If `false`, the snapshot fails if any data stream or index in `indices` is missing or
+    /// closed. If `true`, the snapshot ignores missing or closed data streams and indices.
+    /// Defaults to `false`.
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.ignore_unavailable = Some(ignore_unavailable);
+        self
+    }
+
+    /// If `true` (default), include the cluster state in the snapshot
+    pub fn include_global_state(mut self, include_global_state: bool) -> Self {
+        self.include_global_state = Some(include_global_state);
+        self
+    }
+
+    /// If `false` (default), the entire snapshot will fail if one or more indices included in
+    /// the snapshot do not have all primary shards available
+    pub fn partial(mut self, partial: bool) -> Self {
+        self.partial = Some(partial);
+        self
+    }
+
+    /// Feature states to include in the snapshot
+    pub fn feature_states<I>(mut self, feature_states: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.feature_states
+            .extend(feature_states.into_iter().map(Into::into));
+        self
+    }
+
+    /// Attaches arbitrary metadata to the snapshot, such as a record of who took the snapshot
+    /// or why it was taken
+    pub fn metadata<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.metadata.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(CreateSnapshot::new(), json!({}));
+
+        assert_serialize(
+            CreateSnapshot::new()
+                .indices(["index_1", "index_2"])
+                .ignore_unavailable(true)
+                .include_global_state(false)
+                .partial(true)
+                .feature_states(["kibana"])
+                .metadata("taken_by", "user123"),
+            json!({
+                "indices": ["index_1", "index_2"],
+                "ignore_unavailable": true,
+                "include_global_state": false,
+                "partial": true,
+                "feature_states": ["kibana"],
+                "metadata": {
+                    "taken_by": "user123"
+                }
+            }),
+        );
+    }
+}
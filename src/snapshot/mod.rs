@@ -0,0 +1,13 @@
+//! A snapshot is a backup of a running Elasticsearch cluster. You can use snapshots to regularly
+//! back up a cluster with no downtime, recover data after deletion or a hardware failure, and
+//! transfer data between clusters.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/snapshot-restore.html>
+
+mod create_snapshot;
+mod restore_snapshot;
+mod snapshot_repository;
+
+pub use self::create_snapshot::*;
+pub use self::restore_snapshot::*;
+pub use self::snapshot_repository::*;
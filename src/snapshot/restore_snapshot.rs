@@ -0,0 +1,173 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Restore snapshot request body, restores a snapshot of a cluster or specified data streams
+/// and indices.
+///
+/// To restore an index under a new name:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// RestoreSnapshot::new()
+///     .indices(["index_1"])
+///     .rename_pattern("index_(.+)")
+///     .rename_replacement("restored_index_$1");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/restore-snapshot-api.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct RestoreSnapshot {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    indices: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_unavailable: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_global_state: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_aliases: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    partial: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rename_pattern: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rename_replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_settings: Option<IndexSettings>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_index_settings: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    feature_states: Vec<String>,
+}
+
+impl RestoreSnapshot {
+    /// Creates an instance of [`RestoreSnapshot`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data streams and indices to restore. Supports multi-target syntax. Defaults to all
+    /// regular data streams and indices in the snapshot.
+    pub fn indices<I>(mut self, indices: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.indices.extend(indices.into_iter().map(Into::into));
+        self
+    }
+
+    /// If `true`, the request ignores any index or data stream in `indices` that's missing from
+    /// the snapshot. Defaults to `false`.
+    pub fn ignore_unavailable(mut self, ignore_unavailable: bool) -> Self {
+        self.ignore_unavailable = Some(ignore_unavailable);
+        self
+    }
+
+    /// If `true`, restore the cluster state. Defaults to `false`.
+    pub fn include_global_state(mut self, include_global_state: bool) -> Self {
+        self.include_global_state = Some(include_global_state);
+        self
+    }
+
+    /// If `true` (default), the request restores aliases for any restored data streams and
+    /// indices
+    pub fn include_aliases(mut self, include_aliases: bool) -> Self {
+        self.include_aliases = Some(include_aliases);
+        self
+    }
+
+    /// If `true`, allows restoring a partial snapshot of indices with unavailable shards.
+    /// Defaults to `false`.
+    pub fn partial(mut self, partial: bool) -> Self {
+        self.partial = Some(partial);
+        self
+    }
+
+    /// Defines a rename pattern to apply to restored data streams and indices. Data streams and
+    /// indices matching the rename pattern will be renamed according to `rename_replacement`.
+    pub fn rename_pattern(mut self, rename_pattern: impl Into<String>) -> Self {
+        self.rename_pattern = Some(rename_pattern.into());
+        self
+    }
+
+    /// Defines the rename replacement string, capture groups of `rename_pattern` can be
+    /// referenced using `$1` syntax
+    pub fn rename_replacement(mut self, rename_replacement: impl Into<String>) -> Self {
+        self.rename_replacement = Some(rename_replacement.into());
+        self
+    }
+
+    /// Index settings to add or change in restored indices, including backing indices
+    pub fn index_settings(mut self, index_settings: IndexSettings) -> Self {
+        self.index_settings = Some(index_settings);
+        self
+    }
+
+    /// Index settings to not restore from the snapshot
+    pub fn ignore_index_settings<I>(mut self, ignore_index_settings: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.ignore_index_settings
+            .extend(ignore_index_settings.into_iter().map(Into::into));
+        self
+    }
+
+    /// Feature states to restore
+    pub fn feature_states<I>(mut self, feature_states: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.feature_states
+            .extend(feature_states.into_iter().map(Into::into));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(RestoreSnapshot::new(), json!({}));
+
+        assert_serialize(
+            RestoreSnapshot::new()
+                .indices(["index_1", "index_2"])
+                .ignore_unavailable(true)
+                .include_global_state(false)
+                .include_aliases(false)
+                .partial(true)
+                .rename_pattern("index_(.+)")
+                .rename_replacement("restored_index_$1")
+                .index_settings(IndexSettings::new().number_of_replicas(0))
+                .ignore_index_settings(["index.refresh_interval"])
+                .feature_states(["geoip"]),
+            json!({
+                "indices": ["index_1", "index_2"],
+                "ignore_unavailable": true,
+                "include_global_state": false,
+                "include_aliases": false,
+                "partial": true,
+                "rename_pattern": "index_(.+)",
+                "rename_replacement": "restored_index_$1",
+                "index_settings": {
+                    "number_of_replicas": 0
+                },
+                "ignore_index_settings": ["index.refresh_interval"],
+                "feature_states": ["geoip"]
+            }),
+        );
+    }
+}
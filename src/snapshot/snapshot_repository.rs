@@ -0,0 +1,171 @@
+use crate::search::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Snapshot repository body for the create or update snapshot repository API.
+///
+/// To register a shared file system repository:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let repository =
+/// SnapshotRepository::fs("/mount/backups/my_fs_backup_location").compress(true);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-snapshot-repo-api.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotRepository {
+    r#type: String,
+
+    settings: BTreeMap<String, Value>,
+}
+
+impl SnapshotRepository {
+    /// Creates an instance of [`SnapshotRepository`] of any repository type, settings specific
+    /// to the repository type can be provided using [`SnapshotRepository::setting`]
+    ///
+    /// - `type` - Repository type, such as `fs`, `s3`, `gcs`, `azure` or `url`
+    pub fn new(r#type: impl Into<String>) -> Self {
+        Self {
+            r#type: r#type.into(),
+            settings: Default::default(),
+        }
+    }
+
+    /// Creates an instance of [`SnapshotRepository`] for a shared file system repository
+    ///
+    /// - `location` - Location of the shared filesystem used to store and retrieve snapshots.
+    ///   This location must be registered in the `path.repo` setting on all master and data
+    ///   nodes in the cluster.
+    pub fn fs(location: impl Into<String>) -> Self {
+        Self::new("fs").setting("location", location.into())
+    }
+
+    /// Creates an instance of [`SnapshotRepository`] for an AWS S3 repository
+    ///
+    /// - `bucket` - Name of the S3 bucket to use for snapshots
+    pub fn s3(bucket: impl Into<String>) -> Self {
+        Self::new("s3").setting("bucket", bucket.into())
+    }
+
+    /// Creates an instance of [`SnapshotRepository`] for a Google Cloud Storage repository
+    ///
+    /// - `bucket` - The name of the bucket to be used for snapshots
+    pub fn gcs(bucket: impl Into<String>) -> Self {
+        Self::new("gcs").setting("bucket", bucket.into())
+    }
+
+    /// Creates an instance of [`SnapshotRepository`] for an Azure repository
+    ///
+    /// - `container` - Container name. You must create the Azure container before creating the
+    ///   repository.
+    pub fn azure(container: impl Into<String>) -> Self {
+        Self::new("azure").setting("container", container.into())
+    }
+
+    /// Creates an instance of [`SnapshotRepository`] for a read-only URL repository
+    ///
+    /// - `url` - URL location of the root of the shared filesystem repository
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::new("url").setting("url", url.into())
+    }
+
+    /// Sets a repository type specific setting
+    pub fn setting<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.settings.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// If `true`, metadata files, such as index mappings and settings, are compressed in
+    /// snapshots. Data files are not compressed. Defaults to `true`.
+    pub fn compress(self, compress: bool) -> Self {
+        self.setting("compress", compress)
+    }
+
+    /// Maximum size of files in snapshots. In snapshots, files larger than this are broken down
+    /// into chunks of this size or smaller.
+    pub fn chunk_size(self, chunk_size: Byte) -> Self {
+        self.byte_setting("chunk_size", chunk_size)
+    }
+
+    /// Maximum snapshot creation rate per node. Defaults to `40mb` per second.
+    pub fn max_snapshot_bytes_per_sec(self, max_snapshot_bytes_per_sec: Byte) -> Self {
+        self.byte_setting("max_snapshot_bytes_per_sec", max_snapshot_bytes_per_sec)
+    }
+
+    /// Maximum snapshot restore rate per node. Defaults to unlimited.
+    pub fn max_restore_bytes_per_sec(self, max_restore_bytes_per_sec: Byte) -> Self {
+        self.byte_setting("max_restore_bytes_per_sec", max_restore_bytes_per_sec)
+    }
+
+    /// If `true`, the repository is read-only. The cluster can retrieve and restore snapshots
+    /// from the repository but not write to the repository or create snapshots in it.
+    pub fn readonly(self, readonly: bool) -> Self {
+        self.setting("readonly", readonly)
+    }
+
+    fn byte_setting(self, key: &str, value: Byte) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => self.setting(key, value),
+            Err(_) => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            SnapshotRepository::fs("/mount/backups"),
+            json!({
+                "type": "fs",
+                "settings": {
+                    "location": "/mount/backups"
+                }
+            }),
+        );
+
+        assert_serialize(
+            SnapshotRepository::s3("my-bucket")
+                .setting("base_path", "snapshots")
+                .compress(true)
+                .chunk_size(Byte::Gigabytes(1))
+                .max_snapshot_bytes_per_sec(Byte::Megabytes(80))
+                .max_restore_bytes_per_sec(Byte::Megabytes(100))
+                .readonly(false),
+            json!({
+                "type": "s3",
+                "settings": {
+                    "bucket": "my-bucket",
+                    "base_path": "snapshots",
+                    "compress": true,
+                    "chunk_size": "1gb",
+                    "max_snapshot_bytes_per_sec": "80mb",
+                    "max_restore_bytes_per_sec": "100mb",
+                    "readonly": false
+                }
+            }),
+        );
+
+        assert_serialize(
+            SnapshotRepository::gcs("my-bucket"),
+            json!({ "type": "gcs", "settings": { "bucket": "my-bucket" } }),
+        );
+
+        assert_serialize(
+            SnapshotRepository::azure("backups"),
+            json!({ "type": "azure", "settings": { "container": "backups" } }),
+        );
+
+        assert_serialize(
+            SnapshotRepository::url("http://example.com/snapshots"),
+            json!({ "type": "url", "settings": { "url": "http://example.com/snapshots" } }),
+        );
+    }
+}
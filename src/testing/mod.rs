@@ -0,0 +1,137 @@
+//! Assertions for testing code that builds Elasticsearch requests with this crate, available
+//! with the `testing` feature.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! elasticsearch-dsl = { version = "*", features = ["testing"] }
+//! ```
+//!
+//! Compare against an inline expectation:
+//! ```
+//! # use elasticsearch_dsl::*;
+//! # use elasticsearch_dsl::testing::*;
+//! # use serde_json::json;
+//! assert_serialize(
+//!     Search::new().size(10),
+//!     json!({ "size": 10 }),
+//! );
+//! ```
+//!
+//! Or against a golden file, which is written when missing or when the `UPDATE_SNAPSHOTS`
+//! environment variable is set:
+//! ```no_run
+//! # use elasticsearch_dsl::*;
+//! # use elasticsearch_dsl::testing::*;
+//! assert_serialize_snapshot(
+//!     Search::new().query(Query::term("user", "kimchy")),
+//!     "tests/snapshots/search.json",
+//! );
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// Environment variable which forces snapshots to be rewritten
+pub const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+/// Asserts that `subject` serializes to the JSON `expectation`
+#[track_caller]
+pub fn assert_serialize<S>(subject: S, expectation: Value)
+where
+    S: Serialize,
+{
+    let result = to_value(&subject);
+
+    pretty_assertions::assert_eq!(
+        result,
+        expectation,
+        "serialized value does not match expectation"
+    );
+}
+
+/// Asserts that `subject` serializes to the JSON stored in the golden file at `path`.
+///
+/// The file is created with the serialized value when it does not exist yet, or overwritten
+/// when the [`UPDATE_SNAPSHOTS`] environment variable is set.
+#[track_caller]
+pub fn assert_serialize_snapshot<S, P>(subject: S, path: P)
+where
+    S: Serialize,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let result = to_value(&subject);
+
+    if !path.exists() || std::env::var_os(UPDATE_SNAPSHOTS).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create snapshot directory");
+        }
+
+        std::fs::write(path, pretty(&result) + "\n").expect("failed to write snapshot");
+
+        return;
+    }
+
+    let snapshot = std::fs::read_to_string(path).expect("failed to read snapshot");
+    let expectation: Value = serde_json::from_str(&snapshot).expect("snapshot is not valid JSON");
+
+    pretty_assertions::assert_eq!(
+        result,
+        expectation,
+        "serialized value does not match snapshot {}, rerun with {}=1 to update the snapshot",
+        path.display(),
+        UPDATE_SNAPSHOTS,
+    );
+}
+
+/// Serializes the subject into a string first, so that the value is compared as it is sent
+fn to_value<S>(subject: &S) -> Value
+where
+    S: Serialize,
+{
+    let string = serde_json::to_string(subject).expect("failed to serialize subject");
+
+    serde_json::from_str(&string).expect("failed to deserialize subject")
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Search;
+
+    #[test]
+    fn asserts_serialization() {
+        assert_serialize(Search::new().size(10), json!({ "size": 10 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match expectation")]
+    fn panics_on_mismatch() {
+        assert_serialize(Search::new().size(10), json!({ "size": 20 }));
+    }
+
+    #[test]
+    fn asserts_snapshot() {
+        let path = std::env::temp_dir()
+            .join(format!("elasticsearch-dsl-{}", std::process::id()))
+            .join("snapshot.json");
+
+        let _ = std::fs::remove_file(&path);
+
+        assert_serialize_snapshot(Search::new().size(10), &path);
+        assert_serialize_snapshot(Search::new().size(10), &path);
+
+        let mismatch = std::panic::catch_unwind(|| {
+            assert_serialize_snapshot(Search::new().size(20), &path);
+        });
+
+        let _ = std::fs::remove_file(&path);
+
+        assert!(mismatch.is_err() || std::env::var_os(UPDATE_SNAPSHOTS).is_some());
+    }
+}
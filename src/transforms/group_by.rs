@@ -0,0 +1,140 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Defines how to group the data in a pivot transform. The supported sources are the same as
+/// the composite aggregation sources: `terms`, `histogram` and `date_histogram`.
+///
+/// To group by a field value:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let group_by =
+/// GroupBy::terms("customer_id").missing_bucket(true);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/transform-resource.html#transform-resource-pivot-group-by>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GroupBy(KeyValuePair<&'static str, GroupBySource>);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct GroupBySource {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    interval: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    calendar_interval: Option<CalendarInterval>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fixed_interval: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+}
+
+impl GroupBy {
+    fn new(source: &'static str, field: impl Into<String>) -> Self {
+        Self(KeyValuePair::new(
+            source,
+            GroupBySource {
+                field: field.into(),
+                interval: None,
+                calendar_interval: None,
+                fixed_interval: None,
+                time_zone: None,
+                missing_bucket: None,
+            },
+        ))
+    }
+
+    /// Groups by the values of a field
+    pub fn terms(field: impl Into<String>) -> Self {
+        Self::new("terms", field)
+    }
+
+    /// Groups numeric values into buckets of the given interval
+    pub fn histogram(field: impl Into<String>, interval: impl Into<Number>) -> Self {
+        let mut group_by = Self::new("histogram", field);
+        group_by.0.value.interval = Some(interval.into());
+        group_by
+    }
+
+    /// Groups dates into calendar-aware buckets, such as months
+    pub fn date_histogram(field: impl Into<String>, calendar_interval: CalendarInterval) -> Self {
+        let mut group_by = Self::new("date_histogram", field);
+        group_by.0.value.calendar_interval = Some(calendar_interval);
+        group_by
+    }
+
+    /// Groups dates into fixed size buckets, such as 90 minutes
+    pub fn fixed_date_histogram(field: impl Into<String>, fixed_interval: Time) -> Self {
+        let mut group_by = Self::new("date_histogram", field);
+        group_by.0.value.fixed_interval = Some(fixed_interval);
+        group_by
+    }
+
+    /// Time zone used for date histogram buckets, either an ISO 8601 UTC offset or an IANA time
+    /// zone ID
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.0.value.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// If `true`, documents without a value for the field are grouped into a `null` bucket.
+    /// Defaults to `false`.
+    pub fn missing_bucket(mut self, missing_bucket: bool) -> Self {
+        self.0.value.missing_bucket = Some(missing_bucket);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            GroupBy::terms("customer_id").missing_bucket(true),
+            json!({
+                "terms": {
+                    "field": "customer_id",
+                    "missing_bucket": true
+                }
+            }),
+        );
+
+        assert_serialize(
+            GroupBy::histogram("price", 50),
+            json!({
+                "histogram": {
+                    "field": "price",
+                    "interval": 50
+                }
+            }),
+        );
+
+        assert_serialize(
+            GroupBy::date_histogram("order_date", CalendarInterval::Day).time_zone("+01:00"),
+            json!({
+                "date_histogram": {
+                    "field": "order_date",
+                    "calendar_interval": "day",
+                    "time_zone": "+01:00"
+                }
+            }),
+        );
+
+        assert_serialize(
+            GroupBy::fixed_date_histogram("order_date", Time::Minutes(90)),
+            json!({
+                "date_histogram": {
+                    "field": "order_date",
+                    "fixed_interval": "90m"
+                }
+            }),
+        );
+    }
+}
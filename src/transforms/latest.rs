@@ -0,0 +1,49 @@
+/// The latest method transforms the data by finding the latest document for each unique key.
+///
+/// To keep the latest order of every customer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let latest =
+/// Latest::new(["customer_id"], "order_date");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/transform-resource.html#transform-resource-latest>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Latest {
+    unique_key: Vec<String>,
+
+    sort: String,
+}
+
+impl Latest {
+    /// Creates an instance of [`Latest`]
+    ///
+    /// - `unique_key` - Fields that are used to group the data
+    /// - `sort` - The date field that is used to identify the latest documents
+    pub fn new<I>(unique_key: I, sort: impl Into<String>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            unique_key: unique_key.into_iter().map(Into::into).collect(),
+            sort: sort.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Latest::new(["customer_id", "currency"], "order_date"),
+            json!({
+                "unique_key": ["customer_id", "currency"],
+                "sort": "order_date"
+            }),
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Transforms enable you to convert existing Elasticsearch indices into summarized indices,
+//! which provide opportunities for new insights and analytics.
+//!
+//! A transform either pivots the source data into entity-centric buckets, or keeps the latest
+//! document for each unique key.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/transforms.html>
+
+mod group_by;
+mod latest;
+mod pivot;
+mod retention_policy;
+mod transform;
+mod transform_destination;
+mod transform_source;
+mod transform_sync;
+
+pub use self::group_by::*;
+pub use self::latest::*;
+pub use self::pivot::*;
+pub use self::retention_policy::*;
+pub use self::transform::*;
+pub use self::transform_destination::*;
+pub use self::transform_source::*;
+pub use self::transform_sync::*;
@@ -0,0 +1,74 @@
+use crate::search::*;
+use crate::transforms::*;
+use std::collections::BTreeMap;
+
+/// The pivot method transforms the data by aggregating and grouping it.
+///
+/// To compute the total spent per customer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let pivot =
+/// Pivot::new()
+///     .group_by("customer_id", GroupBy::terms("customer_id"))
+///     .aggregate("total_spent", Aggregation::sum("taxful_total_price"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/transform-resource.html#transform-resource-pivot>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Pivot {
+    group_by: BTreeMap<String, GroupBy>,
+
+    #[serde(rename = "aggregations")]
+    aggs: Aggregations,
+}
+
+impl Pivot {
+    /// Creates an instance of [`Pivot`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named group, which becomes a field in the destination index
+    pub fn group_by(mut self, name: impl Into<String>, group_by: GroupBy) -> Self {
+        let _ = self.group_by.insert(name.into(), group_by);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Pivot::new()
+                .group_by("customer_id", GroupBy::terms("customer_id"))
+                .aggregate("total_spent", Aggregation::sum("taxful_total_price"))
+                .aggregate("last_order", Aggregation::max("order_date")),
+            json!({
+                "group_by": {
+                    "customer_id": {
+                        "terms": {
+                            "field": "customer_id"
+                        }
+                    }
+                },
+                "aggregations": {
+                    "last_order": {
+                        "max": {
+                            "field": "order_date"
+                        }
+                    },
+                    "total_spent": {
+                        "sum": {
+                            "field": "taxful_total_price"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
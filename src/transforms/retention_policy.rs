@@ -0,0 +1,46 @@
+use crate::search::*;
+
+/// Defines a retention policy for the transform. Data that meets the defined criteria is
+/// deleted from the destination index.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-transform.html#put-transform-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RetentionPolicy {
+    time: TimeRetention,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TimeRetention {
+    field: String,
+
+    max_age: Time,
+}
+
+impl RetentionPolicy {
+    /// Specifies that the transform uses a time field to set the retention policy
+    ///
+    /// - `field` - The date field that is used to calculate the age of the document
+    /// - `max_age` - Documents that are older than this value are deleted from the destination
+    pub fn time(field: impl Into<String>, max_age: Time) -> Self {
+        Self {
+            time: TimeRetention {
+                field: field.into(),
+                max_age,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            RetentionPolicy::time("order_date", Time::Days(30)),
+            json!({ "time": { "field": "order_date", "max_age": "30d" } }),
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::search::*;
+use crate::transforms::*;
+use crate::util::*;
+
+/// Transform configuration body for the create transform API.
+///
+/// To create a continuous transform summarizing orders per customer:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let transform =
+/// Transform::pivot(
+///     TransformSource::new(["orders"]).query(Query::term("status", "completed")),
+///     "orders-by-customer",
+///     Pivot::new()
+///         .group_by("customer_id", GroupBy::terms("customer_id"))
+///         .aggregate("total_spent", Aggregation::sum("total_price")),
+/// )
+/// .frequency(Time::Minutes(5))
+/// .sync(TransformSync::time("order_date"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-transform.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Transform {
+    source: TransformSource,
+
+    dest: TransformDestination,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pivot: Option<Pivot>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    latest: Option<Latest>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    frequency: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sync: Option<TransformSync>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    retention_policy: Option<RetentionPolicy>,
+}
+
+impl Transform {
+    fn new(source: impl Into<TransformSource>, dest: impl Into<TransformDestination>) -> Self {
+        Self {
+            source: source.into(),
+            dest: dest.into(),
+            pivot: None,
+            latest: None,
+            description: None,
+            frequency: None,
+            sync: None,
+            retention_policy: None,
+        }
+    }
+
+    /// Creates an instance of [`Transform`] using the pivot method
+    ///
+    /// - `source` - The source of the data for the transform
+    /// - `dest` - The destination for the transform
+    /// - `pivot` - Defines how to group and aggregate the source data
+    pub fn pivot(
+        source: impl Into<TransformSource>,
+        dest: impl Into<TransformDestination>,
+        pivot: Pivot,
+    ) -> Self {
+        let mut transform = Self::new(source, dest);
+        transform.pivot = Some(pivot);
+        transform
+    }
+
+    /// Creates an instance of [`Transform`] using the latest method
+    ///
+    /// - `source` - The source of the data for the transform
+    /// - `dest` - The destination for the transform
+    /// - `latest` - Defines which documents are kept for each unique key
+    pub fn latest(
+        source: impl Into<TransformSource>,
+        dest: impl Into<TransformDestination>,
+        latest: Latest,
+    ) -> Self {
+        let mut transform = Self::new(source, dest);
+        transform.latest = Some(latest);
+        transform
+    }
+
+    /// Free text description of the transform
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The interval between checks for changes in the source indices when the transform is
+    /// running continuously. Defaults to `1m`.
+    pub fn frequency(mut self, frequency: Time) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Defines the properties transforms require to run continuously
+    pub fn sync(mut self, sync: TransformSync) -> Self {
+        self.sync = Some(sync);
+        self
+    }
+
+    /// Defines a retention policy for the transform
+    pub fn retention_policy(mut self, retention_policy: RetentionPolicy) -> Self {
+        self.retention_policy = Some(retention_policy);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Transform::pivot(
+                TransformSource::new(["orders"]).query(Query::term("status", "completed")),
+                TransformDestination::new("orders-by-customer").pipeline("add_timestamp"),
+                Pivot::new()
+                    .group_by("customer_id", GroupBy::terms("customer_id"))
+                    .aggregate("total_spent", Aggregation::sum("total_price")),
+            )
+            .description("Orders by customer")
+            .frequency(Time::Minutes(5))
+            .sync(TransformSync::time("order_date").delay(Time::Seconds(60)))
+            .retention_policy(RetentionPolicy::time("order_date", Time::Days(30))),
+            json!({
+                "source": {
+                    "index": ["orders"],
+                    "query": {
+                        "term": {
+                            "status": {
+                                "value": "completed"
+                            }
+                        }
+                    }
+                },
+                "dest": {
+                    "index": "orders-by-customer",
+                    "pipeline": "add_timestamp"
+                },
+                "pivot": {
+                    "group_by": {
+                        "customer_id": {
+                            "terms": {
+                                "field": "customer_id"
+                            }
+                        }
+                    },
+                    "aggregations": {
+                        "total_spent": {
+                            "sum": {
+                                "field": "total_price"
+                            }
+                        }
+                    }
+                },
+                "description": "Orders by customer",
+                "frequency": "5m",
+                "sync": {
+                    "time": {
+                        "field": "order_date",
+                        "delay": "60s"
+                    }
+                },
+                "retention_policy": {
+                    "time": {
+                        "field": "order_date",
+                        "max_age": "30d"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Transform::latest(
+                "orders",
+                "latest-orders",
+                Latest::new(["customer_id"], "order_date"),
+            ),
+            json!({
+                "source": {
+                    "index": ["orders"]
+                },
+                "dest": {
+                    "index": "latest-orders"
+                },
+                "latest": {
+                    "unique_key": ["customer_id"],
+                    "sort": "order_date"
+                }
+            }),
+        );
+    }
+}
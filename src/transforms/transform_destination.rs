@@ -0,0 +1,60 @@
+use crate::util::*;
+
+/// The destination for the transform.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-transform.html#put-transform-request-body>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TransformDestination {
+    index: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pipeline: Option<String>,
+}
+
+impl TransformDestination {
+    /// Creates an instance of [`TransformDestination`]
+    ///
+    /// - `index` - The destination index for the transform
+    pub fn new(index: impl Into<String>) -> Self {
+        Self {
+            index: index.into(),
+            pipeline: None,
+        }
+    }
+
+    /// The unique identifier for an ingest pipeline
+    pub fn pipeline(mut self, pipeline: impl Into<String>) -> Self {
+        self.pipeline = Some(pipeline.into());
+        self
+    }
+}
+
+impl<T> From<T> for TransformDestination
+where
+    T: Into<String>,
+{
+    fn from(index: T) -> Self {
+        Self::new(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TransformDestination::new("orders-summary"),
+            json!({ "index": "orders-summary" }),
+        );
+
+        assert_serialize(
+            TransformDestination::new("orders-summary").pipeline("add_timestamp"),
+            json!({
+                "index": "orders-summary",
+                "pipeline": "add_timestamp"
+            }),
+        );
+    }
+}
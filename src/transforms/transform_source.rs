@@ -0,0 +1,90 @@
+use crate::search::*;
+use crate::util::*;
+use std::collections::BTreeMap;
+
+/// The source of the data for the transform.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-transform.html#put-transform-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TransformSource {
+    index: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    runtime_mappings: BTreeMap<String, RuntimeMapping>,
+}
+
+impl TransformSource {
+    /// Creates an instance of [`TransformSource`]
+    ///
+    /// - `index` - The source indices for the transform, wildcard expressions are supported
+    pub fn new<I>(index: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            index: index.into_iter().map(Into::into).collect(),
+            query: None,
+            runtime_mappings: Default::default(),
+        }
+    }
+
+    /// A query clause that retrieves a subset of data from the source index. Defaults to a
+    /// `match_all` query.
+    pub fn query(mut self, query: impl Into<Query>) -> Self {
+        let query = query.into();
+
+        if !query.should_skip() {
+            self.query = Some(query);
+        }
+
+        self
+    }
+
+    /// Adds a runtime field that can be used by the transform
+    pub fn runtime_mapping(mut self, name: impl ToString, mapping: RuntimeMapping) -> Self {
+        let _ = self.runtime_mappings.insert(name.to_string(), mapping);
+        self
+    }
+}
+
+impl<T> From<T> for TransformSource
+where
+    T: Into<String>,
+{
+    fn from(index: T) -> Self {
+        Self::new([index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TransformSource::from("orders"),
+            json!({ "index": ["orders"] }),
+        );
+
+        assert_serialize(
+            TransformSource::new(["orders-*", "archive"])
+                .query(Query::term("currency", "EUR"))
+                .query(Query::bool()),
+            json!({
+                "index": ["orders-*", "archive"],
+                "query": {
+                    "term": {
+                        "currency": {
+                            "value": "EUR"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
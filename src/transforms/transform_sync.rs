@@ -0,0 +1,58 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Defines the properties transforms require to run continuously.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-transform.html#put-transform-request-body>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TransformSync {
+    time: TimeSync,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TimeSync {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delay: Option<Time>,
+}
+
+impl TransformSync {
+    /// Specifies that the transform uses a time field to synchronize the source and destination
+    /// indices
+    ///
+    /// - `field` - The date field that is used to identify new documents in the source
+    pub fn time(field: impl Into<String>) -> Self {
+        Self {
+            time: TimeSync {
+                field: field.into(),
+                delay: None,
+            },
+        }
+    }
+
+    /// The time delay between the current time and the latest input data time. Defaults to
+    /// `60s`.
+    pub fn delay(mut self, delay: Time) -> Self {
+        self.time.delay = Some(delay);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TransformSync::time("order_date"),
+            json!({ "time": { "field": "order_date" } }),
+        );
+
+        assert_serialize(
+            TransformSync::time("order_date").delay(Time::Seconds(120)),
+            json!({ "time": { "field": "order_date", "delay": "120s" } }),
+        );
+    }
+}
@@ -1,12 +1,15 @@
 //! Module containing helpers and util functions that are not specific to any DSL
 
-mod assert_serialize;
 mod join_with_pipe;
 mod key_value_pair;
+mod non_default;
 mod should_skip;
+mod sort_keys;
 
-#[cfg(test)]
-pub(crate) use self::assert_serialize::*;
 pub(crate) use self::join_with_pipe::*;
 pub(crate) use self::key_value_pair::*;
+pub(crate) use self::non_default::*;
 pub(crate) use self::should_skip::*;
+pub(crate) use self::sort_keys::*;
+#[cfg(test)]
+pub(crate) use crate::testing::assert_serialize;
@@ -0,0 +1,28 @@
+/// Wraps an explicitly set parameter value. With the `compact` feature enabled, values matching
+/// the Elasticsearch default are left out, as the request behaves the same without them.
+pub(crate) fn non_default<T, D>(value: T, default: D) -> Option<T>
+where
+    T: PartialEq<D>,
+{
+    if cfg!(feature = "compact") && value == default {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_out_defaults_when_compact() {
+        assert_eq!(non_default(false, true), Some(false));
+
+        if cfg!(feature = "compact") {
+            assert_eq!(non_default(true, true), None);
+        } else {
+            assert_eq!(non_default(true, true), Some(true));
+        }
+    }
+}
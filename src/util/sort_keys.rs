@@ -0,0 +1,41 @@
+use serde_json::Value;
+
+/// Rebuilds objects with their keys in sorted order, recursively. `serde_json` only keeps
+/// object keys sorted while its `preserve_order` feature is disabled, which any crate in the
+/// dependency graph can enable.
+pub(crate) fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sorts_nested_keys() {
+        let value = sort_keys(json!({
+            "b": [{ "z": 1, "a": 2 }],
+            "a": { "y": true, "x": null }
+        }));
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"a":{"x":null,"y":true},"b":[{"a":2,"z":1}]}"#
+        );
+    }
+}
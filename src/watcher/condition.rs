@@ -0,0 +1,132 @@
+use crate::search::*;
+use crate::util::*;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// A watch condition evaluates the data loaded into the watch payload to determine whether any
+/// action is required.
+///
+/// To run actions only when a search returned any hits:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let condition =
+/// Condition::compare("ctx.payload.hits.total", CompareOperator::Gt, 0);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/condition.html>
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// Always evaluates to `true`
+    Always,
+
+    /// Always evaluates to `false`
+    Never,
+
+    /// Performs a simple comparison against a value in the watch payload
+    Compare {
+        /// Path to the value in the execution context
+        path: String,
+
+        /// Comparison operator
+        operator: CompareOperator,
+
+        /// Value to compare against
+        value: Value,
+    },
+
+    /// Uses a script to determine whether or not to execute the actions
+    Script(Script),
+}
+
+/// Comparison operators supported by the compare condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOperator {
+    /// Equal to
+    Eq,
+
+    /// Not equal to
+    NotEq,
+
+    /// Greater than
+    Gt,
+
+    /// Greater than or equal to
+    Gte,
+
+    /// Less than
+    Lt,
+
+    /// Less than or equal to
+    Lte,
+}
+
+impl Condition {
+    /// Creates a compare condition
+    ///
+    /// - `path` - Path to the value in the execution context, such as `ctx.payload.hits.total`
+    /// - `operator` - Comparison operator
+    /// - `value` - Value to compare against
+    pub fn compare(
+        path: impl Into<String>,
+        operator: CompareOperator,
+        value: impl Into<Value>,
+    ) -> Self {
+        Self::Compare {
+            path: path.into(),
+            operator,
+            value: value.into(),
+        }
+    }
+
+    /// Creates a script condition
+    pub fn script(script: Script) -> Self {
+        Self::Script(script)
+    }
+}
+
+impl Serialize for Condition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+
+        match self {
+            Self::Always => map.serialize_entry("always", &Value::Object(Default::default()))?,
+            Self::Never => map.serialize_entry("never", &Value::Object(Default::default()))?,
+            Self::Compare {
+                path,
+                operator,
+                value,
+            } => {
+                let operator = KeyValuePair::new(operator, value);
+                map.serialize_entry("compare", &KeyValuePair::new(path, operator))?
+            }
+            Self::Script(script) => map.serialize_entry("script", script)?,
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Condition::Always, json!({ "always": {} }));
+
+        assert_serialize(Condition::Never, json!({ "never": {} }));
+
+        assert_serialize(
+            Condition::compare("ctx.payload.hits.total", CompareOperator::Gte, 5),
+            json!({ "compare": { "ctx.payload.hits.total": { "gte": 5 } } }),
+        );
+
+        assert_serialize(
+            Condition::script(Script::source("return ctx.payload.hits.total > 5")),
+            json!({ "script": { "source": "return ctx.payload.hits.total > 5" } }),
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::util::*;
+use crate::watcher::*;
+
+/// Sends an email. The email account must be configured in the Elasticsearch keystore.
+///
+/// To create email action:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// WatchAction::email(["ops@example.com"]).subject("Errors found");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/actions-email.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EmailAction {
+    #[serde(rename = "email")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    cc: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    bcc: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    subject: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    body: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    account: Option<String>,
+}
+
+impl WatchAction {
+    /// Creates an instance of [`EmailAction`]
+    ///
+    /// - `to` - The email addresses of the recipients. Supports mustache templates.
+    pub fn email<I>(to: I) -> EmailAction
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        EmailAction {
+            inner: Inner {
+                to: to.into_iter().map(Into::into).collect(),
+                cc: vec![],
+                bcc: vec![],
+                from: None,
+                subject: None,
+                body: None,
+                account: None,
+            },
+        }
+    }
+}
+
+impl EmailAction {
+    /// The email addresses of the carbon copy recipients
+    pub fn cc<I>(mut self, cc: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.inner.cc.extend(cc.into_iter().map(Into::into));
+        self
+    }
+
+    /// The email addresses of the blind carbon copy recipients
+    pub fn bcc<I>(mut self, bcc: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.inner.bcc.extend(bcc.into_iter().map(Into::into));
+        self
+    }
+
+    /// The email address from which the email will be sent
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.inner.from = Some(from.into());
+        self
+    }
+
+    /// The subject of the email. Supports mustache templates.
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.inner.subject = Some(subject.into());
+        self
+    }
+
+    /// The plain text body of the email. Supports mustache templates.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.inner.body = Some(body.into());
+        self
+    }
+
+    /// The account to use to send the email. Defaults to the default account configured in the
+    /// keystore.
+    pub fn account(mut self, account: impl Into<String>) -> Self {
+        self.inner.account = Some(account.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            WatchAction::email(["ops@example.com"]),
+            json!({ "email": { "to": ["ops@example.com"] } }),
+        );
+
+        assert_serialize(
+            WatchAction::email(["ops@example.com"])
+                .cc(["lead@example.com"])
+                .bcc(["audit@example.com"])
+                .from("watcher@example.com")
+                .subject("Errors found")
+                .body("Found {{ctx.payload.hits.total}} errors")
+                .account("work"),
+            json!({
+                "email": {
+                    "to": ["ops@example.com"],
+                    "cc": ["lead@example.com"],
+                    "bcc": ["audit@example.com"],
+                    "from": "watcher@example.com",
+                    "subject": "Errors found",
+                    "body": "Found {{ctx.payload.hits.total}} errors",
+                    "account": "work"
+                }
+            }),
+        );
+    }
+}
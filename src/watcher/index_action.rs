@@ -0,0 +1,83 @@
+use crate::util::*;
+use crate::watcher::*;
+
+/// Indexes the watch payload into Elasticsearch.
+///
+/// To create index action:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// WatchAction::index("alerts");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/actions-index.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndexAction {
+    #[serde(rename = "index")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    index: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_time_field: Option<String>,
+}
+
+impl WatchAction {
+    /// Creates an instance of [`IndexAction`]
+    ///
+    /// - `index` - The index, alias or data stream to index into
+    pub fn index(index: impl Into<String>) -> IndexAction {
+        IndexAction {
+            inner: Inner {
+                index: index.into(),
+                doc_id: None,
+                execution_time_field: None,
+            },
+        }
+    }
+}
+
+impl IndexAction {
+    /// The id of the document to index
+    pub fn doc_id(mut self, doc_id: impl Into<String>) -> Self {
+        self.inner.doc_id = Some(doc_id.into());
+        self
+    }
+
+    /// The field that will store the watch execution time
+    pub fn execution_time_field(mut self, execution_time_field: impl Into<String>) -> Self {
+        self.inner.execution_time_field = Some(execution_time_field.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            WatchAction::index("alerts"),
+            json!({ "index": { "index": "alerts" } }),
+        );
+
+        assert_serialize(
+            WatchAction::index("alerts")
+                .doc_id("{{ctx.watch_id}}")
+                .execution_time_field("@timestamp"),
+            json!({
+                "index": {
+                    "index": "alerts",
+                    "doc_id": "{{ctx.watch_id}}",
+                    "execution_time_field": "@timestamp"
+                }
+            }),
+        );
+    }
+}
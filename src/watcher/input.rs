@@ -0,0 +1,132 @@
+use crate::search::*;
+use crate::util::*;
+use serde_json::Value;
+
+/// The input loads data into the watch payload, which is made available to the condition and
+/// actions.
+///
+/// To load the results of a search into the payload:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let input =
+/// Input::search(["logs"], Search::new().query(Query::term("level", "error")));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/input.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Input {
+    /// Loads static data into the execution context
+    Simple(Value),
+
+    /// Loads the results of a search into the execution context
+    Search(SearchInput),
+}
+
+/// Search input configuration
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchInput {
+    request: SearchInputRequest,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    extract: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    timeout: Option<Time>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SearchInputRequest {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    indices: Vec<String>,
+
+    body: Box<Search>,
+}
+
+impl Input {
+    /// Creates a simple input, loading static data into the payload
+    pub fn simple(payload: impl Into<Value>) -> Self {
+        Self::Simple(payload.into())
+    }
+
+    /// Creates a search input, loading the results of the search into the payload
+    ///
+    /// - `indices` - Indices to search
+    /// - `body` - Search request body
+    pub fn search<I>(indices: I, body: Search) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self::Search(SearchInput {
+            request: SearchInputRequest {
+                indices: indices.into_iter().map(Into::into).collect(),
+                body: Box::new(body),
+            },
+            extract: vec![],
+            timeout: None,
+        })
+    }
+
+    /// Array of keys to extract from the search response and load as the payload. Only
+    /// applies to search inputs.
+    pub fn extract<I>(mut self, extract: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        if let Self::Search(ref mut input) = self {
+            input.extract.extend(extract.into_iter().map(Into::into));
+        }
+        self
+    }
+
+    /// The timeout for waiting for the search api call to return. Only applies to search
+    /// inputs.
+    pub fn timeout(mut self, timeout: Time) -> Self {
+        if let Self::Search(ref mut input) = self {
+            input.timeout = Some(timeout);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Input::simple(json!({ "name": "John" })),
+            json!({ "simple": { "name": "John" } }),
+        );
+
+        assert_serialize(
+            Input::search(
+                ["logs-*"],
+                Search::new().size(0).query(Query::term("level", "error")),
+            )
+            .extract(["hits.total"])
+            .timeout(Time::Seconds(30)),
+            json!({
+                "search": {
+                    "request": {
+                        "indices": ["logs-*"],
+                        "body": {
+                            "size": 0,
+                            "query": {
+                                "term": {
+                                    "level": {
+                                        "value": "error"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "extract": ["hits.total"],
+                    "timeout": "30s"
+                }
+            }),
+        );
+    }
+}
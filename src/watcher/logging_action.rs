@@ -0,0 +1,96 @@
+use crate::util::*;
+use crate::watcher::*;
+
+/// Logs text to the standard Elasticsearch logs.
+///
+/// To create logging action:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// WatchAction::logging("Found {{ctx.payload.hits.total}} errors").level(LoggingLevel::Warn);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/actions-logging.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoggingAction {
+    #[serde(rename = "logging")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    text: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    level: Option<LoggingLevel>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    category: Option<String>,
+}
+
+/// Logging level of a [`LoggingAction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum LoggingLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl WatchAction {
+    /// Creates an instance of [`LoggingAction`]
+    ///
+    /// - `text` - The text that should be logged. Supports mustache templates.
+    pub fn logging(text: impl Into<String>) -> LoggingAction {
+        LoggingAction {
+            inner: Inner {
+                text: text.into(),
+                level: None,
+                category: None,
+            },
+        }
+    }
+}
+
+impl LoggingAction {
+    /// The logging level. Defaults to `info`.
+    pub fn level(mut self, level: LoggingLevel) -> Self {
+        self.inner.level = Some(level);
+        self
+    }
+
+    /// The category under which the text will be logged. Defaults to
+    /// `xpack.watcher.actions.logging`.
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.inner.category = Some(category.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            WatchAction::logging("Found errors"),
+            json!({ "logging": { "text": "Found errors" } }),
+        );
+
+        assert_serialize(
+            WatchAction::logging("Found errors")
+                .level(LoggingLevel::Warn)
+                .category("my_watch"),
+            json!({
+                "logging": {
+                    "text": "Found errors",
+                    "level": "warn",
+                    "category": "my_watch"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Watcher lets you watch for changes or anomalies in your data and perform the necessary
+//! actions in response.
+//!
+//! A watch is constructed from four simple building blocks: a schedule to trigger on, an input
+//! that loads data, a condition that decides whether to act and the actions to take.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/how-watcher-works.html>
+
+mod condition;
+mod email_action;
+mod index_action;
+mod input;
+mod logging_action;
+mod trigger;
+mod watch;
+mod watch_action;
+mod webhook_action;
+
+pub use self::condition::*;
+pub use self::email_action::*;
+pub use self::index_action::*;
+pub use self::input::*;
+pub use self::logging_action::*;
+pub use self::trigger::*;
+pub use self::watch::*;
+pub use self::watch_action::*;
+pub use self::webhook_action::*;
@@ -0,0 +1,100 @@
+use crate::search::*;
+
+/// The trigger determines when the watch execution process should start.
+///
+/// To trigger a watch every 10 minutes:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let trigger =
+/// Trigger::interval(Time::Minutes(10));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/trigger-schedule.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Trigger {
+    schedule: Schedule,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Schedule {
+    Interval(Time),
+    Cron(Vec<String>),
+    Hourly { minute: Vec<u8> },
+    Daily { at: Vec<String> },
+}
+
+impl Trigger {
+    /// Triggers the watch at a fixed time interval
+    pub fn interval(interval: Time) -> Self {
+        Self {
+            schedule: Schedule::Interval(interval),
+        }
+    }
+
+    /// Triggers the watch according to one or more cron-style expressions
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/trigger-schedule.html#schedule-cron>
+    pub fn cron<I>(expressions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            schedule: Schedule::Cron(expressions.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Triggers the watch every hour at the given minutes past the hour
+    pub fn hourly<I>(minutes: I) -> Self
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        Self {
+            schedule: Schedule::Hourly {
+                minute: minutes.into_iter().collect(),
+            },
+        }
+    }
+
+    /// Triggers the watch every day at the given times of day, such as `noon` or `17:00`
+    pub fn daily<I>(at: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            schedule: Schedule::Daily {
+                at: at.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Trigger::interval(Time::Seconds(10)),
+            json!({ "schedule": { "interval": "10s" } }),
+        );
+
+        assert_serialize(
+            Trigger::cron(["0 0/5 * * * ?"]),
+            json!({ "schedule": { "cron": ["0 0/5 * * * ?"] } }),
+        );
+
+        assert_serialize(
+            Trigger::hourly([0, 30]),
+            json!({ "schedule": { "hourly": { "minute": [0, 30] } } }),
+        );
+
+        assert_serialize(
+            Trigger::daily(["noon", "17:00"]),
+            json!({ "schedule": { "daily": { "at": ["noon", "17:00"] } } }),
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::search::*;
+use crate::util::*;
+use crate::watcher::*;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Watch definition body for the create or update watch API.
+///
+/// To log a message whenever errors were indexed in the last five minutes:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let watch =
+/// Watch::new(Trigger::interval(Time::Minutes(5)))
+///     .input(Input::search(
+///         ["logs"],
+///         Search::new()
+///             .size(0)
+///             .query(Query::term("level", "error")),
+///     ))
+///     .condition(Condition::compare("ctx.payload.hits.total", CompareOperator::Gt, 0))
+///     .action("log_errors", WatchAction::logging("Errors were found"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/watcher-api-put-watch.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Watch {
+    trigger: Trigger,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    input: Option<Input>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    condition: Option<Condition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    actions: BTreeMap<String, WatchAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    throttle_period: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    metadata: BTreeMap<String, Value>,
+}
+
+impl Watch {
+    /// Creates an instance of [`Watch`]
+    ///
+    /// - `trigger` - Determines when the watch is executed
+    pub fn new(trigger: Trigger) -> Self {
+        Self {
+            trigger,
+            input: None,
+            condition: None,
+            actions: Default::default(),
+            throttle_period: None,
+            metadata: Default::default(),
+        }
+    }
+
+    /// Loads data into the watch payload. Defaults to an empty payload.
+    pub fn input(mut self, input: Input) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    /// Determines whether or not to execute the watch actions. Defaults to
+    /// [`Condition::Always`].
+    pub fn condition(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Adds an action to perform when the condition is met
+    pub fn action(mut self, name: impl Into<String>, action: impl Into<WatchAction>) -> Self {
+        let _ = self.actions.insert(name.into(), action.into());
+        self
+    }
+
+    /// The minimum time between actions being run. Defaults to `5s`.
+    pub fn throttle_period(mut self, throttle_period: Time) -> Self {
+        self.throttle_period = Some(throttle_period);
+        self
+    }
+
+    /// Metadata that will be copied into the history entries
+    pub fn metadata<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<Value>,
+    {
+        let _ = self.metadata.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Watch::new(Trigger::interval(Time::Seconds(10))),
+            json!({ "trigger": { "schedule": { "interval": "10s" } } }),
+        );
+
+        assert_serialize(
+            Watch::new(Trigger::interval(Time::Minutes(5)))
+                .input(Input::search(
+                    ["logs"],
+                    Search::new().size(0).query(Query::term("level", "error")),
+                ))
+                .condition(Condition::compare(
+                    "ctx.payload.hits.total",
+                    CompareOperator::Gt,
+                    0,
+                ))
+                .action("log_errors", WatchAction::logging("Errors were found"))
+                .action("notify", WatchAction::email(["ops@example.com"]))
+                .throttle_period(Time::Minutes(15))
+                .metadata("team", "ops"),
+            json!({
+                "trigger": {
+                    "schedule": {
+                        "interval": "5m"
+                    }
+                },
+                "input": {
+                    "search": {
+                        "request": {
+                            "indices": ["logs"],
+                            "body": {
+                                "size": 0,
+                                "query": {
+                                    "term": {
+                                        "level": {
+                                            "value": "error"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "condition": {
+                    "compare": {
+                        "ctx.payload.hits.total": {
+                            "gt": 0
+                        }
+                    }
+                },
+                "actions": {
+                    "log_errors": {
+                        "logging": {
+                            "text": "Errors were found"
+                        }
+                    },
+                    "notify": {
+                        "email": {
+                            "to": ["ops@example.com"]
+                        }
+                    }
+                },
+                "throttle_period": "15m",
+                "metadata": {
+                    "team": "ops"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::watcher::*;
+
+/// A container enum for supported watch action types
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/actions.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+#[allow(missing_docs)]
+pub enum WatchAction {
+    Logging(LoggingAction),
+    Email(EmailAction),
+    Webhook(WebhookAction),
+    Index(IndexAction),
+}
+
+impl From<LoggingAction> for WatchAction {
+    fn from(action: LoggingAction) -> Self {
+        Self::Logging(action)
+    }
+}
+
+impl From<EmailAction> for WatchAction {
+    fn from(action: EmailAction) -> Self {
+        Self::Email(action)
+    }
+}
+
+impl From<WebhookAction> for WatchAction {
+    fn from(action: WebhookAction) -> Self {
+        Self::Webhook(action)
+    }
+}
+
+impl From<IndexAction> for WatchAction {
+    fn from(action: IndexAction) -> Self {
+        Self::Index(action)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::util::*;
+use crate::watcher::*;
+use std::collections::BTreeMap;
+
+/// Sends a request to a web service.
+///
+/// To create webhook action:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let action =
+/// WatchAction::webhook(HttpMethod::Post, "https://example.com/alerts")
+///     .body("{\"errors\": {{ctx.payload.hits.total}}}");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/actions-webhook.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookAction {
+    #[serde(rename = "webhook")]
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inner {
+    method: HttpMethod,
+
+    url: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    headers: BTreeMap<String, String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    params: BTreeMap<String, String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    body: Option<String>,
+}
+
+/// HTTP method used by a [`WebhookAction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum HttpMethod {
+    Head,
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl WatchAction {
+    /// Creates an instance of [`WebhookAction`]
+    ///
+    /// - `method` - The HTTP method
+    /// - `url` - The URL of the web service, including scheme, host, port and path
+    pub fn webhook(method: HttpMethod, url: impl Into<String>) -> WebhookAction {
+        WebhookAction {
+            inner: Inner {
+                method,
+                url: url.into(),
+                headers: Default::default(),
+                params: Default::default(),
+                body: None,
+            },
+        }
+    }
+}
+
+impl WebhookAction {
+    /// Adds an HTTP request header. Header values support mustache templates.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let _ = self.inner.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds a URL query string parameter. Parameter values support mustache templates.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let _ = self.inner.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// The HTTP request body. Supports mustache templates.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.inner.body = Some(body.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            WatchAction::webhook(HttpMethod::Get, "https://example.com/ping"),
+            json!({
+                "webhook": {
+                    "method": "get",
+                    "url": "https://example.com/ping"
+                }
+            }),
+        );
+
+        assert_serialize(
+            WatchAction::webhook(HttpMethod::Post, "https://example.com/alerts")
+                .header("Content-Type", "application/json")
+                .param("source", "watcher")
+                .body("{\"watch\": \"{{ctx.watch_id}}\"}"),
+            json!({
+                "webhook": {
+                    "method": "post",
+                    "url": "https://example.com/alerts",
+                    "headers": {
+                        "Content-Type": "application/json"
+                    },
+                    "params": {
+                        "source": "watcher"
+                    },
+                    "body": "{\"watch\": \"{{ctx.watch_id}}\"}"
+                }
+            }),
+        );
+    }
+}